@@ -0,0 +1,164 @@
+use std::{hint::black_box, time::Instant};
+
+use avalanche_types::{avm, errors::Result, ids, key, packer::Packer, packer::PackerReader, txs};
+
+/// Compares "Packer" and "PackerReader" on the signed "avm.BaseTx" bytes.
+///
+/// cargo bench --bench packer_reader
+fn main() {
+    let tx_bytes = signed_base_tx_bytes(8, 8);
+    println!("signed avm.BaseTx size: {} bytes", tx_bytes.len());
+
+    let iterations = 100_000;
+    bench("Packer (copying)", iterations, || {
+        walk_with_packer(black_box(&tx_bytes))
+    });
+    bench("PackerReader (borrowing)", iterations, || {
+        walk_with_reader(black_box(&tx_bytes))
+    });
+    bench("avm::txs::Tx::unpack", iterations, || {
+        let tx = avm::txs::Tx::unpack(black_box(&tx_bytes))?;
+        Ok(tx.fx_creds.len() as u64)
+    });
+}
+
+fn bench<F: FnMut() -> Result<u64>>(name: &str, iterations: u32, mut f: F) {
+    // warm up
+    for _ in 0..iterations / 10 {
+        black_box(f().expect("failed to walk"));
+    }
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f().expect("failed to walk"));
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<28} {:>10} ns/op",
+        name,
+        elapsed.as_nanos() / iterations as u128
+    );
+}
+
+/// Walks every field of the signed "avm.BaseTx" in the codec order,
+/// reading the IDs and signatures as bytes just like "unpack" does.
+macro_rules! walk {
+    ($p:expr) => {{
+        let p = $p;
+        let mut sum: u64 = 0;
+
+        sum += p.unpack_u16()? as u64; // codec version
+        sum += p.unpack_u32()? as u64; // type ID
+        sum += p.unpack_u32()? as u64; // network ID
+        sum += p.unpack_bytes(ids::LEN)?.len() as u64; // blockchain ID
+
+        let outs = p.unpack_u32()?;
+        for _ in 0..outs {
+            sum += p.unpack_bytes(ids::LEN)?.len() as u64; // asset ID
+            sum += p.unpack_u32()? as u64; // type ID
+            sum += p.unpack_u64()?; // amount
+            sum += p.unpack_u64()?; // locktime
+            sum += p.unpack_u32()? as u64; // threshold
+            let addrs = p.unpack_u32()?;
+            for _ in 0..addrs {
+                sum += p.unpack_bytes(20)?.len() as u64;
+            }
+        }
+
+        let ins = p.unpack_u32()?;
+        for _ in 0..ins {
+            sum += p.unpack_bytes(ids::LEN)?.len() as u64; // tx ID
+            sum += p.unpack_u32()? as u64; // output index
+            sum += p.unpack_bytes(ids::LEN)?.len() as u64; // asset ID
+            sum += p.unpack_u32()? as u64; // type ID
+            sum += p.unpack_u64()?; // amount
+            let sig_indices = p.unpack_u32()?;
+            for _ in 0..sig_indices {
+                sum += p.unpack_u32()? as u64;
+            }
+        }
+
+        sum += p.unpack_bytes_with_header()?.len() as u64; // memo
+
+        let creds = p.unpack_u32()?;
+        for _ in 0..creds {
+            sum += p.unpack_u32()? as u64; // type ID
+            sum += p.unpack_2d_bytes(key::secp256k1::signature::LEN)?.len() as u64;
+        }
+
+        Ok(sum)
+    }};
+}
+
+fn walk_with_packer(d: &[u8]) -> Result<u64> {
+    let packer = Packer::load_bytes_for_unpack(d.len() + 1024, d);
+    walk!(&packer)
+}
+
+fn walk_with_reader(d: &[u8]) -> Result<u64> {
+    let reader = PackerReader::new(d.len() + 1024, d)?;
+    walk!(&reader)
+}
+
+/// Builds the signed "avm.BaseTx" with the given number of inputs and outputs,
+/// each input signed by a single key.
+fn signed_base_tx_bytes(ins: usize, outs: usize) -> Vec<u8> {
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let test_key_short_addr = test_key
+        .to_public_key()
+        .to_short_id()
+        .expect("failed to_short_id");
+    let asset_id = ids::Id::from_slice(&[1, 2, 3]);
+
+    let mut transferable_outputs = Vec::new();
+    for i in 0..outs {
+        transferable_outputs.push(txs::transferable::Output {
+            asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: 1000 + i as u64,
+                output_owners: key::secp256k1::txs::OutputOwners {
+                    locktime: 0,
+                    threshold: 1,
+                    addresses: vec![test_key_short_addr.clone()],
+                },
+            }),
+            ..Default::default()
+        });
+    }
+    let mut transferable_inputs = Vec::new();
+    for i in 0..ins {
+        transferable_inputs.push(txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[i as u8; ids::LEN]),
+                output_index: i as u32,
+                ..Default::default()
+            },
+            asset_id,
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 2000 + i as u64,
+                sig_indices: vec![0],
+            }),
+            ..Default::default()
+        });
+    }
+
+    let mut tx = avm::txs::Tx::new(txs::Tx {
+        network_id: 1,
+        blockchain_id: ids::Id::from_slice(&[5, 4, 3, 2, 1]),
+        transferable_outputs: Some(transferable_outputs),
+        transferable_inputs: Some(transferable_inputs),
+        memo: Some(vec![0x00, 0x01, 0x02, 0x03]),
+        ..Default::default()
+    });
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> =
+        (0..ins).map(|_| vec![test_key.clone()]).collect();
+    tokio_test::block_on(tx.sign(signers)).expect("failed to sign");
+
+    tx.base_tx
+        .metadata
+        .expect("unexpected None metadata")
+        .tx_bytes_with_signatures
+}
@@ -0,0 +1,153 @@
+//! Derive macros for the "avalanche_types::packer::{Packable, Unpackable}" traits.
+//!
+//! The derived implementation encodes each field in the declaration order,
+//! which is how the Avalanche linear codec serializes the struct fields
+//! tagged with serialize:"true".
+//!
+//! Fields with serialize:"false" (e.g., "fx_id" or cached IDs) should be
+//! marked with "#[packer(skip)]", and are initialized with "Default::default()"
+//! when unpacked.
+//!
+//! Interface fields that must be prefixed with the type ID are not supported,
+//! since the type ID is determined by the codec registry, not the Rust type.
+//! ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/codec/reflectcodec>
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Index};
+
+/// Derives "avalanche_types::packer::Packable".
+#[proc_macro_derive(Packable, attributes(packer))]
+pub fn derive_packable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_packable(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives "avalanche_types::packer::Unpackable".
+#[proc_macro_derive(Unpackable, attributes(packer))]
+pub fn derive_unpackable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_unpackable(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_packable(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = struct_fields(&input)?;
+    add_trait_bounds(&mut input, quote!(::avalanche_types::packer::Packable));
+
+    let mut packs = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        if is_skipped(field)? {
+            continue;
+        }
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(i);
+                quote!(#index)
+            }
+        };
+        packs.push(quote! {
+            ::avalanche_types::packer::Packable::pack_into(&self.#member, packer)?;
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::avalanche_types::packer::Packable for #name #ty_generics #where_clause {
+            fn pack_into(
+                &self,
+                packer: &::avalanche_types::packer::Packer,
+            ) -> ::avalanche_types::errors::Result<()> {
+                #(#packs)*
+                Ok(())
+            }
+        }
+    })
+}
+
+fn expand_unpackable(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = struct_fields(&input)?;
+    add_trait_bounds(&mut input, quote!(::avalanche_types::packer::Unpackable));
+
+    let mut unpacks = Vec::new();
+    let mut idents = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let ident = match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field_{}", i),
+        };
+        if is_skipped(field)? {
+            unpacks.push(quote! {
+                let #ident = ::core::default::Default::default();
+            });
+        } else {
+            let ty = &field.ty;
+            unpacks.push(quote! {
+                let #ident = <#ty as ::avalanche_types::packer::Unpackable>::unpack_from(packer)?;
+            });
+        }
+        idents.push(ident);
+    }
+
+    let construct = match fields {
+        Fields::Named(_) => quote!(Self { #(#idents),* }),
+        Fields::Unnamed(_) => quote!(Self ( #(#idents),* )),
+        Fields::Unit => quote!(Self),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::avalanche_types::packer::Unpackable for #name #ty_generics #where_clause {
+            fn unpack_from(
+                packer: &::avalanche_types::packer::Packer,
+            ) -> ::avalanche_types::errors::Result<Self> {
+                #(#unpacks)*
+                Ok(#construct)
+            }
+        }
+    })
+}
+
+/// Returns the struct fields, or fails for enums and unions
+/// whose encoding depends on the codec registry type IDs.
+fn struct_fields(input: &DeriveInput) -> syn::Result<Fields> {
+    match &input.data {
+        Data::Struct(data) => Ok(data.fields.clone()),
+        _ => Err(syn::Error::new(
+            input.span(),
+            "packer derive only supports structs (interface types must be packed with the type ID manually)",
+        )),
+    }
+}
+
+/// Returns true if the field is marked with "#[packer(skip)]".
+fn is_skipped(field: &syn::Field) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in field.attrs.iter() {
+        if !attr.path().is_ident("packer") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported packer attribute"))
+            }
+        })?;
+    }
+    Ok(skip)
+}
+
+/// Requires every type parameter to implement the derived trait.
+fn add_trait_bounds(input: &mut DeriveInput, bound: TokenStream2) {
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(#bound));
+    }
+}
@@ -0,0 +1,36 @@
+use std::{env::args, io};
+
+use avalanche_types::jsonrpc::client::Client;
+
+/// cargo run --example jsonrpc_client_c --features="jsonrpc_client" -- [HTTP RPC ENDPOINT] C-custom152qlr6zunz7nw2kc4lfej3cn3wk46u3002k4w5 X
+/// cargo run --example jsonrpc_client_c --features="jsonrpc_client" -- http://44.230.236.23:9650 C-custom152qlr6zunz7nw2kc4lfej3cn3wk46u3002k4w5 X
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    // ref. <https://github.com/env-logger-rs/env_logger/issues/47>
+    env_logger::init_from_env(
+        env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+    );
+
+    let url = args().nth(1).expect("no url given");
+    let jsonrpc_client_c = Client::new().unwrap().c();
+    let caddr = args().nth(2).expect("no c-chain address given");
+    let source_chain = args().nth(3).unwrap_or_else(|| String::from("X"));
+
+    let resp = jsonrpc_client_c
+        .get_utxos(&url, &caddr, &source_chain)
+        .await
+        .unwrap();
+    log::info!(
+        "get_utxos response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
+    // gas for an import tx with one input, one output and one signature
+    let fee = jsonrpc_client_c
+        .get_atomic_tx_fee(&url, 11_230)
+        .await
+        .unwrap();
+    log::info!("get_atomic_tx_fee: {fee} nAVAX");
+
+    Ok(())
+}
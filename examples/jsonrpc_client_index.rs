@@ -0,0 +1,43 @@
+use std::{env::args, io, time::Duration};
+
+use avalanche_types::jsonrpc::client::{index, Client};
+use futures::{StreamExt, TryStreamExt};
+
+/// cargo run --example jsonrpc_client_index --features="jsonrpc_client" -- [HTTP RPC ENDPOINT] [START INDEX]
+/// cargo run --example jsonrpc_client_index --features="jsonrpc_client" -- http://localhost:9650 0
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    // ref. <https://github.com/env-logger-rs/env_logger/issues/47>
+    env_logger::init_from_env(
+        env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+    );
+
+    let url = args().nth(1).expect("no url given");
+    let start_index = args()
+        .nth(2)
+        .map(|s| s.parse::<u64>().expect("invalid start index"))
+        .unwrap_or_default();
+    let jsonrpc_client_index = Client::new().unwrap().index();
+
+    let resp = jsonrpc_client_index
+        .get_last_accepted(&url, index::P_BLOCK)
+        .await
+        .unwrap();
+    log::info!(
+        "get_last_accepted response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
+    // follows the P-chain blocks from the start index
+    let containers: Vec<_> = jsonrpc_client_index
+        .accepted_containers(&url, index::P_BLOCK, start_index, Duration::from_secs(1))
+        .take(10)
+        .try_collect()
+        .await
+        .unwrap();
+    for c in containers.iter() {
+        log::info!("accepted container {} at index {}", c.id, c.index);
+    }
+
+    Ok(())
+}
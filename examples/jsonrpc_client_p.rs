@@ -1,6 +1,6 @@
 use std::{env::args, io};
 
-use avalanche_types::jsonrpc::client::p as jsonrpc_client_p;
+use avalanche_types::jsonrpc::client::Client;
 
 /// cargo run --example jsonrpc_client_p --features="jsonrpc_client" -- [HTTP RPC ENDPOINT] P-custom1qwmslrrqdv4slxvynhy9csq069l0u8mqwjzmcd
 /// cargo run --example jsonrpc_client_p --features="jsonrpc_client" -- http://52.42.183.125:9650
@@ -21,30 +21,32 @@ async fn main() -> io::Result<()> {
     );
 
     let url = args().nth(1).expect("no url given");
+    let jsonrpc_client_p = Client::new().unwrap().p();
     println!("{}", url);
 
     if let Some(paddr) = &args().nth(2) {
         println!("{}", paddr);
-        let resp = jsonrpc_client_p::get_balance(&url, paddr).await.unwrap();
+        let resp = jsonrpc_client_p.get_balance(&url, paddr).await.unwrap();
         log::info!(
             "get_balance response: {}",
             serde_json::to_string_pretty(&resp).unwrap()
         );
 
-        let resp = jsonrpc_client_p::get_utxos(&url, paddr).await.unwrap();
+        let resp = jsonrpc_client_p.get_utxos(&url, paddr).await.unwrap();
         log::info!(
             "get_utxos response: {}",
             serde_json::to_string_pretty(&resp).unwrap()
         );
     }
 
-    let resp = jsonrpc_client_p::get_height(&url).await.unwrap();
+    let resp = jsonrpc_client_p.get_height(&url).await.unwrap();
     log::info!(
         "get_height response: {}",
         serde_json::to_string_pretty(&resp).unwrap()
     );
 
-    let resp = jsonrpc_client_p::get_primary_network_validators(&url)
+    let resp = jsonrpc_client_p
+        .get_primary_network_validators(&url)
         .await
         .unwrap();
     log::info!(
@@ -52,13 +54,49 @@ async fn main() -> io::Result<()> {
         serde_json::to_string_pretty(&resp).unwrap()
     );
 
-    let resp = jsonrpc_client_p::get_subnets(&url, None).await.unwrap();
+    let resp = jsonrpc_client_p.get_timestamp(&url).await.unwrap();
+    log::info!(
+        "get_timestamp response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
+    let resp = jsonrpc_client_p
+        .get_current_supply(&url, None)
+        .await
+        .unwrap();
+    log::info!(
+        "get_current_supply response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
+    let resp = jsonrpc_client_p.get_min_stake(&url, None).await.unwrap();
+    log::info!(
+        "get_min_stake response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
+    let resp = jsonrpc_client_p.get_total_stake(&url, None).await.unwrap();
+    log::info!(
+        "get_total_stake response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
+    let resp = jsonrpc_client_p
+        .get_pending_validators(&url, None, None)
+        .await
+        .unwrap();
+    log::info!(
+        "get_pending_validators response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
+    let resp = jsonrpc_client_p.get_subnets(&url, None).await.unwrap();
     log::info!(
         "get_subnets response: {}",
         serde_json::to_string_pretty(&resp).unwrap()
     );
 
-    let resp = jsonrpc_client_p::get_blockchains(&url).await.unwrap();
+    let resp = jsonrpc_client_p.get_blockchains(&url).await.unwrap();
     log::info!(
         "get_blockchains response: {}",
         serde_json::to_string_pretty(&resp).unwrap()
@@ -1,6 +1,7 @@
 use std::{env::args, io};
 
-use avalanche_types::jsonrpc::client::x as jsonrpc_client_x;
+use avalanche_types::jsonrpc::client::Client;
+use futures::TryStreamExt;
 
 /// cargo run --example jsonrpc_client_x --features="jsonrpc_client" -- [HTTP RPC ENDPOINT] X-custom152qlr6zunz7nw2kc4lfej3cn3wk46u3002k4w5
 /// cargo run --example jsonrpc_client_x --features="jsonrpc_client" -- http://44.230.236.23:9650 X-custom152qlr6zunz7nw2kc4lfej3cn3wk46u3002k4w5
@@ -12,15 +13,33 @@ async fn main() -> io::Result<()> {
     );
 
     let url = args().nth(1).expect("no url given");
+    let jsonrpc_client_x = Client::new().unwrap().x();
     let xaddr = args().nth(2).expect("no x-chain address given");
 
-    let resp = jsonrpc_client_x::get_balance(&url, &xaddr).await.unwrap();
+    let resp = jsonrpc_client_x.get_balance(&url, &xaddr).await.unwrap();
     log::info!(
         "get_balance response: {}",
         serde_json::to_string_pretty(&resp).unwrap()
     );
 
-    let resp = jsonrpc_client_x::get_asset_description(&url, "AVAX")
+    let resp = jsonrpc_client_x
+        .get_all_balances(&url, &xaddr)
+        .await
+        .unwrap();
+    log::info!(
+        "get_all_balances response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
+    let utxos: Vec<_> = jsonrpc_client_x
+        .get_all_utxos(&url, &[xaddr], None)
+        .try_collect()
+        .await
+        .unwrap();
+    log::info!("get_all_utxos: {} UTXOs", utxos.len());
+
+    let resp = jsonrpc_client_x
+        .get_asset_description(&url, "AVAX")
         .await
         .unwrap();
     log::info!(
@@ -28,5 +47,11 @@ async fn main() -> io::Result<()> {
         serde_json::to_string_pretty(&resp).unwrap()
     );
 
+    let resp = jsonrpc_client_x.get_height(&url).await.unwrap();
+    log::info!(
+        "get_height response: {}",
+        serde_json::to_string_pretty(&resp).unwrap()
+    );
+
     Ok(())
 }
@@ -0,0 +1,775 @@
+use std::cmp::Ordering;
+
+use crate::{
+    avm::txs::fx,
+    codec,
+    errors::{Error, Result},
+    hash, ids, key, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Minimum length of the asset name.
+/// ref. "avalanchego/vms/avm/txs.minNameLen"
+pub const MIN_NAME_LEN: usize = 1;
+/// Maximum length of the asset name.
+/// ref. "avalanchego/vms/avm/txs.maxNameLen"
+pub const MAX_NAME_LEN: usize = 128;
+/// Maximum length of the asset symbol.
+/// ref. "avalanchego/vms/avm/txs.maxSymbolLen"
+pub const MAX_SYMBOL_LEN: usize = 4;
+/// Maximum number of the decimal places of the asset.
+/// ref. "avalanchego/vms/avm/txs.maxDenomination"
+pub const MAX_DENOMINATION: u8 = 32;
+
+/// Outputs of the newly created asset, all of which belong to the same fx.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#InitialState>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct InitialState {
+    #[serde(rename = "fxIndex")]
+    pub fx_index: u32,
+    pub outputs: Vec<fx::Output>,
+}
+
+impl Default for InitialState {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl InitialState {
+    pub fn default() -> Self {
+        Self {
+            fx_index: 0,
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn new(fx_index: u32, outputs: Vec<fx::Output>) -> Self {
+        Self { fx_index, outputs }
+    }
+
+    /// Sorts the outputs by their packed bytes with the type ID.
+    /// ref. "avalanchego/vms/avm/txs.InitialState.Sort"
+    pub fn sort(&mut self) -> Result<()> {
+        let mut keyed: Vec<(Vec<u8>, fx::Output)> = Vec::new();
+        for out in self.outputs.drain(..) {
+            keyed.push((out.to_bytes()?, out));
+        }
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        self.outputs = keyed.into_iter().map(|(_, out)| out).collect();
+        Ok(())
+    }
+
+    /// ref. "avalanchego/vms/avm/txs.InitialState.Verify"
+    pub fn verify(&self, fxs_len: u32) -> Result<()> {
+        if self.fx_index >= fxs_len {
+            return Err(Error::Other {
+                message: format!("unknown feature extension index {}", self.fx_index), // ref. "errUnknownFx"
+                retryable: false,
+            });
+        }
+
+        let mut prev: Option<Vec<u8>> = None;
+        for out in self.outputs.iter() {
+            if out.fx_index() != self.fx_index {
+                return Err(Error::Other {
+                    message: format!(
+                        "output type ID {} does not belong to the fx index {}",
+                        out.type_id(),
+                        self.fx_index
+                    ),
+                    retryable: false,
+                });
+            }
+            out.verify()?;
+
+            let b = out.to_bytes()?;
+            if let Some(prev) = &prev {
+                if prev >= &b {
+                    return Err(Error::Other {
+                        message: "outputs not sorted".to_string(), // ref. "errOutputsNotSorted"
+                        retryable: false,
+                    });
+                }
+            }
+            prev = Some(b);
+        }
+        Ok(())
+    }
+}
+
+impl Ord for InitialState {
+    fn cmp(&self, other: &InitialState) -> Ordering {
+        self.fx_index.cmp(&other.fx_index)
+    }
+}
+
+impl PartialOrd for InitialState {
+    fn partial_cmp(&self, other: &InitialState) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Creates a new asset, whose asset ID is the transaction ID.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#CreateAssetTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+    pub name: String,
+    pub symbol: String,
+    pub denomination: u8,
+    #[serde(rename = "initialStates")]
+    pub states: Vec<InitialState>,
+    pub fx_creds: Vec<fx::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            name: String::new(),
+            symbol: String::new(),
+            denomination: 0,
+            states: Vec::new(),
+            fx_creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID, which is also the asset ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "avm.CreateAssetTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Sorts the initial states by the fx index, and each of their outputs.
+    pub fn sort_states(&mut self) -> Result<()> {
+        for state in self.states.iter_mut() {
+            state.sort()?;
+        }
+        self.states.sort();
+        Ok(())
+    }
+
+    /// Verifies the asset name, symbol, denomination and the initial states
+    /// with the default X-chain fxs.
+    /// ref. "avalanchego/vms/avm/txs.CreateAssetTx.SyntacticVerify"
+    pub fn verify(&self) -> Result<()> {
+        if self.name.len() < MIN_NAME_LEN {
+            return Err(Error::Other {
+                message: format!("name is too short, minimum size is {}", MIN_NAME_LEN), // ref. "errNameTooShort"
+                retryable: false,
+            });
+        }
+        if self.name.len() > MAX_NAME_LEN {
+            return Err(Error::Other {
+                message: format!("name is too long, maximum size is {}", MAX_NAME_LEN), // ref. "errNameTooLong"
+                retryable: false,
+            });
+        }
+        if self.symbol.len() > MAX_SYMBOL_LEN {
+            return Err(Error::Other {
+                message: format!("symbol is too long, maximum size is {}", MAX_SYMBOL_LEN), // ref. "errSymbolTooLong"
+                retryable: false,
+            });
+        }
+        if self.states.is_empty() {
+            return Err(Error::Other {
+                message: "assets must support at least one Fx".to_string(), // ref. "errNoFxs"
+                retryable: false,
+            });
+        }
+        if self.name.trim() != self.name {
+            return Err(Error::Other {
+                message: "unexpected whitespace provided".to_string(), // ref. "errUnexpectedWhitespace"
+                retryable: false,
+            });
+        }
+        if !self
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ')
+        {
+            return Err(Error::Other {
+                message: "asset's name must be made up of only letters and numbers".to_string(), // ref. "errIllegalNameCharacter"
+                retryable: false,
+            });
+        }
+        if !self.symbol.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(Error::Other {
+                message: "asset's symbol must be all upper case letters".to_string(), // ref. "errIllegalSymbolCharacter"
+                retryable: false,
+            });
+        }
+        if self.denomination > MAX_DENOMINATION {
+            return Err(Error::Other {
+                message: "denomination is too large".to_string(), // ref. "errDenominationTooLarge"
+                retryable: false,
+            });
+        }
+
+        for (i, state) in self.states.iter().enumerate() {
+            if i > 0 && self.states[i - 1].fx_index >= state.fx_index {
+                return Err(Error::Other {
+                    message: "initial states not sorted and unique".to_string(), // ref. "errInitialStatesNotSortedUnique"
+                    retryable: false,
+                });
+            }
+            state.verify(fx::FXS_LEN)?;
+        }
+        Ok(())
+    }
+
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        // unpack the second, third, and fourth fields in the struct
+        let name = packer.unpack_str()?;
+        let symbol = packer.unpack_str()?;
+        let denomination = packer.unpack_byte()?;
+
+        // unpack the fifth field in the struct
+        let states_len = packer.unpack_u32()?;
+        let mut states: Vec<InitialState> = Vec::new();
+        for _ in 0..states_len {
+            let fx_index = packer.unpack_u32()?;
+
+            let outputs_len = packer.unpack_u32()?;
+            let mut outputs: Vec<fx::Output> = Vec::new();
+            for _ in 0..outputs_len {
+                outputs.push(packer.unpack_fx_output()?);
+            }
+            states.push(InitialState { fx_index, outputs });
+        }
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let fx_creds = packer.unpack_fx_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        )?);
+
+        Ok(Self {
+            base_tx,
+            name,
+            symbol,
+            denomination,
+            states,
+            fx_creds,
+        })
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        // marshal "unsigned tx" with the codec version
+        let type_id = Self::type_id();
+        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+
+        // "avalanchego" marshals the whole struct again for signed bytes
+        // even when the underlying "unsigned_tx" is already once marshaled
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx
+        //
+        // reuse the underlying packer to avoid marshaling the unsigned tx twice
+        // just marshal the next fields in the struct and pack them all together
+        // in the existing packer
+        let b = packer.take_bytes();
+        packer.set_bytes(&b);
+
+        // pack the second, third, and fourth fields in the struct
+        packer.pack_str(&self.name)?;
+        packer.pack_str(&self.symbol)?;
+        packer.pack_byte(self.denomination)?;
+
+        // pack the fifth field in the struct
+        // "InitialState" is a struct pointer thus no type ID,
+        // but each output is "verify.State" interface thus prefixed with its type ID
+        packer.pack_u32(self.states.len() as u32)?;
+        for state in self.states.iter() {
+            packer.pack_u32(state.fx_index)?;
+            packer.pack_u32(state.outputs.len() as u32)?;
+            for out in state.outputs.iter() {
+                out.pack(&packer)?;
+            }
+        }
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // compute sha256 for marshaled "unsigned tx" bytes
+        // IMPORTANT: take the hash only for the type "avm.CreateAssetTx" unsigned tx
+        // not other fields -- only hash "avm.CreateAssetTx.*" but not "avm.Tx.Creds"
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#CreateAssetTx
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        // number of of credentials
+        let fx_creds_len = signers.len() as u32;
+        // pack the sixth field in the struct
+        packer.pack_u32(fx_creds_len)?;
+
+        // sign the hash with the signers (in case of multi-sig)
+        // and combine all signatures into a secp256k1fx credential
+        self.fx_creds = Vec::new();
+        for keys in signers.iter() {
+            let mut sigs: Vec<Vec<u8>> = Vec::new();
+            for k in keys.iter() {
+                let sig = k.sign_digest(&tx_bytes_hash).await?;
+                sigs.push(Vec::from(sig));
+            }
+
+            let mut cred = key::secp256k1::txs::Credential::default();
+            cred.signatures = sigs;
+
+            let mut fx_cred = fx::Credential::default();
+            fx_cred.cred = cred;
+
+            // add a new credential to "Tx"
+            self.fx_creds.push(fx_cred);
+        }
+        if fx_creds_len > 0 {
+            // pack each "fx_cred" which is "secp256k1fx.Credential"
+            // marshal type ID for "secp256k1fx.Credential"
+            let cred_type_id = key::secp256k1::txs::Credential::type_id();
+            for fx_cred in self.fx_creds.iter() {
+                packer.pack_u32(cred_type_id)?;
+                packer.pack_u32(fx_cred.cred.signatures.len() as u32)?;
+                for sig in fx_cred.cred.signatures.iter() {
+                    packer.pack_bytes(sig)?;
+                }
+            }
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
+        // ref. "avalanchego/vms/avm.Tx.SignSECP256K1Fx"
+        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
+        self.base_tx.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::create_asset::test_create_asset_tx_serialization_with_one_signer --exact --show-output
+#[test]
+fn test_create_asset_tx_serialization_with_one_signer() {
+    use crate::{avm, ids::short, nftfx, propertyfx};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    // ref. "avalanchego/vms/avm/vm_test.go"
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let test_key_short_addr = test_key
+        .to_public_key()
+        .to_short_bytes()
+        .expect("failed short_address_bytes");
+    let test_key_short_addr = short::Id::from_slice(&test_key_short_addr);
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&test_key_short_addr));
+
+    let base_tx = txs::Tx {
+        network_id: 10,
+        blockchain_id: ids::Id::from_slice(&[5, 4, 3, 2, 1]),
+        transferable_inputs: Some(vec![txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[0xff, 0xfe, 0xfd]),
+                output_index: 1,
+                ..txs::utxo::Id::default()
+            },
+            asset_id: ids::Id::from_slice(&[1, 2, 3]),
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 1000,
+                sig_indices: vec![0],
+            }),
+            ..txs::transferable::Input::default()
+        }]),
+        ..txs::Tx::default()
+    };
+    let mut tx = Tx {
+        base_tx: base_tx.clone(),
+        name: "Volatility Index".to_string(),
+        symbol: "VIX".to_string(),
+        denomination: 2,
+        states: vec![
+            InitialState::new(
+                fx::PROPERTYFX_INDEX,
+                vec![fx::Output::PropertyfxMint(propertyfx::MintOutput::new(
+                    owners.clone(),
+                ))],
+            ),
+            InitialState::new(
+                fx::SECP256K1FX_INDEX,
+                vec![
+                    fx::Output::Secp256k1fxTransfer(key::secp256k1::txs::transfer::Output::new(
+                        12345,
+                        owners.clone(),
+                    )),
+                    fx::Output::Secp256k1fxMint(key::secp256k1::txs::mint::Output::new(
+                        owners.clone(),
+                    )),
+                ],
+            ),
+            InitialState::new(
+                fx::NFTFX_INDEX,
+                vec![fx::Output::NftfxMint(nftfx::MintOutput::new(
+                    3,
+                    owners.clone(),
+                ))],
+            ),
+        ],
+        ..Tx::default()
+    };
+    assert!(tx
+        .verify()
+        .unwrap_err()
+        .contains("initial states not sorted and unique"));
+    tx.sort_states().unwrap();
+    assert_eq!(
+        tx.states.iter().map(|s| s.fx_index).collect::<Vec<u32>>(),
+        vec![0, 1, 2]
+    );
+    // "secp256k1fx.MintOutput" (type ID 6) sorts before "secp256k1fx.TransferOutput" (7)
+    assert_eq!(tx.states[0].outputs[0].type_id(), 6);
+    tx.verify().unwrap();
+
+    ab!(tx.sign(vec![vec![test_key]])).expect("failed to sign");
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures;
+
+    let mut expected_unsigned_bytes = base_tx
+        .pack(codec::VERSION, Tx::type_id())
+        .unwrap()
+        .take_bytes()
+        .to_vec();
+    assert_eq!(&expected_unsigned_bytes[2..6], &[0x00, 0x00, 0x00, 0x01]);
+    expected_unsigned_bytes.extend_from_slice(&[
+        // name
+        0x00, 0x10, //
+        b'V', b'o', b'l', b'a', b't', b'i', b'l', b'i', //
+        b't', b'y', b' ', b'I', b'n', b'd', b'e', b'x', //
+        //
+        // symbol
+        0x00, 0x03, b'V', b'I', b'X', //
+        //
+        // denomination
+        0x02, //
+        //
+        // states.len()
+        0x00, 0x00, 0x00, 0x03, //
+        //
+        // states[0].fx_index
+        0x00, 0x00, 0x00, 0x00, //
+        //
+        // states[0].outputs.len()
+        0x00, 0x00, 0x00, 0x02, //
+        //
+        // states[0].outputs[0] secp256k1fx.MintOutput type ID
+        0x00, 0x00, 0x00, 0x06, //
+        //
+        // locktime, threshold, addresses.len()
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x01, //
+        0x00, 0x00, 0x00, 0x01, //
+    ]);
+    expected_unsigned_bytes.extend_from_slice(test_key_short_addr.as_ref());
+    expected_unsigned_bytes.extend_from_slice(&[
+        // states[0].outputs[1] secp256k1fx.TransferOutput type ID
+        0x00, 0x00, 0x00, 0x07, //
+        //
+        // amount
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x39, //
+        //
+        // locktime, threshold, addresses.len()
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x01, //
+        0x00, 0x00, 0x00, 0x01, //
+    ]);
+    expected_unsigned_bytes.extend_from_slice(test_key_short_addr.as_ref());
+    expected_unsigned_bytes.extend_from_slice(&[
+        // states[1].fx_index
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // states[1].outputs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // states[1].outputs[0] nftfx.MintOutput type ID
+        0x00, 0x00, 0x00, 0x0a, //
+        //
+        // group_id
+        0x00, 0x00, 0x00, 0x03, //
+        //
+        // locktime, threshold, addresses.len()
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x01, //
+        0x00, 0x00, 0x00, 0x01, //
+    ]);
+    expected_unsigned_bytes.extend_from_slice(test_key_short_addr.as_ref());
+    expected_unsigned_bytes.extend_from_slice(&[
+        // states[2].fx_index
+        0x00, 0x00, 0x00, 0x02, //
+        //
+        // states[2].outputs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // states[2].outputs[0] propertyfx.MintOutput type ID
+        0x00, 0x00, 0x00, 0x0f, //
+        //
+        // locktime, threshold, addresses.len()
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x01, //
+        0x00, 0x00, 0x00, 0x01, //
+    ]);
+    expected_unsigned_bytes.extend_from_slice(test_key_short_addr.as_ref());
+    assert_eq!(
+        tx_metadata.tx_bytes_with_no_signature,
+        expected_unsigned_bytes
+    );
+    assert_eq!(
+        &tx_bytes_with_signatures[..expected_unsigned_bytes.len()],
+        &expected_unsigned_bytes[..]
+    );
+    assert_eq!(
+        &tx_bytes_with_signatures[expected_unsigned_bytes.len()..expected_unsigned_bytes.len() + 8],
+        &[
+            0x00, 0x00, 0x00, 0x01, // fx_creds.len()
+            0x00, 0x00, 0x00, 0x09, // secp256k1fx.Credential type ID
+        ]
+    );
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
+
+    let signed_tx = avm::txs::SignedTx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(signed_tx, avm::txs::SignedTx::CreateAsset(tx.clone()));
+    assert_eq!(signed_tx.tx_id(), tx.tx_id());
+    assert_eq!(signed_tx.fx_creds().len(), 1);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::create_asset::test_create_asset_tx_verify --exact --show-output
+#[test]
+fn test_create_asset_tx_verify() {
+    use crate::{ids::short, nftfx};
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0x01, 0x02])]);
+    let valid = Tx {
+        name: "Team Rocket".to_string(),
+        symbol: "TR".to_string(),
+        denomination: 9,
+        states: vec![InitialState::new(
+            fx::SECP256K1FX_INDEX,
+            vec![fx::Output::Secp256k1fxTransfer(
+                key::secp256k1::txs::transfer::Output::new(1000, owners.clone()),
+            )],
+        )],
+        ..Tx::default()
+    };
+    valid.verify().unwrap();
+
+    let cases: Vec<(Tx, &str)> = vec![
+        (
+            Tx {
+                name: String::new(),
+                ..valid.clone()
+            },
+            "name is too short",
+        ),
+        (
+            Tx {
+                name: "a".repeat(MAX_NAME_LEN + 1),
+                ..valid.clone()
+            },
+            "name is too long",
+        ),
+        (
+            Tx {
+                symbol: "ABCDE".to_string(),
+                ..valid.clone()
+            },
+            "symbol is too long",
+        ),
+        (
+            Tx {
+                states: Vec::new(),
+                ..valid.clone()
+            },
+            "at least one Fx",
+        ),
+        (
+            Tx {
+                name: " Team Rocket".to_string(),
+                ..valid.clone()
+            },
+            "unexpected whitespace",
+        ),
+        (
+            Tx {
+                name: "Team-Rocket".to_string(),
+                ..valid.clone()
+            },
+            "only letters and numbers",
+        ),
+        (
+            Tx {
+                symbol: "tr".to_string(),
+                ..valid.clone()
+            },
+            "all upper case letters",
+        ),
+        (
+            Tx {
+                denomination: MAX_DENOMINATION + 1,
+                ..valid.clone()
+            },
+            "denomination is too large",
+        ),
+        (
+            Tx {
+                states: vec![valid.states[0].clone(), valid.states[0].clone()],
+                ..valid.clone()
+            },
+            "initial states not sorted and unique",
+        ),
+        (
+            Tx {
+                states: vec![InitialState::new(fx::FXS_LEN, Vec::new())],
+                ..valid.clone()
+            },
+            "unknown feature extension",
+        ),
+        (
+            Tx {
+                states: vec![InitialState::new(
+                    fx::SECP256K1FX_INDEX,
+                    vec![fx::Output::NftfxMint(nftfx::MintOutput::new(
+                        0,
+                        owners.clone(),
+                    ))],
+                )],
+                ..valid.clone()
+            },
+            "does not belong to the fx index",
+        ),
+        (
+            Tx {
+                states: vec![InitialState::new(
+                    fx::SECP256K1FX_INDEX,
+                    vec![
+                        valid.states[0].outputs[0].clone(),
+                        valid.states[0].outputs[0].clone(),
+                    ],
+                )],
+                ..valid.clone()
+            },
+            "outputs not sorted",
+        ),
+        (
+            Tx {
+                states: vec![InitialState::new(
+                    fx::NFTFX_INDEX,
+                    vec![fx::Output::NftfxTransfer(nftfx::TransferOutput::new(
+                        0,
+                        vec![0; nftfx::MAX_PAYLOAD_SIZE + 1],
+                        owners,
+                    ))],
+                )],
+                ..valid.clone()
+            },
+            "payload too large",
+        ),
+    ];
+    for (tx, expected) in cases.iter() {
+        let err = tx.verify().unwrap_err();
+        assert!(
+            err.contains(expected),
+            "expected '{}', got '{}'",
+            expected,
+            err.message()
+        );
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::create_asset::test_initial_state_max_payload --exact --show-output
+#[test]
+fn test_initial_state_max_payload() {
+    use crate::{ids::short, nftfx};
+
+    let addresses: Vec<short::Id> = (0..32_u8)
+        .map(|i| short::Id::from_slice(&[i + 1; 20]))
+        .collect();
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, &addresses);
+
+    // payloads up to "nftfx.MaxPayloadSize" are valid,
+    // even if the packed output is larger than the payload
+    let mut state = InitialState::new(
+        fx::NFTFX_INDEX,
+        vec![
+            fx::Output::NftfxTransfer(nftfx::TransferOutput::new(
+                2,
+                vec![0xff; nftfx::MAX_PAYLOAD_SIZE],
+                owners.clone(),
+            )),
+            fx::Output::NftfxTransfer(nftfx::TransferOutput::new(
+                1,
+                vec![0xff; nftfx::MAX_PAYLOAD_SIZE],
+                owners,
+            )),
+        ],
+    );
+    assert!(state.outputs[0].to_bytes().unwrap().len() > nftfx::MAX_PAYLOAD_SIZE);
+
+    state.sort().unwrap();
+    state.verify(fx::FXS_LEN).unwrap();
+    match &state.outputs[0] {
+        fx::Output::NftfxTransfer(out) => assert_eq!(out.group_id, 1),
+        _ => panic!("unexpected output"),
+    }
+}
@@ -54,6 +54,35 @@ impl Tx {
         *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        // unpack the second field in the struct
+        let destination_chain_id = packer.unpack_bytes(ids::LEN)?;
+        let destination_chain_id = ids::Id::from_slice(&destination_chain_id);
+
+        // unpack the third field in the struct
+        let destination_chain_transferable_outputs = packer.unpack_transferable_outputs()?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let fx_creds = packer.unpack_fx_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        )?);
+
+        Ok(Self {
+            base_tx,
+            destination_chain_id,
+            destination_chain_transferable_outputs,
+            fx_creds,
+        })
+    }
+
     /// Returns the transaction ID.
     /// Only non-empty if the embedded metadata is updated
     /// with the signing process.
@@ -465,4 +494,8 @@ fn test_export_tx_serialization_with_two_signers() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
 }
@@ -1,11 +1,34 @@
-use crate::{ids, key};
+use crate::{
+    codec,
+    errors::{Error, Result},
+    ids, key, nftfx,
+    packer::{self, Packable},
+    propertyfx,
+};
 use serde::{Deserialize, Serialize};
 
+/// Index of "secp256k1fx" in the X-chain fxs.
+/// ref. "avalanchego/vms/avm.VM.fxs"
+pub const SECP256K1FX_INDEX: u32 = 0;
+/// Index of "nftfx" in the X-chain fxs.
+pub const NFTFX_INDEX: u32 = 1;
+/// Index of "propertyfx" in the X-chain fxs.
+pub const PROPERTYFX_INDEX: u32 = 2;
+/// Number of the fxs registered in the X-chain by default.
+pub const FXS_LEN: u32 = 3;
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#FxCredential>
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Credential {
     pub fx_id: ids::Id, // skip serialization due to serialize:"false"
     pub cred: key::secp256k1::txs::Credential,
+
+    /// Index of the fx that defines the credential type
+    /// (e.g., "nftfx.Credential" to spend the nftfx outputs).
+    /// All fx credentials embed "secp256k1fx.Credential", and only
+    /// differ in the type ID.
+    #[serde(skip)]
+    pub fx_index: u32,
 }
 
 impl Default for Credential {
@@ -19,6 +42,335 @@ impl Credential {
         Self {
             fx_id: ids::Id::empty(),
             cred: key::secp256k1::txs::Credential::default(),
+            fx_index: SECP256K1FX_INDEX,
+        }
+    }
+
+    /// Returns the type ID of the credential for its fx.
+    pub fn type_id(&self) -> u32 {
+        match self.fx_index {
+            NFTFX_INDEX => nftfx::credential_type_id(),
+            PROPERTYFX_INDEX => propertyfx::credential_type_id(),
+            _ => key::secp256k1::txs::Credential::type_id(),
+        }
+    }
+}
+
+impl packer::Packer {
+    /// Unpacks the list of "avm.FxCredential" in the signed tx,
+    /// each of which is prefixed with its credential type ID
+    /// ("secp256k1fx.Credential", "nftfx.Credential" or "propertyfx.Credential").
+    /// "fx_id" is left empty as it is serialize:"false".
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx>
+    pub fn unpack_fx_credentials(&self) -> Result<Vec<Credential>> {
+        let fx_creds_len = self.unpack_u32()?;
+        let mut fx_creds: Vec<Credential> = Vec::new();
+        for _ in 0..fx_creds_len {
+            let type_id_cred = self.unpack_u32()?;
+            let fx_index = match type_id_cred {
+                // "secp256k1fx.Credential"
+                9 => SECP256K1FX_INDEX,
+                // "nftfx.Credential"
+                14 => NFTFX_INDEX,
+                // "propertyfx.Credential"
+                19 => PROPERTYFX_INDEX,
+                _ => {
+                    return Err(Error::UnexpectedTypeId {
+                        type_id: type_id_cred,
+                        type_name: codec::x_type_name(type_id_cred),
+                        interface: "verify.Verifiable".to_string(),
+                    });
+                }
+            };
+            let cred = self.unpack_credential()?;
+            fx_creds.push(Credential {
+                cred,
+                fx_index,
+                ..Credential::default()
+            });
+        }
+        Ok(fx_creds)
+    }
+}
+
+/// Fx output (e.g., the initial state of an asset or the UTXO output),
+/// encoded with its type ID as the Go "verify.State" interface.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/verify#State>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum Output {
+    Secp256k1fxTransfer(key::secp256k1::txs::transfer::Output),
+    Secp256k1fxMint(key::secp256k1::txs::mint::Output),
+    NftfxMint(nftfx::MintOutput),
+    NftfxTransfer(nftfx::TransferOutput),
+    PropertyfxMint(propertyfx::MintOutput),
+    PropertyfxOwned(propertyfx::OwnedOutput),
+}
+
+impl Output {
+    pub fn type_id(&self) -> u32 {
+        match self {
+            Self::Secp256k1fxTransfer(_) => key::secp256k1::txs::transfer::Output::type_id(),
+            Self::Secp256k1fxMint(_) => key::secp256k1::txs::mint::Output::type_id(),
+            Self::NftfxMint(_) => nftfx::MintOutput::type_id(),
+            Self::NftfxTransfer(_) => nftfx::TransferOutput::type_id(),
+            Self::PropertyfxMint(_) => propertyfx::MintOutput::type_id(),
+            Self::PropertyfxOwned(_) => propertyfx::OwnedOutput::type_id(),
+        }
+    }
+
+    /// Returns the index of the fx that defines the output.
+    pub fn fx_index(&self) -> u32 {
+        match self {
+            Self::Secp256k1fxTransfer(_) | Self::Secp256k1fxMint(_) => SECP256K1FX_INDEX,
+            Self::NftfxMint(_) | Self::NftfxTransfer(_) => NFTFX_INDEX,
+            Self::PropertyfxMint(_) | Self::PropertyfxOwned(_) => PROPERTYFX_INDEX,
+        }
+    }
+
+    /// Returns the owners of the output.
+    pub fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners {
+        match self {
+            Self::Secp256k1fxTransfer(out) => &out.output_owners,
+            Self::Secp256k1fxMint(out) => &out.output_owners,
+            Self::NftfxMint(out) => &out.output_owners,
+            Self::NftfxTransfer(out) => &out.output_owners,
+            Self::PropertyfxMint(out) => &out.output_owners,
+            Self::PropertyfxOwned(out) => &out.output_owners,
+        }
+    }
+
+    /// Packs the type ID and the output fields.
+    pub fn pack(&self, packer: &packer::Packer) -> Result<()> {
+        packer.pack_u32(self.type_id())?;
+        match self {
+            Self::Secp256k1fxTransfer(out) => out.pack_into(packer),
+            Self::Secp256k1fxMint(out) => out.pack_into(packer),
+            Self::NftfxMint(out) => out.pack_into(packer),
+            Self::NftfxTransfer(out) => out.pack_into(packer),
+            Self::PropertyfxMint(out) => out.pack_into(packer),
+            Self::PropertyfxOwned(out) => out.pack_into(packer),
+        }
+    }
+
+    /// Returns the packed bytes with the type ID, used for sorting the outputs.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let packer = packer::Packer::new((1 << 31) - 1, 128);
+        self.pack(&packer)?;
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    /// ref. "avalanchego/vms/components/verify.State.Verify"
+    pub fn verify(&self) -> Result<()> {
+        match self {
+            Self::Secp256k1fxTransfer(out) => {
+                if out.amount == 0 {
+                    return Err(Error::Other {
+                        message: "output has no value".to_string(), // ref. "errNoValueOutput"
+                        retryable: false,
+                    });
+                }
+                out.output_owners.verify()
+            }
+            Self::NftfxTransfer(out) => out.verify(),
+            _ => self.output_owners().verify(),
+        }
+    }
+}
+
+/// Fx operation that consumes the fx-specific UTXOs and produces new outputs,
+/// encoded with its type ID as the Go "fxs.FxOperation" interface.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/fxs#FxOperation>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum Operation {
+    Secp256k1fxMint(key::secp256k1::txs::mint::Operation),
+    NftfxMint(nftfx::MintOperation),
+    NftfxTransfer(nftfx::TransferOperation),
+    PropertyfxMint(propertyfx::MintOperation),
+    PropertyfxBurn(propertyfx::BurnOperation),
+}
+
+impl Operation {
+    pub fn type_id(&self) -> u32 {
+        match self {
+            Self::Secp256k1fxMint(_) => key::secp256k1::txs::mint::Operation::type_id(),
+            Self::NftfxMint(_) => nftfx::MintOperation::type_id(),
+            Self::NftfxTransfer(_) => nftfx::TransferOperation::type_id(),
+            Self::PropertyfxMint(_) => propertyfx::MintOperation::type_id(),
+            Self::PropertyfxBurn(_) => propertyfx::BurnOperation::type_id(),
+        }
+    }
+
+    /// Returns the index of the fx that defines the operation,
+    /// which also decides the credential type to sign the operation.
+    pub fn fx_index(&self) -> u32 {
+        match self {
+            Self::Secp256k1fxMint(_) => SECP256K1FX_INDEX,
+            Self::NftfxMint(_) | Self::NftfxTransfer(_) => NFTFX_INDEX,
+            Self::PropertyfxMint(_) | Self::PropertyfxBurn(_) => PROPERTYFX_INDEX,
+        }
+    }
+
+    /// Returns the signature indices to spend the consumed UTXO.
+    pub fn sig_indices(&self) -> &[u32] {
+        match self {
+            Self::Secp256k1fxMint(op) => &op.mint_input.sig_indices,
+            Self::NftfxMint(op) => &op.mint_input.sig_indices,
+            Self::NftfxTransfer(op) => &op.input.sig_indices,
+            Self::PropertyfxMint(op) => &op.mint_input.sig_indices,
+            Self::PropertyfxBurn(op) => &op.input.sig_indices,
+        }
+    }
+
+    /// Packs the type ID and the operation fields.
+    pub fn pack(&self, packer: &packer::Packer) -> Result<()> {
+        packer.pack_u32(self.type_id())?;
+        match self {
+            Self::Secp256k1fxMint(op) => op.pack_into(packer),
+            Self::NftfxMint(op) => op.pack_into(packer),
+            Self::NftfxTransfer(op) => op.pack_into(packer),
+            Self::PropertyfxMint(op) => op.pack_into(packer),
+            Self::PropertyfxBurn(op) => op.pack_into(packer),
+        }
+    }
+
+    /// ref. "avalanchego/vms/avm/fxs.FxOperation.Verify"
+    pub fn verify(&self) -> Result<()> {
+        match self {
+            Self::Secp256k1fxMint(op) => {
+                Output::Secp256k1fxMint(op.mint_output.clone()).verify()?;
+                Output::Secp256k1fxTransfer(op.transfer_output.clone()).verify()
+            }
+            Self::NftfxMint(op) => op.verify(),
+            Self::NftfxTransfer(op) => op.verify(),
+            Self::PropertyfxMint(op) => {
+                op.mint_output.output_owners.verify()?;
+                op.owned_output.output_owners.verify()
+            }
+            Self::PropertyfxBurn(_) => Ok(()),
         }
     }
 }
+
+impl packer::Packer {
+    /// Unpacks the fx output prefixed with its type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/verify#State>
+    pub fn unpack_fx_output(&self) -> Result<Output> {
+        let type_id = self.unpack_u32()?;
+        let out = match type_id {
+            // "secp256k1fx.MintOutput"
+            6 => Output::Secp256k1fxMint(self.unpack_mint_output()?),
+            // "secp256k1fx.TransferOutput"
+            7 => Output::Secp256k1fxTransfer(self.unpack_transfer_output()?),
+            // "nftfx.MintOutput"
+            10 => Output::NftfxMint(self.unpack_nft_mint_output()?),
+            // "nftfx.TransferOutput"
+            11 => Output::NftfxTransfer(self.unpack_nft_transfer_output()?),
+            // "propertyfx.MintOutput"
+            15 => Output::PropertyfxMint(self.unpack_property_mint_output()?),
+            // "propertyfx.OwnedOutput"
+            16 => Output::PropertyfxOwned(self.unpack_property_owned_output()?),
+            _ => {
+                return Err(Error::UnexpectedTypeId {
+                    type_id,
+                    type_name: codec::x_type_name(type_id),
+                    interface: "verify.State".to_string(),
+                });
+            }
+        };
+        Ok(out)
+    }
+
+    /// Unpacks the fx operation prefixed with its type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/fxs#FxOperation>
+    pub fn unpack_fx_operation(&self) -> Result<Operation> {
+        let type_id = self.unpack_u32()?;
+        let op = match type_id {
+            // "secp256k1fx.MintOperation"
+            8 => Operation::Secp256k1fxMint(self.unpack_mint_operation()?),
+            // "nftfx.MintOperation"
+            12 => Operation::NftfxMint(self.unpack_nft_mint_operation()?),
+            // "nftfx.TransferOperation"
+            13 => Operation::NftfxTransfer(self.unpack_nft_transfer_operation()?),
+            // "propertyfx.MintOperation"
+            17 => Operation::PropertyfxMint(self.unpack_property_mint_operation()?),
+            // "propertyfx.BurnOperation"
+            18 => Operation::PropertyfxBurn(self.unpack_property_burn_operation()?),
+            _ => {
+                return Err(Error::UnexpectedTypeId {
+                    type_id,
+                    type_name: codec::x_type_name(type_id),
+                    interface: "fxs.FxOperation".to_string(),
+                });
+            }
+        };
+        Ok(op)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::fx::test_output --exact --show-output
+#[test]
+fn test_output() {
+    use crate::ids::short;
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0x01, 0x02])]);
+    let outs = [
+        Output::Secp256k1fxTransfer(key::secp256k1::txs::transfer::Output::new(
+            100,
+            owners.clone(),
+        )),
+        Output::Secp256k1fxMint(key::secp256k1::txs::mint::Output::new(owners.clone())),
+        Output::NftfxMint(nftfx::MintOutput::new(1, owners.clone())),
+        Output::NftfxTransfer(nftfx::TransferOutput::new(1, vec![0x01], owners.clone())),
+        Output::PropertyfxMint(propertyfx::MintOutput::new(owners.clone())),
+        Output::PropertyfxOwned(propertyfx::OwnedOutput::new(owners)),
+    ];
+    let expected_type_ids = [7, 6, 10, 11, 15, 16];
+    let expected_fx_indices = [0, 0, 1, 1, 2, 2];
+
+    for (i, out) in outs.iter().enumerate() {
+        assert_eq!(out.type_id(), expected_type_ids[i]);
+        assert_eq!(out.fx_index(), expected_fx_indices[i]);
+        assert_eq!(out.output_owners().threshold, 1);
+
+        let b = out.to_bytes().unwrap();
+        assert_eq!(&b[..4], &expected_type_ids[i].to_be_bytes());
+        let packer = packer::Packer::load_bytes_for_unpack(1024, &b);
+        assert_eq!(&packer.unpack_fx_output().unwrap(), out);
+        assert_eq!(packer.get_offset(), b.len());
+    }
+
+    // "secp256k1fx.Credential" is not an output
+    let packer = packer::Packer::load_bytes_for_unpack(1024, &9_u32.to_be_bytes());
+    assert!(packer
+        .unpack_fx_output()
+        .unwrap_err()
+        .contains("secp256k1fx.Credential"));
+
+    assert!(
+        Output::Secp256k1fxTransfer(key::secp256k1::txs::transfer::Output::default())
+            .verify()
+            .is_err()
+    );
+
+    // every output verifies its owners
+    let unspendable = key::secp256k1::txs::OutputOwners::new(0, 2, &[short::Id::empty()]);
+    let outs = [
+        Output::Secp256k1fxTransfer(key::secp256k1::txs::transfer::Output::new(
+            100,
+            unspendable.clone(),
+        )),
+        Output::Secp256k1fxMint(key::secp256k1::txs::mint::Output::new(unspendable.clone())),
+        Output::NftfxMint(nftfx::MintOutput::new(1, unspendable.clone())),
+        Output::NftfxTransfer(nftfx::TransferOutput::new(
+            1,
+            vec![0x01],
+            unspendable.clone(),
+        )),
+        Output::PropertyfxMint(propertyfx::MintOutput::new(unspendable.clone())),
+        Output::PropertyfxOwned(propertyfx::OwnedOutput::new(unspendable)),
+    ];
+    for out in outs.iter() {
+        assert!(out.verify().unwrap_err().contains("output is unspendable"));
+    }
+}
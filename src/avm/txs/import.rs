@@ -64,6 +64,35 @@ impl Tx {
         *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        // unpack the second field in the struct
+        let source_chain_id = packer.unpack_bytes(ids::LEN)?;
+        let source_chain_id = ids::Id::from_slice(&source_chain_id);
+
+        // unpack the third field in the struct
+        let source_chain_transferable_inputs = packer.unpack_transferable_inputs()?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let fx_creds = packer.unpack_fx_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        )?);
+
+        Ok(Self {
+            base_tx,
+            source_chain_id,
+            source_chain_transferable_inputs,
+            fx_creds,
+        })
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
@@ -156,9 +185,10 @@ impl Tx {
                         // marshal "platformvm::txs::StakeableLockIn.locktime" field
                         packer.pack_u64(stakeable_lock_in.locktime)?;
 
-                        // "platformvm.StakeableLockIn.TransferableIn" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockIn"
-                        // so no need to encode type ID
+                        // "platformvm.StakeableLockIn.TransferableIn" is an interface
+                        // thus encode the type ID for "secp256k1fx.TransferInput"
+                        packer.pack_u32(key::secp256k1::txs::transfer::Input::type_id())?;
+                        //
                         // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
                         // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
                         // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
@@ -455,4 +485,8 @@ fn test_import_tx_serialization_with_two_signers() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
 }
@@ -1,9 +1,15 @@
+pub mod create_asset;
 pub mod export;
 pub mod fx;
 pub mod import;
+pub mod operation;
 pub mod vertex;
 
-use crate::{codec, errors::Result, hash, ids, key, txs};
+use crate::{
+    codec,
+    errors::{Error, Result},
+    formatting, hash, ids, key, packer, txs,
+};
 use serde::{Deserialize, Serialize};
 
 /// Base transaction.
@@ -67,6 +73,23 @@ impl Tx {
         *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let fx_creds = packer.unpack_fx_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        )?);
+
+        Ok(Self { base_tx, fx_creds })
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
@@ -142,6 +165,96 @@ impl Tx {
     }
 }
 
+/// Signed X-chain tx decoded from its type ID.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#UnsignedTx>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SignedTx {
+    Base(Tx),
+    CreateAsset(create_asset::Tx),
+    Import(import::Tx),
+    Operation(operation::Tx),
+    Export(export::Tx),
+}
+
+impl SignedTx {
+    /// Parses the raw hex-encoded data from the "avm.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        // ref. "utils/formatting.encode" prepends "0x" for "Hex" encoding
+        let d = d.trim_start_matches("0x");
+
+        let decoded =
+            formatting::decode_hex_with_checksum(d.as_bytes()).map_err(|e| Error::Other {
+                message: format!("failed formatting::decode_hex_with_checksum '{}'", e),
+                retryable: false,
+            })?;
+        Self::unpack(&decoded)
+    }
+
+    /// Parses the signed tx bytes, reading the codec version and
+    /// the type ID to decide the underlying tx type.
+    /// Fails with "Error::UnexpectedTypeId" for the type IDs
+    /// that are not transactions (e.g., "secp256k1fx.TransferInput").
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
+        let _codec_version = packer.unpack_u16()?;
+        let type_id = packer.unpack_u32()?;
+
+        let tx = match type_id {
+            // "avm.BaseTx"
+            0 => Self::Base(Tx::unpack(d)?),
+            // "avm.CreateAssetTx"
+            1 => Self::CreateAsset(create_asset::Tx::unpack(d)?),
+            // "avm.OperationTx"
+            2 => Self::Operation(operation::Tx::unpack(d)?),
+            // "avm.ImportTx"
+            3 => Self::Import(import::Tx::unpack(d)?),
+            // "avm.ExportTx"
+            4 => Self::Export(export::Tx::unpack(d)?),
+            _ => {
+                return Err(Error::UnexpectedTypeId {
+                    type_id,
+                    type_name: codec::x_type_name(type_id),
+                    interface: "txs.UnsignedTx".to_string(),
+                });
+            }
+        };
+        Ok(tx)
+    }
+
+    /// Returns the transaction ID computed from the signed tx bytes.
+    pub fn tx_id(&self) -> ids::Id {
+        match self {
+            Self::Base(tx) => tx.tx_id(),
+            Self::CreateAsset(tx) => tx.tx_id(),
+            Self::Import(tx) => tx.tx_id(),
+            Self::Operation(tx) => tx.tx_id(),
+            Self::Export(tx) => tx.tx_id(),
+        }
+    }
+
+    /// Returns the embedded "avax.BaseTx".
+    pub fn base_tx(&self) -> &txs::Tx {
+        match self {
+            Self::Base(tx) => &tx.base_tx,
+            Self::CreateAsset(tx) => &tx.base_tx,
+            Self::Import(tx) => &tx.base_tx,
+            Self::Operation(tx) => &tx.base_tx,
+            Self::Export(tx) => &tx.base_tx,
+        }
+    }
+
+    /// Returns the credentials of the signed tx.
+    pub fn fx_creds(&self) -> &[fx::Credential] {
+        match self {
+            Self::Base(tx) => &tx.fx_creds,
+            Self::CreateAsset(tx) => &tx.fx_creds,
+            Self::Import(tx) => &tx.fx_creds,
+            Self::Operation(tx) => &tx.fx_creds,
+            Self::Export(tx) => &tx.fx_creds,
+        }
+    }
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::test_tx_serialization_with_two_signers --exact --show-output
 /// ref. "avalanchego/vms/avm.TestBaseTxSerialization"
 #[test]
@@ -364,4 +477,44 @@ fn test_tx_serialization_with_two_signers() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx_with_two_signers);
+    assert_eq!(unpacked.tx_id(), tx_with_two_signers.tx_id());
+
+    // re-packing the unpacked tx must produce the same tx ID
+    let mut repacked = Tx::new(txs::Tx {
+        metadata: None,
+        ..unpacked.base_tx.clone()
+    });
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![
+        vec![test_key.clone(), test_key.clone()],
+        vec![test_key.clone(), test_key],
+    ];
+    ab!(repacked.sign(signers)).expect("failed to sign");
+    assert_eq!(repacked.tx_id(), tx_with_two_signers.tx_id());
+
+    let tx_hex = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+    let signed_tx = SignedTx::from_hex(&tx_hex).unwrap();
+    assert_eq!(signed_tx, SignedTx::Base(tx_with_two_signers.clone()));
+    assert_eq!(signed_tx.tx_id(), tx_with_two_signers.tx_id());
+    assert_eq!(signed_tx.fx_creds().len(), 2);
+
+    // trailing bytes are rejected
+    let mut extra = tx_bytes_with_signatures.clone();
+    extra.push(0x00);
+    assert!(SignedTx::unpack(&extra).is_err());
+
+    // "secp256k1fx.TransferInput" is not a tx
+    let mut unknown = tx_bytes_with_signatures;
+    unknown[2..6].copy_from_slice(&5_u32.to_be_bytes());
+    match SignedTx::unpack(&unknown).unwrap_err() {
+        Error::UnexpectedTypeId {
+            type_id, type_name, ..
+        } => {
+            assert_eq!(type_id, 5);
+            assert_eq!(type_name.as_deref(), Some("secp256k1fx.TransferInput"));
+        }
+        e => panic!("unexpected error {}", e),
+    }
 }
@@ -0,0 +1,568 @@
+use std::collections::HashSet;
+
+use crate::{
+    avm::txs::fx,
+    codec,
+    errors::{Error, Result},
+    hash, ids, key, packer, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fx operation on the UTXOs of the asset.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Operation>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct TransferableOperation {
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    /// UTXOs consumed by the operation, in the same order as
+    /// the outputs that the fx operation input spends.
+    #[serde(rename = "inputIDs")]
+    pub utxo_ids: Vec<txs::utxo::Id>,
+
+    /// Packer skips serialization due to serialize:"false" in avalanchego.
+    #[serde(rename = "fxID", skip_serializing_if = "Option::is_none")]
+    pub fx_id: Option<ids::Id>,
+
+    #[serde(rename = "operation")]
+    pub fx_op: fx::Operation,
+}
+
+impl TransferableOperation {
+    pub fn new(asset_id: ids::Id, utxo_ids: Vec<txs::utxo::Id>, fx_op: fx::Operation) -> Self {
+        Self {
+            asset_id,
+            utxo_ids,
+            fx_id: None,
+            fx_op,
+        }
+    }
+
+    /// Packs the operation fields, with the type ID of the fx operation.
+    pub fn pack(&self, packer: &packer::Packer) -> Result<()> {
+        // "Operation.Asset" is struct and serialize:"true"
+        // but embedded inline in the struct "Operation"
+        // so no need to encode type ID
+        packer.pack_bytes(self.asset_id.as_ref())?;
+
+        packer.pack_u32(self.utxo_ids.len() as u32)?;
+        for utxo_id in self.utxo_ids.iter() {
+            packer.pack_bytes(utxo_id.tx_id.as_ref())?;
+            packer.pack_u32(utxo_id.output_index)?;
+        }
+
+        // fx_id is serialize:"false" thus skipping serialization
+
+        // "Operation.Op" is "fxs.FxOperation" interface thus prefixed with its type ID
+        self.fx_op.pack(packer)
+    }
+
+    /// Returns the packed bytes, used for sorting the operations.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let packer = packer::Packer::new((1 << 31) - 1, 128);
+        self.pack(&packer)?;
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    /// ref. "avalanchego/vms/avm/txs.Operation.Verify"
+    pub fn verify(&self) -> Result<()> {
+        if !cmp_manager::is_sorted_and_unique(&self.utxo_ids) {
+            return Err(Error::Other {
+                message: "utxo IDs not sorted and unique".to_string(), // ref. "errNotSortedAndUniqueUTXOIDs"
+                retryable: false,
+            });
+        }
+        self.fx_op.verify()
+    }
+}
+
+impl packer::Packer {
+    /// Unpacks the "avm.Operation" fields.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Operation>
+    pub fn unpack_transferable_operation(&self) -> Result<TransferableOperation> {
+        let asset_id = self.unpack_bytes(ids::LEN)?;
+
+        let utxo_ids_len = self.unpack_u32()?;
+        let mut utxo_ids: Vec<txs::utxo::Id> = Vec::new();
+        for _ in 0..utxo_ids_len {
+            let tx_id = self.unpack_bytes(ids::LEN)?;
+            let output_index = self.unpack_u32()?;
+            utxo_ids.push(txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&tx_id),
+                output_index,
+                ..txs::utxo::Id::default()
+            });
+        }
+
+        let fx_op = self.unpack_fx_operation()?;
+        Ok(TransferableOperation::new(
+            ids::Id::from_slice(&asset_id),
+            utxo_ids,
+            fx_op,
+        ))
+    }
+}
+
+/// Consumes and produces the fx-specific UTXOs (e.g., mints more of
+/// the variable-cap asset, or transfers the NFTs).
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#OperationTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+    pub operations: Vec<TransferableOperation>,
+    pub fx_creds: Vec<fx::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            operations: Vec::new(),
+            fx_creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "avm.OperationTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Sorts the UTXO IDs of each operation, and then the operations
+    /// by their packed bytes.
+    /// ref. "avalanchego/vms/avm/txs.SortOperations"
+    pub fn sort_operations(&mut self) -> Result<()> {
+        let mut keyed: Vec<(Vec<u8>, TransferableOperation)> = Vec::new();
+        for mut op in self.operations.drain(..) {
+            op.utxo_ids.sort();
+            keyed.push((op.to_bytes()?, op));
+        }
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        self.operations = keyed.into_iter().map(|(_, op)| op).collect();
+        Ok(())
+    }
+
+    /// ref. "avalanchego/vms/avm/txs.OperationTx.SyntacticVerify"
+    pub fn verify(&self) -> Result<()> {
+        if self.operations.is_empty() {
+            return Err(Error::Other {
+                message: "an operationTx must have at least one operation".to_string(), // ref. "errNoOperations"
+                retryable: false,
+            });
+        }
+
+        let mut consumed: HashSet<(ids::Id, u32)> = HashSet::new();
+        if let Some(ins) = &self.base_tx.transferable_inputs {
+            for input in ins.iter() {
+                consumed.insert((input.utxo_id.tx_id, input.utxo_id.output_index));
+            }
+        }
+
+        let mut prev: Option<Vec<u8>> = None;
+        for op in self.operations.iter() {
+            op.verify()?;
+            for utxo_id in op.utxo_ids.iter() {
+                if !consumed.insert((utxo_id.tx_id, utxo_id.output_index)) {
+                    return Err(Error::Other {
+                        message: "inputs attempt to double spend an input".to_string(), // ref. "errDoubleSpend"
+                        retryable: false,
+                    });
+                }
+            }
+
+            let b = op.to_bytes()?;
+            if let Some(prev) = &prev {
+                if prev >= &b {
+                    return Err(Error::Other {
+                        message: "operations not sorted and unique".to_string(), // ref. "errOperationsNotSortedUnique"
+                        retryable: false,
+                    });
+                }
+            }
+            prev = Some(b);
+        }
+        Ok(())
+    }
+
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        // unpack the second field in the struct
+        let operations_len = packer.unpack_u32()?;
+        let mut operations: Vec<TransferableOperation> = Vec::new();
+        for _ in 0..operations_len {
+            operations.push(packer.unpack_transferable_operation()?);
+        }
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let fx_creds = packer.unpack_fx_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        )?);
+
+        Ok(Self {
+            base_tx,
+            operations,
+            fx_creds,
+        })
+    }
+
+    /// Signs the inputs and then the operations in order, thus "signers" must
+    /// have one entry per input followed by one entry per operation.
+    /// The credentials for the operations use the credential type of the fx
+    /// (e.g., "nftfx.Credential" for "nftfx.TransferOperation").
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignNFTFx>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignPropertyFx>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        let inputs_len = match &self.base_tx.transferable_inputs {
+            Some(ins) => ins.len(),
+            None => 0,
+        };
+        if signers.len() != inputs_len + self.operations.len() {
+            return Err(Error::Other {
+                message: format!(
+                    "{} signers but {} inputs and {} operations",
+                    signers.len(),
+                    inputs_len,
+                    self.operations.len()
+                ),
+                retryable: false,
+            });
+        }
+
+        // marshal "unsigned tx" with the codec version
+        let type_id = Self::type_id();
+        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+
+        // "avalanchego" marshals the whole struct again for signed bytes
+        // even when the underlying "unsigned_tx" is already once marshaled
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx
+        //
+        // reuse the underlying packer to avoid marshaling the unsigned tx twice
+        // just marshal the next fields in the struct and pack them all together
+        // in the existing packer
+        let b = packer.take_bytes();
+        packer.set_bytes(&b);
+
+        // pack the second field in the struct
+        packer.pack_u32(self.operations.len() as u32)?;
+        for op in self.operations.iter() {
+            op.pack(&packer)?;
+        }
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // compute sha256 for marshaled "unsigned tx" bytes
+        // IMPORTANT: take the hash only for the type "avm.OperationTx" unsigned tx
+        // not other fields -- only hash "avm.OperationTx.*" but not "avm.Tx.Creds"
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#OperationTx
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        // number of of credentials
+        let fx_creds_len = signers.len() as u32;
+        // pack the third field in the struct
+        packer.pack_u32(fx_creds_len)?;
+
+        // sign the hash with the signers (in case of multi-sig)
+        // and combine all signatures into a credential of the fx
+        self.fx_creds = Vec::new();
+        for (i, keys) in signers.iter().enumerate() {
+            let mut sigs: Vec<Vec<u8>> = Vec::new();
+            for k in keys.iter() {
+                let sig = k.sign_digest(&tx_bytes_hash).await?;
+                sigs.push(Vec::from(sig));
+            }
+
+            let mut cred = key::secp256k1::txs::Credential::default();
+            cred.signatures = sigs;
+
+            let mut fx_cred = fx::Credential::default();
+            fx_cred.cred = cred;
+            if i >= inputs_len {
+                fx_cred.fx_index = self.operations[i - inputs_len].fx_op.fx_index();
+            }
+
+            // add a new credential to "Tx"
+            self.fx_creds.push(fx_cred);
+        }
+        for fx_cred in self.fx_creds.iter() {
+            // marshal type ID for "secp256k1fx.Credential", "nftfx.Credential",
+            // or "propertyfx.Credential", all of which embed "secp256k1fx.Credential"
+            packer.pack_u32(fx_cred.type_id())?;
+            packer.pack_u32(fx_cred.cred.signatures.len() as u32)?;
+            for sig in fx_cred.cred.signatures.iter() {
+                packer.pack_bytes(sig)?;
+            }
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
+        // ref. "avalanchego/vms/avm.Tx.SignSECP256K1Fx"
+        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
+        self.base_tx.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::operation::test_operation_tx_serialization --exact --show-output
+#[test]
+fn test_operation_tx_serialization() {
+    use crate::{avm, ids::short, nftfx, propertyfx};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    // ref. "avalanchego/vms/avm/vm_test.go"
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let test_key_short_addr = test_key
+        .to_public_key()
+        .to_short_bytes()
+        .expect("failed short_address_bytes");
+    let test_key_short_addr = short::Id::from_slice(&test_key_short_addr);
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&test_key_short_addr));
+
+    let asset_id = ids::Id::from_slice(&[0xaa, 0xbb]);
+    let utxo_id = |b: u8, output_index: u32| txs::utxo::Id {
+        tx_id: ids::Id::from_slice(&[b]),
+        output_index,
+        ..txs::utxo::Id::default()
+    };
+
+    let base_tx = txs::Tx {
+        network_id: 10,
+        blockchain_id: ids::Id::from_slice(&[5, 4, 3, 2, 1]),
+        transferable_inputs: Some(vec![txs::transferable::Input {
+            utxo_id: utxo_id(0xff, 1),
+            asset_id: ids::Id::from_slice(&[1, 2, 3]),
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 1000,
+                sig_indices: vec![0],
+            }),
+            ..txs::transferable::Input::default()
+        }]),
+        ..txs::Tx::default()
+    };
+    let mut tx = Tx {
+        base_tx: base_tx.clone(),
+        operations: vec![
+            TransferableOperation::new(
+                asset_id,
+                vec![utxo_id(0x03, 0)],
+                fx::Operation::PropertyfxBurn(propertyfx::BurnOperation {
+                    input: key::secp256k1::txs::Input::new(vec![0]),
+                }),
+            ),
+            TransferableOperation::new(
+                asset_id,
+                vec![utxo_id(0x02, 0)],
+                fx::Operation::NftfxTransfer(nftfx::TransferOperation {
+                    input: key::secp256k1::txs::Input::new(vec![0]),
+                    output: nftfx::TransferOutput::new(7, vec![0x01, 0x02], owners.clone()),
+                }),
+            ),
+            TransferableOperation::new(
+                asset_id,
+                vec![utxo_id(0x01, 0)],
+                fx::Operation::Secp256k1fxMint(key::secp256k1::txs::mint::Operation {
+                    mint_input: key::secp256k1::txs::Input::new(vec![0]),
+                    mint_output: key::secp256k1::txs::mint::Output::new(owners.clone()),
+                    transfer_output: key::secp256k1::txs::transfer::Output::new(
+                        5000,
+                        owners.clone(),
+                    ),
+                }),
+            ),
+        ],
+        ..Tx::default()
+    };
+    assert!(tx
+        .verify()
+        .unwrap_err()
+        .contains("operations not sorted and unique"));
+    tx.sort_operations().unwrap();
+    tx.verify().unwrap();
+    assert_eq!(
+        tx.operations
+            .iter()
+            .map(|op| op.fx_op.fx_index())
+            .collect::<Vec<u32>>(),
+        vec![fx::SECP256K1FX_INDEX, fx::NFTFX_INDEX, fx::PROPERTYFX_INDEX]
+    );
+
+    // one signer for the input, and one for each operation
+    let signers = vec![vec![test_key.clone()]; 3];
+    assert!(ab!(tx.sign(signers))
+        .unwrap_err()
+        .contains("3 signers but 1 inputs and 3 operations"));
+    let signers = vec![vec![test_key.clone()]; 4];
+    ab!(tx.sign(signers)).expect("failed to sign");
+    assert_eq!(
+        tx.fx_creds
+            .iter()
+            .map(|c| c.type_id())
+            .collect::<Vec<u32>>(),
+        vec![9, 9, 14, 19]
+    );
+
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let owner_bytes = [
+        // locktime, threshold, addresses.len()
+        &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+            0x00, 0x00, 0x00, 0x01, //
+            0x00, 0x00, 0x00, 0x01, //
+        ][..],
+        test_key_short_addr.as_ref(),
+    ]
+    .concat();
+
+    // check each operation by re-packing it separately
+    let mut ops_bytes: Vec<u8> = Vec::new();
+    for op in tx.operations.iter() {
+        ops_bytes.extend_from_slice(&op.to_bytes().unwrap());
+    }
+    let op0 = [
+        asset_id.as_ref(),
+        &[0x00, 0x00, 0x00, 0x01],
+        ids::Id::from_slice(&[0x01]).as_ref(),
+        &[0x00, 0x00, 0x00, 0x00],
+        &[
+            // secp256k1fx.MintOperation type ID
+            0x00, 0x00, 0x00, 0x08, //
+            //
+            // mint_input.sig_indices
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, //
+        ],
+        &owner_bytes,
+        // transfer_output.amount
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x88],
+        &owner_bytes,
+    ]
+    .concat();
+    let op1 = [
+        asset_id.as_ref(),
+        &[0x00, 0x00, 0x00, 0x01],
+        ids::Id::from_slice(&[0x02]).as_ref(),
+        &[0x00, 0x00, 0x00, 0x00],
+        &[
+            // nftfx.TransferOperation type ID
+            0x00, 0x00, 0x00, 0x0d, //
+            //
+            // input.sig_indices
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, //
+            //
+            // output.group_id
+            0x00, 0x00, 0x00, 0x07, //
+            //
+            // output.payload
+            0x00, 0x00, 0x00, 0x02, 0x01, 0x02, //
+        ],
+        &owner_bytes,
+    ]
+    .concat();
+    let op2 = [
+        asset_id.as_ref(),
+        &[0x00, 0x00, 0x00, 0x01],
+        ids::Id::from_slice(&[0x03]).as_ref(),
+        &[0x00, 0x00, 0x00, 0x00],
+        &[
+            // propertyfx.BurnOperation type ID
+            0x00, 0x00, 0x00, 0x12, //
+            //
+            // input.sig_indices
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, //
+        ],
+    ]
+    .concat();
+    assert_eq!(ops_bytes, [op0, op1, op2].concat());
+
+    let mut expected = base_tx
+        .pack(codec::VERSION, Tx::type_id())
+        .unwrap()
+        .take_bytes()
+        .to_vec();
+    assert_eq!(&expected[2..6], &[0x00, 0x00, 0x00, 0x02]);
+    // operations.len()
+    expected.extend_from_slice(&[0x00, 0x00, 0x00, 0x03]);
+    expected.extend_from_slice(&ops_bytes);
+    assert_eq!(tx_metadata.tx_bytes_with_no_signature, expected);
+
+    let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures;
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
+    assert_eq!(unpacked.fx_creds[2].fx_index, fx::NFTFX_INDEX);
+    assert_eq!(unpacked.fx_creds[3].fx_index, fx::PROPERTYFX_INDEX);
+
+    let signed_tx = avm::txs::SignedTx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(signed_tx, avm::txs::SignedTx::Operation(tx.clone()));
+    assert_eq!(signed_tx.tx_id(), tx.tx_id());
+    assert_eq!(signed_tx.fx_creds().len(), 4);
+
+    // UTXOs consumed by both the base tx and an operation are rejected
+    let mut double_spend = tx.clone();
+    double_spend.operations[0].utxo_ids = vec![utxo_id(0xff, 1)];
+    assert!(double_spend
+        .verify()
+        .unwrap_err()
+        .contains("double spend an input"));
+
+    let mut unsorted = tx;
+    unsorted.operations[0].utxo_ids = vec![utxo_id(0x05, 0), utxo_id(0x04, 0)];
+    assert!(unsorted
+        .verify()
+        .unwrap_err()
+        .contains("utxo IDs not sorted and unique"));
+}
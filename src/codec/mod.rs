@@ -76,6 +76,54 @@ lazy_static! {
         m.insert("signer.Empty".to_string(), 27);
         m.insert("signer.ProofOfPossession".to_string(), 28);
 
+        m.insert("platformvm.BanffProposalBlock".to_string(), 29);
+        m.insert("platformvm.BanffAbortBlock".to_string(), 30);
+        m.insert("platformvm.BanffCommitBlock".to_string(), 31);
+        m.insert("platformvm.BanffStandardBlock".to_string(), 32);
+
         m
     };
+
+    /// ref. <https://github.com/ava-labs/coreth/blob/v0.11.7/plugin/evm/codec.go>
+    ///     (used for encoding Go interface type into a "struct")
+    pub static ref C_ATOMIC_TYPES: HashMap<String, usize> = {
+        let mut m = HashMap::new();
+        m.insert("evm.UnsignedImportTx".to_string(), 0);
+        m.insert("evm.UnsignedExportTx".to_string(), 1);
+
+        // "c.SkipRegistrations(3)"
+
+        m.insert("secp256k1fx.TransferInput".to_string(), 5);
+        m.insert("secp256k1fx.MintOutput".to_string(), 6);
+        m.insert("secp256k1fx.TransferOutput".to_string(), 7);
+        m.insert("secp256k1fx.MintOperation".to_string(), 8);
+        m.insert("secp256k1fx.Credential".to_string(), 9);
+        m.insert("secp256k1fx.Input".to_string(), 10);
+        m.insert("secp256k1fx.OutputOwners".to_string(), 11);
+        m
+    };
+}
+
+/// Returns the registered X-chain type name of the type ID, if any.
+pub fn x_type_name(type_id: u32) -> Option<String> {
+    X_TYPES
+        .iter()
+        .find(|(_, v)| **v as u32 == type_id)
+        .map(|(k, _)| k.clone())
+}
+
+/// Returns the registered P-chain type name of the type ID, if any.
+pub fn p_type_name(type_id: u32) -> Option<String> {
+    P_TYPES
+        .iter()
+        .find(|(_, v)| **v as u32 == type_id)
+        .map(|(k, _)| k.clone())
+}
+
+/// Returns the registered C-chain atomic type name of the type ID, if any.
+pub fn c_atomic_type_name(type_id: u32) -> Option<String> {
+    C_ATOMIC_TYPES
+        .iter()
+        .find(|(_, v)| **v as u32 == type_id)
+        .map(|(k, _)| k.clone())
 }
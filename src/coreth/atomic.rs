@@ -0,0 +1,897 @@
+//! Atomic transactions to move the assets between the C-chain and the
+//! other chains (e.g., X-chain, P-chain) via the shared memory.
+//! ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#Tx>
+use std::cmp::Ordering;
+
+use crate::{
+    codec::{self, serde::hex_0x_primitive_types_h160::Hex0xH160},
+    errors::{Error, Result},
+    formatting, hash, ids, key,
+    packer::{self, Packable, Unpackable},
+    txs,
+};
+use primitive_types::{H160, U256};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Imported funds credited to the EVM account.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#EVMOutput>
+#[serde_as]
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct EvmOutput {
+    #[serde_as(as = "Hex0xH160")]
+    pub address: H160,
+    pub amount: u64,
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+}
+
+impl Default for EvmOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl EvmOutput {
+    pub fn default() -> Self {
+        Self {
+            address: H160::zero(),
+            amount: 0,
+            asset_id: ids::Id::empty(),
+        }
+    }
+}
+
+/// ref. "coreth/plugin/evm.EVMOutput.Compare"
+impl Ord for EvmOutput {
+    fn cmp(&self, other: &EvmOutput) -> Ordering {
+        self.address
+            .cmp(&other.address) // returns when "address"es are not Equal
+            .then_with(
+                || self.asset_id.cmp(&other.asset_id), // if "address"es are Equal, compare "asset_id"
+            )
+    }
+}
+
+impl PartialOrd for EvmOutput {
+    fn partial_cmp(&self, other: &EvmOutput) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Exported funds debited from the EVM account, with the account nonce.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#EVMInput>
+#[serde_as]
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct EvmInput {
+    #[serde_as(as = "Hex0xH160")]
+    pub address: H160,
+    pub amount: u64,
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    pub nonce: u64,
+}
+
+impl Default for EvmInput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl EvmInput {
+    pub fn default() -> Self {
+        Self {
+            address: H160::zero(),
+            amount: 0,
+            asset_id: ids::Id::empty(),
+            nonce: 0,
+        }
+    }
+}
+
+/// ref. "coreth/plugin/evm.EVMInput.Compare"
+impl Ord for EvmInput {
+    fn cmp(&self, other: &EvmInput) -> Ordering {
+        self.address
+            .cmp(&other.address) // returns when "address"es are not Equal
+            .then_with(
+                || self.asset_id.cmp(&other.asset_id), // if "address"es are Equal, compare "asset_id"
+            )
+    }
+}
+
+impl PartialOrd for EvmInput {
+    fn partial_cmp(&self, other: &EvmInput) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Imports the UTXOs from the shared memory of the source chain
+/// into the EVM accounts.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#UnsignedImportTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct UnsignedImportTx {
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: ids::Id,
+    #[serde(rename = "sourceChain")]
+    pub source_chain: ids::Id,
+    #[serde(rename = "importedInputs")]
+    pub imported_inputs: Vec<txs::transferable::Input>,
+    #[serde(rename = "outputs")]
+    pub outs: Vec<EvmOutput>,
+}
+
+impl Default for UnsignedImportTx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl UnsignedImportTx {
+    pub fn default() -> Self {
+        Self {
+            network_id: 0,
+            blockchain_id: ids::Id::empty(),
+            source_chain: ids::Id::empty(),
+            imported_inputs: Vec::new(),
+            outs: Vec::new(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "evm.UnsignedImportTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::C_ATOMIC_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Sorts the imported inputs and the EVM outputs.
+    /// The signers must be in the same order as the sorted inputs.
+    pub fn sort(&mut self) {
+        self.imported_inputs.sort();
+        self.outs.sort();
+    }
+}
+
+/// Exports the funds of the EVM accounts to the shared memory
+/// of the destination chain.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#UnsignedExportTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct UnsignedExportTx {
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: ids::Id,
+    #[serde(rename = "destinationChain")]
+    pub destination_chain: ids::Id,
+    #[serde(rename = "inputs")]
+    pub ins: Vec<EvmInput>,
+    #[serde(rename = "exportedOutputs")]
+    pub exported_outputs: Vec<txs::transferable::Output>,
+}
+
+impl Default for UnsignedExportTx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl UnsignedExportTx {
+    pub fn default() -> Self {
+        Self {
+            network_id: 0,
+            blockchain_id: ids::Id::empty(),
+            destination_chain: ids::Id::empty(),
+            ins: Vec::new(),
+            exported_outputs: Vec::new(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "evm.UnsignedExportTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::C_ATOMIC_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Sorts the EVM inputs and the exported outputs.
+    /// The signers must be in the same order as the sorted inputs.
+    pub fn sort(&mut self) {
+        self.ins.sort();
+        self.exported_outputs.sort();
+    }
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#UnsignedAtomicTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum UnsignedAtomicTx {
+    Import(UnsignedImportTx),
+    Export(UnsignedExportTx),
+}
+
+impl UnsignedAtomicTx {
+    pub fn type_id(&self) -> u32 {
+        match self {
+            Self::Import(_) => UnsignedImportTx::type_id(),
+            Self::Export(_) => UnsignedExportTx::type_id(),
+        }
+    }
+
+    /// Packs the type ID and the unsigned tx fields.
+    pub fn pack(&self, packer: &packer::Packer) -> Result<()> {
+        packer.pack_u32(self.type_id())?;
+        match self {
+            Self::Import(tx) => {
+                packer.pack_u32(tx.network_id)?;
+                packer.pack_bytes(tx.blockchain_id.as_ref())?;
+                packer.pack_bytes(tx.source_chain.as_ref())?;
+                pack_transferable_inputs(packer, &tx.imported_inputs)?;
+                tx.outs.pack_into(packer)
+            }
+            Self::Export(tx) => {
+                packer.pack_u32(tx.network_id)?;
+                packer.pack_bytes(tx.blockchain_id.as_ref())?;
+                packer.pack_bytes(tx.destination_chain.as_ref())?;
+                tx.ins.pack_into(packer)?;
+                pack_transferable_outputs(packer, &tx.exported_outputs)
+            }
+        }
+    }
+
+    /// Returns the unsigned tx bytes with the codec version,
+    /// which is the payload to be signed.
+    pub fn bytes(&self) -> Result<Vec<u8>> {
+        let packer = packer::Packer::new((1 << 31) - 1, 128);
+        packer.pack_u16(codec::VERSION)?;
+        self.pack(&packer)?;
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    /// Returns the amount of gas consumed by the tx once signed, which is charged
+    /// per byte of the signed tx and per signature. Each input is signed by its
+    /// own credential, so the byte length is computed over the signed layout
+    /// without having to sign. Set "fixed_fee" to add the atomic tx base cost
+    /// (activated since Apricot Phase 5).
+    /// ref. "coreth/plugin/evm.UnsignedImportTx.GasUsed"
+    /// ref. "coreth/plugin/evm.UnsignedExportTx.GasUsed"
+    pub fn gas_used(&self, fixed_fee: bool) -> Result<u64> {
+        let overflow = || Error::Other {
+            message: "gas used overflows u64".to_string(), // ref. "math.ErrOverflow"
+            retryable: false,
+        };
+
+        // one credential per input, with one signature per sig index
+        // (one signature per EVM input)
+        let sigs_per_cred: Vec<u64> = match self {
+            Self::Import(tx) => tx
+                .imported_inputs
+                .iter()
+                .map(|i| {
+                    i.transfer_input
+                        .as_ref()
+                        .map_or(0, |ti| ti.sig_indices.len() as u64)
+                })
+                .collect(),
+            Self::Export(tx) => vec![1; tx.ins.len()],
+        };
+        let sigs = sigs_per_cred.iter().sum::<u64>();
+
+        // unsigned bytes, "creds" length, and per credential its type ID,
+        // "signatures" length and the 65-byte signatures
+        let bytes_len = (self.bytes()?.len() as u64)
+            .checked_add(4)
+            .and_then(|l| l.checked_add((sigs_per_cred.len() as u64).checked_mul(4 + 4)?))
+            .and_then(|l| l.checked_add(sigs.checked_mul(65)?))
+            .ok_or_else(overflow)?;
+        let mut cost = bytes_len.checked_mul(TX_BYTES_GAS).ok_or_else(overflow)?;
+
+        cost = sigs
+            .checked_mul(COST_PER_SIGNATURE)
+            .and_then(|c| cost.checked_add(c))
+            .ok_or_else(overflow)?;
+
+        if fixed_fee {
+            cost = cost.checked_add(ATOMIC_TX_BASE_COST).ok_or_else(overflow)?;
+        }
+        Ok(cost)
+    }
+}
+
+/// Conversion rate between the X-chain denomination (nAVAX, 9 decimals)
+/// and the C-chain denomination (wei, 18 decimals).
+/// ref. "coreth/plugin/evm.X2CRate"
+pub const X2C_RATE: u64 = 1_000_000_000;
+
+/// ref. "coreth/plugin/evm.TxBytesGas"
+pub const TX_BYTES_GAS: u64 = 1;
+
+/// ref. "avalanchego/vms/secp256k1fx.CostPerSignature"
+pub const COST_PER_SIGNATURE: u64 = 1_000;
+
+/// ref. "coreth/params.AtomicTxBaseCost"
+pub const ATOMIC_TX_BASE_COST: u64 = 10_000;
+
+/// Computes the atomic tx fee in nAVAX for the given gas and the
+/// current base fee in wei, rounding up.
+/// ref. "coreth/plugin/evm.CalculateDynamicFee"
+pub fn calculate_dynamic_fee(gas: u64, base_fee: U256) -> Result<u64> {
+    let x2c_rate = U256::from(X2C_RATE);
+    let fee = U256::from(gas)
+        .checked_mul(base_fee)
+        .and_then(|f| f.checked_add(x2c_rate - 1))
+        .map(|f| f / x2c_rate);
+    match fee {
+        Some(fee) if fee <= U256::from(u64::MAX) => Ok(fee.as_u64()),
+        _ => Err(Error::Other {
+            message: format!("fee overflow for gas {} and base fee {}", gas, base_fee), // ref. "errFeeOverflow"
+            retryable: false,
+        }),
+    }
+}
+
+/// Signed atomic transaction.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#Tx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    #[serde(rename = "unsignedTx")]
+    pub unsigned_tx: UnsignedAtomicTx,
+    #[serde(rename = "credentials")]
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+
+    /// Only non-empty once signed or unpacked.
+    #[serde(skip)]
+    pub metadata: Option<txs::Metadata>,
+}
+
+impl Tx {
+    pub fn new(unsigned_tx: UnsignedAtomicTx) -> Self {
+        Self {
+            unsigned_tx,
+            creds: Vec::new(),
+            metadata: None,
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.metadata.is_some() {
+            let m = self.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    /// Parses the raw hex-encoded data from the "avax.getAtomicTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        // ref. "utils/formatting.encode" prepends "0x" for "Hex" encoding
+        let d = d.trim_start_matches("0x");
+
+        let decoded =
+            formatting::decode_hex_with_checksum(d.as_bytes()).map_err(|e| Error::Other {
+                message: format!("failed formatting::decode_hex_with_checksum '{}'", e),
+                retryable: false,
+            })?;
+        Self::unpack(&decoded)
+    }
+
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. "coreth/plugin/evm.ExtractAtomicTx"
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
+
+        let codec_version = packer.unpack_u16()?;
+        if codec_version != codec::VERSION {
+            return Err(Error::Other {
+                message: format!("unknown codec version {}", codec_version), // ref. "errUnknownVersion"
+                retryable: false,
+            });
+        }
+
+        let type_id = packer.unpack_u32()?;
+        let unsigned_tx = match type_id {
+            // "evm.UnsignedImportTx"
+            0 => {
+                let network_id = packer.unpack_u32()?;
+                let blockchain_id = ids::Id::unpack_from(&packer)?;
+                let source_chain = ids::Id::unpack_from(&packer)?;
+                let imported_inputs = packer.unpack_transferable_inputs()?.unwrap_or_default();
+                if imported_inputs.iter().any(|i| i.transfer_input.is_none()) {
+                    return Err(Error::Other {
+                        message: "imported inputs must be secp256k1fx.TransferInput".to_string(),
+                        retryable: false,
+                    });
+                }
+                let outs = Vec::<EvmOutput>::unpack_from(&packer)?;
+                UnsignedAtomicTx::Import(UnsignedImportTx {
+                    network_id,
+                    blockchain_id,
+                    source_chain,
+                    imported_inputs,
+                    outs,
+                })
+            }
+            // "evm.UnsignedExportTx"
+            1 => {
+                let network_id = packer.unpack_u32()?;
+                let blockchain_id = ids::Id::unpack_from(&packer)?;
+                let destination_chain = ids::Id::unpack_from(&packer)?;
+                let ins = Vec::<EvmInput>::unpack_from(&packer)?;
+                let exported_outputs = packer.unpack_transferable_outputs()?.unwrap_or_default();
+                if exported_outputs.iter().any(|o| o.transfer_output.is_none()) {
+                    return Err(Error::Other {
+                        message: "exported outputs must be secp256k1fx.TransferOutput".to_string(),
+                        retryable: false,
+                    });
+                }
+                UnsignedAtomicTx::Export(UnsignedExportTx {
+                    network_id,
+                    blockchain_id,
+                    destination_chain,
+                    ins,
+                    exported_outputs,
+                })
+            }
+            _ => {
+                return Err(Error::UnexpectedTypeId {
+                    type_id,
+                    type_name: codec::c_atomic_type_name(type_id),
+                    interface: "evm.UnsignedAtomicTx".to_string(),
+                });
+            }
+        };
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let creds = packer.unpack_credentials()?;
+        let metadata = txs::Metadata::from_unpacked(&packer, d, tx_bytes_with_no_signature_len)?;
+
+        Ok(Self {
+            unsigned_tx,
+            creds,
+            metadata: Some(metadata),
+        })
+    }
+
+    /// Signs the inputs in order, thus "signers" must have one entry
+    /// per imported input (for import) or per EVM input (for export).
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#Tx.Sign>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        // marshal "unsigned tx" with the codec version
+        // ref. "avalanchego/codec.manager.Marshal"
+        let packer = packer::Packer::new((1 << 31) - 1, 128);
+        packer.pack_u16(codec::VERSION)?;
+        self.unsigned_tx.pack(&packer)?;
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // compute sha256 for marshaled "unsigned tx" bytes
+        // IMPORTANT: take the hash only for the type "evm.UnsignedAtomicTx" unsigned tx
+        // not other fields -- only hash "evm.Tx.UnsignedAtomicTx" but not "evm.Tx.Creds"
+        // ref. https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#Tx
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        // number of of credentials
+        let creds_len = signers.len() as u32;
+        // pack the second field in the struct
+        packer.pack_u32(creds_len)?;
+
+        // sign the hash with the signers (in case of multi-sig)
+        // and combine all signatures into a secp256k1fx credential
+        self.creds = Vec::new();
+        for keys in signers.iter() {
+            let mut sigs: Vec<Vec<u8>> = Vec::new();
+            for k in keys.iter() {
+                let sig = k.sign_digest(&tx_bytes_hash).await?;
+                sigs.push(Vec::from(sig));
+            }
+
+            let mut cred = key::secp256k1::txs::Credential::default();
+            cred.signatures = sigs;
+
+            // add a new credential to "Tx"
+            self.creds.push(cred);
+        }
+        if creds_len > 0 {
+            // pack each "cred" which is "secp256k1fx.Credential"
+            // marshal type ID for "secp256k1fx.Credential"
+            let cred_type_id = key::secp256k1::txs::Credential::type_id();
+            for cred in self.creds.iter() {
+                packer.pack_u32(cred_type_id)?;
+                packer.pack_u32(cred.signatures.len() as u32)?;
+                for sig in cred.signatures.iter() {
+                    packer.pack_bytes(sig)?;
+                }
+            }
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        // update "Tx.Metadata" with id/unsigned bytes/bytes
+        // ref. "coreth/plugin/evm.Tx.Sign"
+        // ref. "avalanchego/vms/components/avax.Metadata.Initialize"
+        self.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// Packs the list of "avax.TransferableInput" with "secp256k1fx.TransferInput",
+/// the only input type registered in the coreth codec.
+fn pack_transferable_inputs(
+    packer: &packer::Packer,
+    ins: &[txs::transferable::Input],
+) -> Result<()> {
+    packer.pack_u32(ins.len() as u32)?;
+    for transferable_input in ins.iter() {
+        // "TransferableInput.UTXOID" and "TransferableInput.Asset" are embedded inline
+        // so no need to encode type ID
+        packer.pack_bytes(transferable_input.utxo_id.tx_id.as_ref())?;
+        packer.pack_u32(transferable_input.utxo_id.output_index)?;
+        packer.pack_bytes(transferable_input.asset_id.as_ref())?;
+
+        // fx_id is serialize:"false" thus skipping serialization
+
+        let transfer_input = match &transferable_input.transfer_input {
+            Some(transfer_input) => transfer_input,
+            None => {
+                return Err(Error::Other {
+                    message: "unexpected None TransferableInput transfer_input".to_string(),
+                    retryable: false,
+                })
+            }
+        };
+        // marshal type ID for "secp256k1fx.TransferInput"
+        packer.pack_u32(key::secp256k1::txs::transfer::Input::type_id())?;
+        transfer_input.pack_into(packer)?;
+    }
+    Ok(())
+}
+
+/// Packs the list of "avax.TransferableOutput" with "secp256k1fx.TransferOutput",
+/// the only output type registered in the coreth codec.
+fn pack_transferable_outputs(
+    packer: &packer::Packer,
+    outs: &[txs::transferable::Output],
+) -> Result<()> {
+    packer.pack_u32(outs.len() as u32)?;
+    for transferable_output in outs.iter() {
+        // "TransferableOutput.Asset" is embedded inline
+        // so no need to encode type ID
+        packer.pack_bytes(transferable_output.asset_id.as_ref())?;
+
+        // fx_id is serialize:"false" thus skipping serialization
+
+        let transfer_output = match &transferable_output.transfer_output {
+            Some(transfer_output) => transfer_output,
+            None => {
+                return Err(Error::Other {
+                    message: "unexpected None TransferableOutput transfer_output".to_string(),
+                    retryable: false,
+                })
+            }
+        };
+        // marshal type ID for "secp256k1fx.TransferOutput"
+        packer.pack_u32(key::secp256k1::txs::transfer::Output::type_id())?;
+        transfer_output.pack_into(packer)?;
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- coreth::atomic::test_import_tx_serialization --exact --show-output
+#[test]
+fn test_import_tx_serialization() {
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    // ref. "avalanchego/vms/avm/vm_test.go"
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+
+    let mut unsigned_tx = UnsignedImportTx {
+        network_id: 10,
+        blockchain_id: ids::Id::from_slice(&[0x0c]),
+        source_chain: ids::Id::from_slice(&[0x0b]),
+        imported_inputs: vec![txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[0xff]),
+                output_index: 1,
+                ..txs::utxo::Id::default()
+            },
+            asset_id: ids::Id::from_slice(&[0xaa]),
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 1000,
+                sig_indices: vec![0],
+            }),
+            ..txs::transferable::Input::default()
+        }],
+        outs: vec![
+            EvmOutput {
+                address: H160::from_low_u64_be(2),
+                amount: 500,
+                asset_id: ids::Id::from_slice(&[0xaa]),
+            },
+            EvmOutput {
+                address: H160::from_low_u64_be(1),
+                amount: 400,
+                asset_id: ids::Id::from_slice(&[0xaa]),
+            },
+        ],
+    };
+    unsigned_tx.sort();
+    assert_eq!(unsigned_tx.outs[0].address, H160::from_low_u64_be(1));
+
+    let mut tx = Tx::new(UnsignedAtomicTx::Import(unsigned_tx));
+    ab!(tx.sign(vec![vec![test_key]])).expect("failed to sign");
+    let metadata = tx.metadata.clone().unwrap();
+
+    let expected_unsigned_bytes: Vec<u8> = [
+        &[
+            // codec version
+            0x00, 0x00, //
+            //
+            // evm.UnsignedImportTx type ID
+            0x00, 0x00, 0x00, 0x00, //
+            //
+            // network id
+            0x00, 0x00, 0x00, 0x0a, //
+        ][..],
+        // blockchain id
+        ids::Id::from_slice(&[0x0c]).as_ref(),
+        // source chain
+        ids::Id::from_slice(&[0x0b]).as_ref(),
+        // imported_inputs.len()
+        &[0x00, 0x00, 0x00, 0x01],
+        // imported_inputs[0].utxo_id
+        ids::Id::from_slice(&[0xff]).as_ref(),
+        &[0x00, 0x00, 0x00, 0x01],
+        // imported_inputs[0].asset_id
+        ids::Id::from_slice(&[0xaa]).as_ref(),
+        &[
+            // secp256k1fx.TransferInput type ID
+            0x00, 0x00, 0x00, 0x05, //
+            //
+            // amount
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xe8, //
+            //
+            // sig_indices
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, //
+            //
+            // outs.len()
+            0x00, 0x00, 0x00, 0x02, //
+        ],
+        // outs[0]
+        H160::from_low_u64_be(1).as_ref(),
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x90],
+        ids::Id::from_slice(&[0xaa]).as_ref(),
+        // outs[1]
+        H160::from_low_u64_be(2).as_ref(),
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xf4],
+        ids::Id::from_slice(&[0xaa]).as_ref(),
+    ]
+    .concat();
+    assert_eq!(metadata.tx_bytes_with_no_signature, expected_unsigned_bytes);
+
+    let tx_bytes_with_signatures = metadata.tx_bytes_with_signatures;
+    assert_eq!(
+        &tx_bytes_with_signatures
+            [expected_unsigned_bytes.len()..expected_unsigned_bytes.len() + 12],
+        &[
+            0x00, 0x00, 0x00, 0x01, // creds.len()
+            0x00, 0x00, 0x00, 0x09, // secp256k1fx.Credential type ID
+            0x00, 0x00, 0x00, 0x01, // signatures.len()
+        ]
+    );
+    assert_eq!(
+        tx_bytes_with_signatures.len(),
+        expected_unsigned_bytes.len() + 12 + 65
+    );
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
+    assert_eq!(
+        unpacked.tx_id(),
+        ids::Id::from_slice(&hash::sha256(&tx_bytes_with_signatures))
+    );
+
+    let tx_hex = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+    assert_eq!(Tx::from_hex(&tx_hex).unwrap(), tx);
+
+    let json_encoded = serde_json::to_string(&tx).unwrap();
+    assert!(json_encoded.contains("\"sourceChain\""));
+    assert!(json_encoded.contains("\"address\":\"0x0000000000000000000000000000000000000001\""));
+
+    // trailing bytes are rejected
+    let mut extra = tx_bytes_with_signatures.clone();
+    extra.push(0x00);
+    assert!(Tx::unpack(&extra).is_err());
+
+    // unknown atomic tx type
+    let mut unknown = tx_bytes_with_signatures;
+    unknown[2..6].copy_from_slice(&2_u32.to_be_bytes());
+    assert!(matches!(
+        Tx::unpack(&unknown).unwrap_err(),
+        Error::UnexpectedTypeId { type_id: 2, .. }
+    ));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- coreth::atomic::test_export_tx_serialization --exact --show-output
+#[test]
+fn test_export_tx_serialization() {
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    // ref. "avalanchego/vms/avm/vm_test.go"
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let eth_addr = test_key.to_public_key().to_h160();
+    let short_addr = test_key.to_public_key().to_short_id().unwrap();
+
+    let unsigned_tx = UnsignedExportTx {
+        network_id: 10,
+        blockchain_id: ids::Id::from_slice(&[0x0c]),
+        destination_chain: ids::Id::from_slice(&[0x0b]),
+        ins: vec![EvmInput {
+            address: eth_addr,
+            amount: 2000,
+            asset_id: ids::Id::from_slice(&[0xaa]),
+            nonce: 7,
+        }],
+        exported_outputs: vec![txs::transferable::Output {
+            asset_id: ids::Id::from_slice(&[0xaa]),
+            transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+                1000,
+                key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&short_addr)),
+            )),
+            ..txs::transferable::Output::default()
+        }],
+    };
+
+    let mut tx = Tx::new(UnsignedAtomicTx::Export(unsigned_tx));
+    ab!(tx.sign(vec![vec![test_key]])).expect("failed to sign");
+    let metadata = tx.metadata.clone().unwrap();
+
+    let expected_unsigned_bytes: Vec<u8> = [
+        &[
+            // codec version
+            0x00, 0x00, //
+            //
+            // evm.UnsignedExportTx type ID
+            0x00, 0x00, 0x00, 0x01, //
+            //
+            // network id
+            0x00, 0x00, 0x00, 0x0a, //
+        ][..],
+        // blockchain id
+        ids::Id::from_slice(&[0x0c]).as_ref(),
+        // destination chain
+        ids::Id::from_slice(&[0x0b]).as_ref(),
+        // ins.len()
+        &[0x00, 0x00, 0x00, 0x01],
+        // ins[0]
+        eth_addr.as_ref(),
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0xd0],
+        ids::Id::from_slice(&[0xaa]).as_ref(),
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07],
+        // exported_outputs.len()
+        &[0x00, 0x00, 0x00, 0x01],
+        // exported_outputs[0].asset_id
+        ids::Id::from_slice(&[0xaa]).as_ref(),
+        &[
+            // secp256k1fx.TransferOutput type ID
+            0x00, 0x00, 0x00, 0x07, //
+            //
+            // amount
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0xe8, //
+            //
+            // locktime, threshold, addresses.len()
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+            0x00, 0x00, 0x00, 0x01, //
+            0x00, 0x00, 0x00, 0x01, //
+        ],
+        short_addr.as_ref(),
+    ]
+    .concat();
+    assert_eq!(metadata.tx_bytes_with_no_signature, expected_unsigned_bytes);
+
+    let unpacked = Tx::unpack(&metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
+    assert_eq!(unpacked.creds.len(), 1);
+
+    // the coreth codec only registers "secp256k1fx" outputs
+    let mut missing_output = tx.clone();
+    if let UnsignedAtomicTx::Export(unsigned_tx) = &mut missing_output.unsigned_tx {
+        unsigned_tx.exported_outputs[0].transfer_output = None;
+    }
+    assert!(ab!(missing_output.sign(Vec::<Vec<key::secp256k1::private_key::Key>>::new())).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- coreth::atomic::test_gas_used_and_dynamic_fee --exact --show-output
+#[test]
+fn test_gas_used_and_dynamic_fee() {
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let import_tx = UnsignedAtomicTx::Import(UnsignedImportTx {
+        imported_inputs: vec![txs::transferable::Input {
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 1000,
+                sig_indices: vec![0],
+            }),
+            ..txs::transferable::Input::default()
+        }],
+        outs: vec![EvmOutput::default()],
+        ..UnsignedImportTx::default()
+    });
+
+    // 74-byte header, 88-byte input and 60-byte output with the list lengths
+    assert_eq!(import_tx.bytes().unwrap().len(), 230);
+
+    // gas is charged over the signed tx bytes
+    let mut signed = Tx::new(import_tx.clone());
+    ab!(signed.sign(vec![vec![
+        key::secp256k1::private_key::Key::generate().unwrap()
+    ]]))
+    .unwrap();
+    let signed_len = signed.metadata.unwrap().tx_bytes_with_signatures.len() as u64;
+    assert_eq!(signed_len, 230 + 4 + 4 + 4 + 65);
+    assert_eq!(
+        import_tx.gas_used(false).unwrap(),
+        signed_len + COST_PER_SIGNATURE
+    );
+    assert_eq!(
+        import_tx.gas_used(true).unwrap(),
+        signed_len + COST_PER_SIGNATURE + ATOMIC_TX_BASE_COST
+    );
+
+    let export_tx = UnsignedAtomicTx::Export(UnsignedExportTx {
+        ins: vec![EvmInput::default(), EvmInput::default()],
+        ..UnsignedExportTx::default()
+    });
+    assert_eq!(export_tx.bytes().unwrap().len(), 74 + 4 + 2 * 68 + 4);
+    assert_eq!(
+        export_tx.gas_used(true).unwrap(),
+        74 + 4 + 2 * 68 + 4 + 4 + 2 * (4 + 4 + 65) + 2 * COST_PER_SIGNATURE + ATOMIC_TX_BASE_COST
+    );
+
+    // 25 nAVAX per gas
+    let base_fee = U256::from(25) * U256::from(X2C_RATE);
+    assert_eq!(calculate_dynamic_fee(11_230, base_fee).unwrap(), 280_750);
+
+    // rounds up to the nearest nAVAX
+    assert_eq!(calculate_dynamic_fee(1, U256::from(1)).unwrap(), 1);
+    assert_eq!(calculate_dynamic_fee(0, base_fee).unwrap(), 0);
+
+    assert!(calculate_dynamic_fee(u64::MAX, base_fee).is_err());
+}
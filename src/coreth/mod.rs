@@ -1,2 +1,3 @@
+pub mod atomic;
 pub mod chain_config;
 pub mod genesis;
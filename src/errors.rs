@@ -11,6 +11,14 @@ pub enum Error {
     API { message: String, retryable: bool },
     #[error("failed for other reasons (message: {message:?}, retryable: {retryable:?})")]
     Other { message: String, retryable: bool },
+    /// The decoded type ID is either not registered in the codec or
+    /// not supported for the interface being unpacked.
+    #[error("unexpected type ID {type_id} (registered as {type_name:?}) for {interface:?}")]
+    UnexpectedTypeId {
+        type_id: u32,
+        type_name: Option<String>,
+        interface: String,
+    },
 }
 
 impl Error {
@@ -20,6 +28,7 @@ impl Error {
     pub fn message(&self) -> String {
         match self {
             Error::API { message, .. } | Error::Other { message, .. } => message.clone(),
+            Error::UnexpectedTypeId { .. } => self.to_string(),
         }
     }
 
@@ -29,6 +38,7 @@ impl Error {
     pub fn retryable(&self) -> bool {
         match self {
             Error::API { retryable, .. } | Error::Other { retryable, .. } => *retryable,
+            Error::UnexpectedTypeId { .. } => false,
         }
     }
 
@@ -1,9 +1,31 @@
-use std::io::{self, Error as ioError, ErrorKind};
+use std::{
+    collections::HashMap,
+    io::{self, Error as ioError, ErrorKind},
+};
 
+use crate::{ids, jsonrpc};
 use serde::{Deserialize, Serialize};
 
 /// The chain alias method name
 const ALIAS_METHOD: &str = "admin.aliasChain";
+/// The API endpoint alias method name
+const ALIAS_ENDPOINT_METHOD: &str = "admin.alias";
+/// The get chain aliases method name
+const GET_CHAIN_ALIASES_METHOD: &str = "admin.getChainAliases";
+/// The set logger level method name
+const SET_LOGGER_LEVEL_METHOD: &str = "admin.setLoggerLevel";
+/// The get logger level method name
+const GET_LOGGER_LEVEL_METHOD: &str = "admin.getLoggerLevel";
+/// The load VMs method name
+const LOAD_VMS_METHOD: &str = "admin.loadVMs";
+/// The memory profile method name
+const MEMORY_PROFILE_METHOD: &str = "admin.memoryProfile";
+/// The start CPU profile method name
+const START_CPU_PROFILE_METHOD: &str = "admin.startCPUProfile";
+/// The stop CPU profile method name
+const STOP_CPU_PROFILE_METHOD: &str = "admin.stopCPUProfile";
+/// The lock profile method name
+const LOCK_PROFILE_METHOD: &str = "admin.lockProfile";
 
 /// The request to alias a chain via the admin API.
 /// Ref: https://docs.avax.network/apis/avalanchego/apis/admin#adminaliaschain
@@ -55,9 +77,366 @@ pub struct ChainAliasResponse {
     pub id: u32,
 }
 
+/// Response for the admin requests that return an empty result
+/// (e.g., "admin.alias" and the profiling requests).
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct EmptyResponse {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Set if the request failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// The request to alias an API endpoint via the admin API.
+/// Ref: https://docs.avax.network/apis/avalanchego/apis/admin#adminalias
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct AliasRequest {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Method (admin.alias)
+    pub method: String,
+    /// Alias parameters
+    pub params: Option<AliasParams>,
+}
+
+impl Default for AliasRequest {
+    fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: ALIAS_ENDPOINT_METHOD.to_string(),
+            params: None,
+        }
+    }
+}
+
+impl AliasRequest {
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| ioError::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// Parameters for the API endpoint alias request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct AliasParams {
+    /// The original endpoint of the API, relative to "/ext/" (e.g., "bc/X")
+    pub endpoint: String,
+    /// The newly issued alias, reachable at "/ext/[alias]"
+    pub alias: String,
+}
+
+/// The request to get the aliases of a chain via the admin API.
+/// Ref: https://docs.avax.network/apis/avalanchego/apis/admin#admingetchainaliases
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetChainAliasesRequest {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Method (admin.getChainAliases)
+    pub method: String,
+    /// Chain parameters
+    pub params: Option<GetChainAliasesParams>,
+}
+
+impl Default for GetChainAliasesRequest {
+    fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: GET_CHAIN_ALIASES_METHOD.to_string(),
+            params: None,
+        }
+    }
+}
+
+impl GetChainAliasesRequest {
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| ioError::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// Parameters for the get chain aliases request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetChainAliasesParams {
+    /// The long-form chain ID
+    pub chain: String,
+}
+
+/// Response for the get chain aliases request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetChainAliasesResponse {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Aliases of the chain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetChainAliasesResult>,
+    /// Set if the request failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// Result of the get chain aliases request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct GetChainAliasesResult {
+    /// All aliases of the chain, including the chain ID
+    pub aliases: Vec<String>,
+}
+
+/// The request to set the log and display levels of the loggers via the admin API.
+/// Ref: https://docs.avax.network/apis/avalanchego/apis/admin#adminsetloggerlevel
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct SetLoggerLevelRequest {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Method (admin.setLoggerLevel)
+    pub method: String,
+    /// Logger level parameters
+    pub params: Option<SetLoggerLevelParams>,
+}
+
+impl Default for SetLoggerLevelRequest {
+    fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: SET_LOGGER_LEVEL_METHOD.to_string(),
+            params: None,
+        }
+    }
+}
+
+impl SetLoggerLevelRequest {
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| ioError::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// Parameters for the set logger level request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLoggerLevelParams {
+    /// The logger to update (e.g., "C"), all loggers if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logger_name: Option<String>,
+    /// The level of the logs written to the file (e.g., "debug"), unchanged if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_level: Option<String>,
+    /// The level of the logs displayed (e.g., "info"), unchanged if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_level: Option<String>,
+}
+
+/// The request to get the log and display levels of the loggers via the admin API.
+/// Ref: https://docs.avax.network/apis/avalanchego/apis/admin#admingetloggerlevel
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetLoggerLevelRequest {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Method (admin.getLoggerLevel)
+    pub method: String,
+    /// Logger parameters
+    pub params: Option<GetLoggerLevelParams>,
+}
+
+impl Default for GetLoggerLevelRequest {
+    fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: GET_LOGGER_LEVEL_METHOD.to_string(),
+            params: None,
+        }
+    }
+}
+
+impl GetLoggerLevelRequest {
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| ioError::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// Parameters for the get logger level request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLoggerLevelParams {
+    /// The logger to query, all loggers if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logger_name: Option<String>,
+}
+
+/// Response for the get (and set) logger level requests.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct LoggerLevelResponse {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Levels of the loggers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<LoggerLevelResult>,
+    /// Set if the request failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// Result of the logger level requests.
+/// Older nodes return an empty result for "admin.setLoggerLevel".
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggerLevelResult {
+    /// Levels keyed by the logger name
+    #[serde(default)]
+    pub logger_levels: HashMap<String, LogAndDisplayLevels>,
+}
+
+/// Log and display levels of a logger.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogAndDisplayLevels {
+    pub log_level: String,
+    pub display_level: String,
+}
+
+/// The request to dynamically load the VM binaries in the plugin directory via the admin API.
+/// Ref: https://docs.avax.network/apis/avalanchego/apis/admin#adminloadvms
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct LoadVmsRequest {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Method (admin.loadVMs)
+    pub method: String,
+    /// Empty parameters
+    pub params: Option<EmptyParams>,
+}
+
+impl Default for LoadVmsRequest {
+    fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: LOAD_VMS_METHOD.to_string(),
+            params: Some(EmptyParams {}),
+        }
+    }
+}
+
+impl LoadVmsRequest {
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| ioError::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// Response for the load VMs request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct LoadVmsResponse {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Loaded and failed VMs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<LoadVmsResult>,
+    /// Set if the request failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// Result of the load VMs request.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct LoadVmsResult {
+    /// Aliases keyed by the ID of each newly loaded VM
+    #[serde(rename = "newVMs")]
+    pub new_vms: HashMap<ids::Id, Vec<String>>,
+    /// Errors keyed by the ID of each VM that failed to load
+    #[serde(rename = "failedVMs", default, skip_serializing_if = "Option::is_none")]
+    pub failed_vms: Option<HashMap<ids::Id, String>>,
+}
+
+/// Empty parameters for the admin requests without any input.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct EmptyParams {}
+
+/// The request to write the profile on the node (e.g., "admin.memoryProfile").
+/// Profiles are written to the node's profile directory.
+/// Ref: https://docs.avax.network/apis/avalanchego/apis/admin#adminmemoryprofile
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ProfileRequest {
+    /// Jsonrpc version
+    pub jsonrpc: String,
+    /// Id of request
+    pub id: u32,
+    /// Method (e.g., admin.memoryProfile)
+    pub method: String,
+    /// Empty parameters
+    pub params: Option<EmptyParams>,
+}
+
+impl ProfileRequest {
+    fn new(method: &str) -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: method.to_string(),
+            params: Some(EmptyParams {}),
+        }
+    }
+
+    /// Writes the memory profile to "mem.profile".
+    pub fn memory_profile() -> Self {
+        Self::new(MEMORY_PROFILE_METHOD)
+    }
+
+    /// Starts the CPU profiling, to be written to "cpu.profile" once stopped.
+    pub fn start_cpu_profile() -> Self {
+        Self::new(START_CPU_PROFILE_METHOD)
+    }
+
+    /// Stops the CPU profiling.
+    pub fn stop_cpu_profile() -> Self {
+        Self::new(STOP_CPU_PROFILE_METHOD)
+    }
+
+    /// Writes the mutex statistics to "lock.profile".
+    pub fn lock_profile() -> Self {
+        Self::new(LOCK_PROFILE_METHOD)
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| ioError::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::jsonrpc::admin::{ChainAliasParams, ChainAliasRequest, ChainAliasResponse};
+    use std::str::FromStr;
+
+    use crate::ids;
+    use crate::jsonrpc::admin::{
+        AliasParams, AliasRequest, ChainAliasParams, ChainAliasRequest, ChainAliasResponse,
+        EmptyResponse, GetChainAliasesParams, GetChainAliasesRequest, GetChainAliasesResponse,
+        GetLoggerLevelParams, GetLoggerLevelRequest, LoadVmsRequest, LoadVmsResponse,
+        LoggerLevelResponse, ProfileRequest, SetLoggerLevelParams, SetLoggerLevelRequest,
+    };
     use crate::jsonrpc::{DEFAULT_ID, DEFAULT_VERSION};
 
     #[test]
@@ -89,4 +468,119 @@ mod tests {
 
         assert_eq!(expected, deserialized);
     }
+
+    #[test]
+    fn test_alias() {
+        let req = AliasRequest {
+            params: Some(AliasParams {
+                endpoint: String::from("bc/X"),
+                alias: String::from("myAlias"),
+            }),
+            ..Default::default()
+        };
+        let expected: &str = r#"{"jsonrpc":"2.0","id":1,"method":"admin.alias","params":{"endpoint":"bc/X","alias":"myAlias"}}"#;
+        assert_eq!(req.encode_json().unwrap(), expected);
+
+        let resp: EmptyResponse =
+            serde_json::from_str(r#"{"jsonrpc": "2.0","id": 1,"result": {}}"#).unwrap();
+        assert!(resp.error.is_none());
+
+        let resp: EmptyResponse = serde_json::from_str(
+            r#"{"jsonrpc": "2.0","error": {"code": -32000,"message": "alias already exists","data": null},"id": 1}"#,
+        )
+        .unwrap();
+        assert_eq!(resp.error.unwrap().message, "alias already exists");
+    }
+
+    #[test]
+    fn test_get_chain_aliases() {
+        let req = GetChainAliasesRequest {
+            params: Some(GetChainAliasesParams {
+                chain: String::from("sV6o671RtkGBcno1FiaDbVcFv2sG5aVXMZYzKdP4VQAWmJQnM"),
+            }),
+            ..Default::default()
+        };
+        let expected: &str = r#"{"jsonrpc":"2.0","id":1,"method":"admin.getChainAliases","params":{"chain":"sV6o671RtkGBcno1FiaDbVcFv2sG5aVXMZYzKdP4VQAWmJQnM"}}"#;
+        assert_eq!(req.encode_json().unwrap(), expected);
+
+        let resp: GetChainAliasesResponse = serde_json::from_str(
+            r#"{"jsonrpc": "2.0","result": {"aliases": ["X","avm","2eNy1mUFdmaxXNj1eQHUe7Np4gju9sJsEtWQ4MX3ToiNKuADed"]},"id": 1}"#,
+        )
+        .unwrap();
+        assert_eq!(resp.result.unwrap().aliases.len(), 3);
+    }
+
+    #[test]
+    fn test_logger_level() {
+        let req = SetLoggerLevelRequest {
+            params: Some(SetLoggerLevelParams {
+                logger_name: Some(String::from("C")),
+                log_level: Some(String::from("debug")),
+                display_level: None,
+            }),
+            ..Default::default()
+        };
+        let expected: &str = r#"{"jsonrpc":"2.0","id":1,"method":"admin.setLoggerLevel","params":{"loggerName":"C","logLevel":"debug"}}"#;
+        assert_eq!(req.encode_json().unwrap(), expected);
+
+        let req = GetLoggerLevelRequest {
+            params: Some(GetLoggerLevelParams::default()),
+            ..Default::default()
+        };
+        let expected: &str =
+            r#"{"jsonrpc":"2.0","id":1,"method":"admin.getLoggerLevel","params":{}}"#;
+        assert_eq!(req.encode_json().unwrap(), expected);
+
+        let resp: LoggerLevelResponse = serde_json::from_str(
+            r#"{"jsonrpc": "2.0","result": {"loggerLevels": {"C": {"logLevel": "DEBUG","displayLevel": "INFO"}}},"id": 1}"#,
+        )
+        .unwrap();
+        let levels = resp.result.unwrap().logger_levels;
+        assert_eq!(levels["C"].log_level, "DEBUG");
+        assert_eq!(levels["C"].display_level, "INFO");
+
+        // older nodes return an empty result
+        let resp: LoggerLevelResponse =
+            serde_json::from_str(r#"{"jsonrpc": "2.0","result": {},"id": 1}"#).unwrap();
+        assert!(resp.result.unwrap().logger_levels.is_empty());
+    }
+
+    #[test]
+    fn test_load_vms() {
+        let req = LoadVmsRequest::default();
+        let expected: &str = r#"{"jsonrpc":"2.0","id":1,"method":"admin.loadVMs","params":{}}"#;
+        assert_eq!(req.encode_json().unwrap(), expected);
+
+        let resp: LoadVmsResponse = serde_json::from_str(
+            r#"{"jsonrpc": "2.0","result": {"newVMs": {"tGas3T58KzdjLHhBDMnH2TvrddhqTji5iZAMZ3RXs2NLpSnhH": ["foovm"]},"failedVMs": {"rXJsCSEYXg2TehWxCEEGj6JU2PWKTkd6cBdNLjoe2SpsKD9cy": "error message"}},"id": 1}"#,
+        )
+        .unwrap();
+        let result = resp.result.unwrap();
+        let vm_id = ids::Id::from_str("tGas3T58KzdjLHhBDMnH2TvrddhqTji5iZAMZ3RXs2NLpSnhH").unwrap();
+        assert_eq!(result.new_vms[&vm_id], vec![String::from("foovm")]);
+        assert_eq!(result.failed_vms.unwrap().len(), 1);
+
+        let resp: LoadVmsResponse =
+            serde_json::from_str(r#"{"jsonrpc": "2.0","result": {"newVMs": {}},"id": 1}"#).unwrap();
+        assert!(resp.result.unwrap().failed_vms.is_none());
+    }
+
+    #[test]
+    fn test_profile() {
+        let expected: &str =
+            r#"{"jsonrpc":"2.0","id":1,"method":"admin.memoryProfile","params":{}}"#;
+        assert_eq!(
+            ProfileRequest::memory_profile().encode_json().unwrap(),
+            expected
+        );
+        assert_eq!(
+            ProfileRequest::start_cpu_profile().method,
+            "admin.startCPUProfile"
+        );
+        assert_eq!(
+            ProfileRequest::stop_cpu_profile().method,
+            "admin.stopCPUProfile"
+        );
+        assert_eq!(ProfileRequest::lock_profile().method, "admin.lockProfile");
+    }
 }
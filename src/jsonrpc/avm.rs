@@ -1,6 +1,6 @@
 use std::io::{self, Error, ErrorKind};
 
-use crate::{choices, codec::serde::hex_0x_utxo::Hex0xUtxo, ids, jsonrpc, txs};
+use crate::{avm, choices, codec::serde::hex_0x_utxo::Hex0xUtxo, ids, jsonrpc, txs};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -248,7 +248,14 @@ impl GetUtxosRequest {
 #[serde(rename_all = "camelCase")]
 pub struct GetUtxosParams {
     pub addresses: Vec<String>,
+    /// Chain alias or blockchain Id that exported the atomic UTXOs (e.g., "C").
+    /// Set "None" to fetch the UTXOs on this chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_chain: Option<String>,
     pub limit: u32,
+    /// Set to the "endIndex" of the previous call to fetch the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<super::EndIndex>,
     pub encoding: String,
 }
 
@@ -592,3 +599,446 @@ impl IssueStopVertexRequest {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueStopVertexParams {}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgettx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetTxResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetTxResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgettx>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetTxResult {
+    /// JSON object for "json" encoding, or the hex-encoded tx bytes
+    /// with the checksum for "hex" encoding.
+    pub tx: serde_json::Value,
+    pub encoding: String,
+}
+
+impl GetTxResult {
+    /// Decodes the tx returned with "hex" encoding.
+    pub fn parse_tx(&self) -> crate::errors::Result<avm::txs::SignedTx> {
+        let d = self
+            .tx
+            .as_str()
+            .ok_or_else(|| crate::errors::Error::Other {
+                message: format!("tx is not hex-encoded (encoding '{}')", self.encoding),
+                retryable: false,
+            })?;
+        avm::txs::SignedTx::from_hex(d)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::avm::test_get_tx --exact --show-output
+#[test]
+fn test_get_tx() {
+    use crate::key;
+
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgettx>
+    let resp: GetTxResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "tx": {
+            "unsignedTx": {
+                "networkID": 1,
+                "blockchainID": "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM",
+                "outputs": [],
+                "inputs": [],
+                "memo": "0x"
+            },
+            "credentials": [],
+            "id": "2QouvFWUbjuySRxeX5xMbNCuAaKWfbk5FeEa2JmoF85RKLk2dD"
+        },
+        "encoding": "json"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+    let result = resp.result.unwrap();
+    assert_eq!(result.encoding, "json");
+    assert_eq!(
+        result.tx["id"],
+        "2QouvFWUbjuySRxeX5xMbNCuAaKWfbk5FeEa2JmoF85RKLk2dD"
+    );
+    assert!(result.parse_tx().is_err());
+
+    let mut tx = avm::txs::Tx::new(txs::Tx {
+        network_id: 1,
+        blockchain_id: ids::Id::from_slice(&[0x01]),
+        ..txs::Tx::default()
+    });
+    tokio_test::block_on(tx.sign(Vec::<Vec<key::secp256k1::private_key::Key>>::new())).unwrap();
+    let tx_hex = crate::formatting::encode_hex_with_checksum(
+        &tx.base_tx
+            .metadata
+            .clone()
+            .unwrap()
+            .tx_bytes_with_signatures,
+    );
+    let resp: GetTxResponse = serde_json::from_str(&format!(
+        "{{\"jsonrpc\":\"2.0\",\"result\":{{\"tx\":\"0x{}\",\"encoding\":\"hex\"}},\"id\":1}}",
+        tx_hex
+    ))
+    .unwrap();
+    let parsed = resp.result.unwrap().parse_tx().unwrap();
+    assert_eq!(parsed.tx_id(), tx.tx_id());
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetallbalances>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetAllBalancesResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetAllBalancesResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetallbalances>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetAllBalancesResult {
+    pub balances: Vec<AssetBalance>,
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Balance>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct AssetBalance {
+    /// Asset Id, or its alias (e.g., "AVAX").
+    pub asset: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub balance: u64,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::avm::test_get_all_balances --exact --show-output
+#[test]
+fn test_get_all_balances() {
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetallbalances>
+    let resp: GetAllBalancesResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "balances": [
+            {
+                "asset": "AVAX",
+                "balance": "102"
+            },
+            {
+                "asset": "2sdnziCz37Jov3QSNMXcFRGFJ1tgauaj6L7qfk7yUcRPfQMC79",
+                "balance": "10000"
+            }
+        ]
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetAllBalancesResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetAllBalancesResult {
+            balances: vec![
+                AssetBalance {
+                    asset: String::from("AVAX"),
+                    balance: 102,
+                },
+                AssetBalance {
+                    asset: String::from("2sdnziCz37Jov3QSNMXcFRGFJ1tgauaj6L7qfk7yUcRPfQMC79"),
+                    balance: 10000,
+                },
+            ],
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetaddresstxs>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetAddressTxsRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetAddressTxsParams>,
+}
+
+impl Default for GetAddressTxsRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetAddressTxsRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetaddresstxs>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAddressTxsParams {
+    pub address: String,
+    /// Set to the "cursor" of the previous call to fetch the next page.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<u64>,
+    #[serde(rename = "assetID")]
+    pub asset_id: String,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<u64>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetaddresstxs>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetAddressTxsResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetAddressTxsResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetaddresstxs>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetAddressTxsResult {
+    #[serde(rename = "txIDs")]
+    pub tx_ids: Vec<ids::Id>,
+    /// Cursor to fetch the next page.
+    #[serde_as(as = "DisplayFromStr")]
+    pub cursor: u64,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::avm::test_get_address_txs --exact --show-output
+#[test]
+fn test_get_address_txs() {
+    use std::str::FromStr;
+
+    let mut data = GetAddressTxsRequest::default();
+    data.method = String::from("avm.getAddressTxs");
+    data.params = Some(GetAddressTxsParams {
+        address: String::from("X-local1kpprmfpzzm5lxyene32f6lr7j0aj7gxsu6hp9y"),
+        cursor: Some(2),
+        asset_id: String::from("AVAX"),
+        page_size: Some(20),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"avm.getAddressTxs\",\"params\":{\"address\":\"X-local1kpprmfpzzm5lxyene32f6lr7j0aj7gxsu6hp9y\",\"cursor\":\"2\",\"assetID\":\"AVAX\",\"pageSize\":\"20\"}}"
+    );
+
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetaddresstxs>
+    let resp: GetAddressTxsResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "txIDs": [
+            "SsJF7KKwxiUJkczygwmgLqo3XVRotmpKP8rMp74cpLuNLfwf6"
+        ],
+        "cursor": "1"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetAddressTxsResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetAddressTxsResult {
+            tx_ids: vec![
+                ids::Id::from_str("SsJF7KKwxiUJkczygwmgLqo3XVRotmpKP8rMp74cpLuNLfwf6").unwrap(),
+            ],
+            cursor: 1,
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblockbyheight>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockByHeightRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetBlockByHeightParams>,
+}
+
+impl Default for GetBlockByHeightRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetBlockByHeightRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblockbyheight>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockByHeightParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+    pub encoding: String,
+}
+
+/// Response for "avm.getBlock" and "avm.getBlockByHeight".
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetBlockResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblock>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockResult {
+    /// Hex-encoded block bytes with the checksum.
+    pub block: String,
+    pub encoding: String,
+}
+
+impl GetBlockResult {
+    /// Decodes the hex-encoded block into the raw block bytes.
+    pub fn block_bytes(&self) -> crate::errors::Result<Vec<u8>> {
+        // ref. "utils/formatting.encode" prepends "0x" for "Hex" encoding
+        let d = self.block.trim_start_matches("0x");
+        crate::formatting::decode_hex_with_checksum(d.as_bytes()).map_err(|e| {
+            crate::errors::Error::Other {
+                message: format!("failed formatting::decode_hex_with_checksum '{}'", e),
+                retryable: false,
+            }
+        })
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::avm::test_get_block --exact --show-output
+#[test]
+fn test_get_block() {
+    let mut data = GetBlockByHeightRequest::default();
+    data.method = String::from("avm.getBlockByHeight");
+    data.params = Some(GetBlockByHeightParams {
+        height: 275686313486,
+        encoding: String::from("hex"),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"avm.getBlockByHeight\",\"params\":{\"height\":\"275686313486\",\"encoding\":\"hex\"}}"
+    );
+
+    let blk_bytes = vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x1d, 0x01, 0x02];
+    let resp: GetBlockResponse = serde_json::from_str(&format!(
+        "{{\"jsonrpc\":\"2.0\",\"result\":{{\"block\":\"0x{}\",\"encoding\":\"hex\"}},\"id\":1}}",
+        crate::formatting::encode_hex_with_checksum(&blk_bytes)
+    ))
+    .unwrap();
+    assert_eq!(resp.result.unwrap().block_bytes().unwrap(), blk_bytes);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetheight>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetHeightResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetHeightResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetheight>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetHeightResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::avm::test_get_height --exact --show-output
+#[test]
+fn test_get_height() {
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetheight>
+    let resp: GetHeightResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "height": "5094088"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetHeightResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetHeightResult { height: 5094088 }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
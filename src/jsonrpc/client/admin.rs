@@ -1,85 +1,208 @@
-use std::time::Duration;
-
-use reqwest::{header::CONTENT_TYPE, ClientBuilder};
-
 use crate::{
     errors::{Error, Result},
-    jsonrpc::admin::{ChainAliasParams, ChainAliasRequest, ChainAliasResponse},
-    utils,
+    jsonrpc::admin::{
+        AliasParams, AliasRequest, ChainAliasParams, ChainAliasRequest, ChainAliasResponse,
+        EmptyResponse, GetChainAliasesParams, GetChainAliasesRequest, GetChainAliasesResponse,
+        GetLoggerLevelParams, GetLoggerLevelRequest, LoadVmsRequest, LoadVmsResponse,
+        LoggerLevelResponse, ProfileRequest, SetLoggerLevelParams, SetLoggerLevelRequest,
+    },
 };
 
-/// Set an alias for a chain.
-pub async fn alias_chain(
-    http_rpc: &str,
-    chain: String,
-    alias: String,
-) -> Result<ChainAliasResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
+impl super::Client {
+    #[must_use]
+    pub fn admin(&self) -> Admin {
+        Admin {
+            inner: self.clone(),
+        }
+    }
+}
+
+/// Client for the "admin.*" APIs on the "/ext/admin" path.
+#[derive(Clone, Debug)]
+pub struct Admin {
+    pub inner: super::Client,
+}
+
+impl Admin {
+    /// Set an alias for a chain.
+    pub async fn alias_chain(
+        &self,
+        http_rpc: &str,
+        chain: String,
+        alias: String,
+    ) -> Result<ChainAliasResponse> {
+        let url = super::url(http_rpc, "/ext/admin")?;
+        log::info!("aliasing chain {chain} to {alias} via {url}");
+
+        let data = ChainAliasRequest {
+            params: Some(ChainAliasParams { chain, alias }),
+            ..Default::default()
+        };
+
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
 
-    let url = match (scheme, port) {
-        (Some(scheme), Some(port)) => format!("{scheme}://{host}:{port}/ext/admin"),
-        (Some(scheme), _) => format!("{scheme}://{host}/ext/admin"),
-        _ => format!("http://{host}/ext/admin"),
-    };
-    log::info!("getting network name for {url}");
-
-    let data = ChainAliasRequest {
-        params: Some(ChainAliasParams { chain, alias }),
-        ..Default::default()
-    };
-
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+        self.inner.post(&url, d).await
+    }
+
+    /// Set an alias for an API endpoint (e.g., "bc/X"), reachable at "/ext/[alias]".
+    pub async fn alias(
+        &self,
+        http_rpc: &str,
+        endpoint: &str,
+        alias: &str,
+    ) -> Result<EmptyResponse> {
+        let url = super::url(http_rpc, "/ext/admin")?;
+        log::info!("aliasing endpoint {endpoint} to {alias} via {url}");
+
+        let data = AliasRequest {
+            params: Some(AliasParams {
+                endpoint: endpoint.to_string(),
+                alias: alias.to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
 
-    let resp = req_cli_builder
-        .post(&url)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-        // TODO: check retryable
-        Error::API {
-            message: format!("failed reqwest::Client.send '{}'", e),
+        self.inner.post(&url, d).await
+    }
+
+    /// Get all aliases of a chain.
+    pub async fn get_chain_aliases(
+        &self,
+        http_rpc: &str,
+        chain: &str,
+    ) -> Result<GetChainAliasesResponse> {
+        let url = super::url(http_rpc, "/ext/admin")?;
+        log::info!("getting aliases of chain {chain} via {url}");
+
+        let data = GetChainAliasesRequest {
+            params: Some(GetChainAliasesParams {
+                chain: chain.to_string(),
+            }),
+            ..Default::default()
+        };
+
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
         })?;
 
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+        self.inner.post(&url, d).await
+    }
+
+    /// Set the log and display levels of a logger ("None" for all loggers).
+    /// Levels set to "None" are left unchanged.
+    pub async fn set_logger_level(
+        &self,
+        http_rpc: &str,
+        logger_name: Option<&str>,
+        log_level: Option<&str>,
+        display_level: Option<&str>,
+    ) -> Result<LoggerLevelResponse> {
+        let url = super::url(http_rpc, "/ext/admin")?;
+        log::info!(
+            "setting logger {:?} levels to {:?}/{:?} via {url}",
+            logger_name,
+            log_level,
+            display_level
+        );
+
+        let data = SetLoggerLevelRequest {
+            params: Some(SetLoggerLevelParams {
+                logger_name: logger_name.map(String::from),
+                log_level: log_level.map(String::from),
+                display_level: display_level.map(String::from),
+            }),
+            ..Default::default()
+        };
+
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
+        })?;
+
+        self.inner.post(&url, d).await
+    }
+
+    /// Get the log and display levels of a logger ("None" for all loggers).
+    pub async fn get_logger_level(
+        &self,
+        http_rpc: &str,
+        logger_name: Option<&str>,
+    ) -> Result<LoggerLevelResponse> {
+        let url = super::url(http_rpc, "/ext/admin")?;
+        log::info!("getting logger {:?} levels via {url}", logger_name);
+
+        let data = GetLoggerLevelRequest {
+            params: Some(GetLoggerLevelParams {
+                logger_name: logger_name.map(String::from),
+            }),
+            ..Default::default()
+        };
+
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&url, d).await
+    }
 
-    let response: ChainAliasResponse = serde_json::from_slice(out.as_ref())
-        .map_err(|e| Error::Other {
-            message: format!("failed serde_json::from_slice '{}'", e),
+    /// Load the new VM binaries in the node's plugin directory,
+    /// without restarting the node.
+    pub async fn load_vms(&self, http_rpc: &str) -> Result<LoadVmsResponse> {
+        let url = super::url(http_rpc, "/ext/admin")?;
+        log::info!("loading VMs via {url}");
+
+        let d = LoadVmsRequest::default()
+            .encode_json()
+            .map_err(|e| Error::Other {
+                message: format!("failed encode_json '{}'", e),
+                retryable: false,
+            })?;
+
+        self.inner.post(&url, d).await
+    }
+
+    /// Write the memory profile to "mem.profile" on the node.
+    pub async fn memory_profile(&self, http_rpc: &str) -> Result<EmptyResponse> {
+        self.profile(http_rpc, ProfileRequest::memory_profile())
+            .await
+    }
+
+    /// Start the CPU profiling on the node.
+    pub async fn start_cpu_profile(&self, http_rpc: &str) -> Result<EmptyResponse> {
+        self.profile(http_rpc, ProfileRequest::start_cpu_profile())
+            .await
+    }
+
+    /// Stop the CPU profiling and write the profile to "cpu.profile" on the node.
+    pub async fn stop_cpu_profile(&self, http_rpc: &str) -> Result<EmptyResponse> {
+        self.profile(http_rpc, ProfileRequest::stop_cpu_profile())
+            .await
+    }
+
+    /// Write the mutex statistics to "lock.profile" on the node.
+    pub async fn lock_profile(&self, http_rpc: &str) -> Result<EmptyResponse> {
+        self.profile(http_rpc, ProfileRequest::lock_profile()).await
+    }
+
+    async fn profile(&self, http_rpc: &str, data: ProfileRequest) -> Result<EmptyResponse> {
+        let url = super::url(http_rpc, "/ext/admin")?;
+        log::info!("sending {} via {url}", data.method);
+
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        })
-        .unwrap();
+        })?;
 
-    Ok(response)
+        self.inner.post(&url, d).await
+    }
 }
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use crate::{
+    coreth,
+    errors::{Error, Result},
+    jsonrpc::{self, evm},
+    txs,
+};
+use futures::Stream;
+
+impl super::Client {
+    #[must_use]
+    pub fn c(&self) -> C {
+        C {
+            inner: self.clone(),
+        }
+    }
+}
+
+/// Client for the C-chain "avax.*" APIs on the "/ext/bc/C/avax" path.
+#[derive(Clone, Debug)]
+pub struct C {
+    pub inner: super::Client,
+}
+
+impl C {
+    /// e.g., "avax.issueTx" on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+    /// Sent only once, since retrying an issued tx fails as a duplicate.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxissuetx>
+    pub async fn issue_tx(&self, http_rpc: &str, tx: &str) -> Result<evm::IssueTxResponse> {
+        let u = super::url(http_rpc, "/ext/bc/C/avax")?;
+        log::info!("issuing an atomic transaction via {u}");
+
+        let mut data = evm::IssueTxRequest::default();
+        data.method = String::from("avax.issueTx");
+        let params = evm::IssueTxParams {
+            tx: prefix_manager::prepend_0x(tx),
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post_once(&u, d).await
+    }
+
+    /// e.g., "avax.getAtomicTxStatus" on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetatomictxstatus>
+    pub async fn get_atomic_tx_status(
+        &self,
+        http_rpc: &str,
+        tx_id: &str,
+    ) -> Result<evm::GetAtomicTxStatusResponse> {
+        let u = super::url(http_rpc, "/ext/bc/C/avax")?;
+        log::info!("getting atomic tx status via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avax.getAtomicTxStatus");
+        let mut params = HashMap::new();
+        params.insert(String::from("txID"), String::from(tx_id));
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "avax.getUTXOs" on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+    /// Fetches the first page of atomic UTXOs exported from the "source_chain"
+    /// (e.g., "X", "P") to the C-chain address (e.g., "C-avax1...").
+    /// Use "get_all_utxos" to fetch all UTXOs.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetutxos>
+    pub async fn get_utxos(
+        &self,
+        http_rpc: &str,
+        caddr: &str,
+        source_chain: &str,
+    ) -> Result<evm::GetUtxosResponse> {
+        self.get_utxos_page(
+            http_rpc,
+            &[caddr.to_string()],
+            source_chain,
+            super::MAX_UTXOS_TO_FETCH,
+            None,
+        )
+        .await
+    }
+
+    /// e.g., "avax.getUTXOs" on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+    /// Fetches a single page of atomic UTXOs for the addresses, starting after
+    /// "start_index" (i.e., "endIndex" of the previous page).
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetutxos>
+    pub async fn get_utxos_page(
+        &self,
+        http_rpc: &str,
+        addresses: &[String],
+        source_chain: &str,
+        limit: u32,
+        start_index: Option<jsonrpc::EndIndex>,
+    ) -> Result<evm::GetUtxosResponse> {
+        let u = super::url(http_rpc, "/ext/bc/C/avax")?;
+        log::info!(
+            "getting atomic UTXOs from {source_chain} via {u} for {} addresses (start index {:?})",
+            addresses.len(),
+            start_index
+        );
+
+        let mut data = evm::GetUtxosRequest::default();
+        data.method = String::from("avax.getUTXOs");
+        let params = evm::GetUtxosParams {
+            addresses: addresses.to_vec(),
+            source_chain: source_chain.to_string(),
+            limit,
+            start_index,
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// Fetches all atomic UTXOs exported from the "source_chain" to the addresses
+    /// by paginating "avax.getUTXOs".
+    pub fn get_all_utxos(
+        &self,
+        http_rpc: &str,
+        addresses: &[String],
+        source_chain: &str,
+    ) -> impl Stream<Item = Result<txs::utxo::Utxo>> {
+        let (cli, http_rpc, source_chain) =
+            (self.clone(), http_rpc.to_string(), source_chain.to_string());
+        super::get_all_utxos("avax.getUTXOs", addresses, move |addresses, start_index| {
+            let (cli, http_rpc, source_chain) =
+                (cli.clone(), http_rpc.clone(), source_chain.clone());
+            async move {
+                cli.get_utxos_page(
+                    &http_rpc,
+                    &addresses,
+                    &source_chain,
+                    super::MAX_UTXOS_TO_FETCH,
+                    start_index,
+                )
+                .await
+            }
+        })
+    }
+
+    /// e.g., "avax.getAtomicTx" on "http://[ADDR]:9650" and "/ext/bc/C/avax" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetatomictx>
+    pub async fn get_atomic_tx(
+        &self,
+        http_rpc: &str,
+        tx_id: &str,
+    ) -> Result<evm::GetAtomicTxResponse> {
+        let u = super::url(http_rpc, "/ext/bc/C/avax")?;
+        log::info!("getting atomic tx {tx_id} via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avax.getAtomicTx");
+        let mut params = HashMap::new();
+        params.insert(String::from("txID"), String::from(tx_id));
+        params.insert(String::from("encoding"), String::from("hex")); // don't use "cb58"
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// Returns the fee in nAVAX for an atomic tx that consumes "gas_used"
+    /// (see "coreth::atomic::UnsignedAtomicTx::gas_used") at the current base fee.
+    /// Coreth does not serve the atomic tx fee on the "/ext/bc/C/avax" path,
+    /// so this fetches "eth_baseFee" from the "/ext/bc/C/rpc" path instead.
+    /// ref. "coreth/plugin/evm.CalculateDynamicFee"
+    pub async fn get_atomic_tx_fee(&self, http_rpc: &str, gas_used: u64) -> Result<u64> {
+        let u = super::url(http_rpc, "/ext/bc/C/rpc")?;
+
+        let base_fee = super::evm::base_fee(&u).await?;
+        coreth::atomic::calculate_dynamic_fee(gas_used, base_fee)
+    }
+}
@@ -48,3 +48,55 @@ pub async fn get_balance(rpc_ep: &str, eth_addr: H160) -> Result<U256> {
                 retryable: false,
             })
 }
+
+/// Fetches the current base fee in wei from "{http_rpc}/ext/bc/C/rpc",
+/// which is used to compute the dynamic fees of the C-chain atomic txs.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#eth_basefee>
+pub async fn base_fee(rpc_ep: &str) -> Result<U256> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| {
+            // TODO: check retryable
+            Error::API {
+                message: format!("failed to create provider '{}'", e),
+                retryable: false,
+            }
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!("getting base fee via {rpc_ep}");
+    provider
+        .request::<_, U256>("eth_baseFee", ())
+        .await
+        .map_err(|e|
+            // TODO: check retryable
+            Error::API {
+                message: format!("failed eth_baseFee '{}'", e),
+                retryable: false,
+            })
+}
+
+/// Fetches the nonce (the number of transactions sent from the address)
+/// from "{http_rpc}/ext/bc/{chain_id_alias}/rpc".
+/// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactioncount>
+pub async fn get_transaction_count(rpc_ep: &str, eth_addr: H160) -> Result<U256> {
+    let provider = Provider::<Http>::try_from(rpc_ep)
+        .map_err(|e| {
+            // TODO: check retryable
+            Error::API {
+                message: format!("failed to create provider '{}'", e),
+                retryable: false,
+            }
+        })?
+        .interval(Duration::from_millis(2000u64));
+
+    log::info!("getting transaction count for {} via {rpc_ep}", eth_addr);
+    provider
+        .get_transaction_count(eth_addr, None)
+        .await
+        .map_err(|e|
+            // TODO: check retryable
+            Error::API {
+                message: format!("failed get_transaction_count '{}'", e),
+                retryable: false,
+            })
+}
@@ -1,64 +1,218 @@
-use std::{sync::Arc, time::Duration};
+use std::{collections::BTreeMap, time::Duration};
 
 use crate::{
     errors::{Error, Result},
     jsonrpc::health,
 };
-use reqwest::ClientBuilder;
+use futures::future::join_all;
+use tokio::time::{self, sleep, Instant};
 
-/// "If a single piece of data must be accessible from more than one task
-/// concurrently, then it must be shared using synchronization primitives such as Arc."
-/// ref. <https://tokio.rs/tokio/tutorial/spawning>
-pub async fn check(http_rpc: Arc<String>, liveness: bool) -> Result<health::Response> {
-    let url_path = {
+impl super::Client {
+    #[must_use]
+    pub fn health(&self) -> Health {
+        Health {
+            inner: self.clone(),
+        }
+    }
+}
+
+/// Client for the health APIs on the "/ext/health" path.
+#[derive(Clone, Debug)]
+pub struct Health {
+    pub inner: super::Client,
+}
+
+impl Health {
+    /// Checks the health (or liveness) of the node.
+    /// The response is decoded even for the unhealthy node,
+    /// which returns the non-success status code with the details.
+    pub async fn check(&self, http_rpc: &str, liveness: bool) -> Result<health::Response> {
         if liveness {
-            "ext/health/liveness"
+            self.liveness(http_rpc, &[]).await
         } else {
-            "ext/health"
+            self.health(http_rpc, &[]).await
         }
-    };
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
+    }
+
+    /// "If a single piece of data must be accessible from more than one task
+    /// concurrently, then it must be shared using synchronization primitives such as Arc."
+    /// ref. <https://tokio.rs/tokio/tutorial/spawning>
+    pub async fn spawn_check(&self, http_rpc: &str, liveness: bool) -> Result<health::Response> {
+        let cli = self.clone();
+        let http_rpc = http_rpc.to_string();
+        tokio::spawn(async move { cli.check(&http_rpc, liveness).await })
+            .await
+            .expect("failed spawn await")
+    }
+
+    /// e.g., "GET /ext/health" on "http://[ADDR]:9650".
+    /// Reports all health checks, or only the ones with the "tags"
+    /// (e.g., subnet Ids or check names) if not empty.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/health#healthhealth>
+    pub async fn health(&self, http_rpc: &str, tags: &[String]) -> Result<health::Response> {
+        let u = url_with_tags(http_rpc, "/ext/health", tags)?;
+        log::info!("checking health via {u}");
+        self.inner.get(&u).await
+    }
+
+    /// e.g., "GET /ext/health/readiness" on "http://[ADDR]:9650".
+    /// Reports whether the node has finished initializing (e.g., bootstrapping).
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/health#healthreadiness>
+    pub async fn readiness(&self, http_rpc: &str, tags: &[String]) -> Result<health::Response> {
+        let u = url_with_tags(http_rpc, "/ext/health/readiness", tags)?;
+        log::info!("checking readiness via {u}");
+        self.inner.get(&u).await
+    }
+
+    /// e.g., "GET /ext/health/liveness" on "http://[ADDR]:9650".
+    /// Reports whether the node is running and should not be restarted.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/health#healthliveness>
+    pub async fn liveness(&self, http_rpc: &str, tags: &[String]) -> Result<health::Response> {
+        let u = url_with_tags(http_rpc, "/ext/health/liveness", tags)?;
+        log::info!("checking liveness via {u}");
+        self.inner.get(&u).await
+    }
+
+    /// Polls "/ext/health" of all endpoints every "poll_interval" until all of them
+    /// are healthy. Fails with the failing checks of each unhealthy endpoint
+    /// (e.g., "bootstrapped" while the chains are still bootstrapping) after "timeout".
+    /// The endpoints are checked concurrently, and each check is cut off at
+    /// the remaining time, so a dead host with retries cannot overshoot "timeout".
+    pub async fn wait_until_healthy(
+        &self,
+        urls: &[String],
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<()> {
+        log::info!(
+            "waiting for {} endpoints to be healthy (timeout {:?})",
+            urls.len(),
+            timeout
+        );
+
+        let start = Instant::now();
+        loop {
+            let remaining = timeout.saturating_sub(start.elapsed());
+            let checks = urls
+                .iter()
+                .map(|u| time::timeout(remaining, self.health(u, &[])));
+            let results = join_all(checks).await;
+
+            // endpoint to its failing checks, or the request error
+            let mut unhealthy: BTreeMap<&str, String> = BTreeMap::new();
+            for (u, res) in urls.iter().zip(results) {
+                match res {
+                    Ok(Ok(resp)) if resp.healthy => {}
+                    Ok(Ok(resp)) => {
+                        let failing: Vec<String> = resp
+                            .failing_checks()
+                            .into_iter()
+                            .map(|(name, e)| format!("{name}: {e}"))
+                            .collect();
+                        if failing.is_empty() {
+                            unhealthy.insert(u, String::from("unhealthy"));
+                        } else {
+                            unhealthy.insert(u, failing.join(", "));
+                        }
+                    }
+                    Ok(Err(e)) => {
+                        unhealthy.insert(u, e.message());
+                    }
+                    Err(_) => {
+                        unhealthy.insert(u, String::from("health check timed out"));
+                    }
+                }
             }
-        })?;
-    let resp = req_cli_builder
-        .get(format!("{}/{}", http_rpc, url_path).as_str())
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
+            if unhealthy.is_empty() {
+                log::info!("all endpoints healthy in {:?}", start.elapsed());
+                return Ok(());
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed.ge(&timeout) {
+                let details: Vec<String> = unhealthy
+                    .iter()
+                    .map(|(u, failing)| format!("{u} [{failing}]"))
+                    .collect();
+                return Err(Error::API {
+                    message: format!(
+                        "{} endpoints not healthy in {:?}: {}",
+                        unhealthy.len(),
+                        timeout,
+                        details.join("; ")
+                    ),
+                    retryable: true,
+                });
+            }
+
+            for (u, failing) in unhealthy.iter() {
+                log::warn!("{u} not healthy yet [{failing}] (elapsed {:?})", elapsed);
+            }
+            sleep(poll_interval.min(timeout - elapsed)).await;
+        }
+    }
+}
+
+/// Returns the URL of the health API path with the "tag" query parameters.
+fn url_with_tags(http_rpc: &str, path: &str, tags: &[String]) -> Result<String> {
+    let u = super::url(http_rpc, path)?;
+    if tags.is_empty() {
+        return Ok(u);
+    }
+    let u = reqwest::Url::parse_with_params(&u, tags.iter().map(|t| ("tag", t))).map_err(|e| {
         Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+            message: format!("failed Url::parse_with_params '{}'", e),
             retryable: false,
         }
     })?;
-    let out: Vec<u8> = out.into();
+    Ok(u.to_string())
+}
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::client::health::test_url_with_tags --exact --show-output
+#[test]
+fn test_url_with_tags() {
+    assert_eq!(
+        url_with_tags("http://127.0.0.1:9650", "/ext/health", &[]).unwrap(),
+        "http://127.0.0.1:9650/ext/health"
+    );
+    assert_eq!(
+        url_with_tags(
+            "http://127.0.0.1:9650",
+            "/ext/health/readiness",
+            &[
+                String::from("29uVeLPJB1eQJkzRemU8g8wZDw5uJRqpab5U2mX9euieVwiEbL"),
+                String::from("bootstrapped"),
+            ]
+        )
+        .unwrap(),
+        "http://127.0.0.1:9650/ext/health/readiness?tag=29uVeLPJB1eQJkzRemU8g8wZDw5uJRqpab5U2mX9euieVwiEbL&tag=bootstrapped"
+    );
 }
 
-pub async fn spawn_check(http_rpc: &str, liveness: bool) -> Result<health::Response> {
-    let ep_arc = Arc::new(http_rpc.to_string());
-    tokio::spawn(async move { check(ep_arc, liveness).await })
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::client::health::test_wait_until_healthy_timeout --exact --show-output
+#[tokio::test]
+async fn test_wait_until_healthy_timeout() {
+    use tokio::net::TcpListener;
+
+    // accepts the connections but never responds
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let u = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut streams = Vec::new();
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            streams.push(stream);
+        }
+    });
+
+    let cli = super::Client::new().unwrap();
+    let start = Instant::now();
+    let err = cli
+        .health()
+        .wait_until_healthy(&[u], Duration::from_millis(300), Duration::from_millis(50))
         .await
-        .expect("failed spawn await")
+        .unwrap_err();
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(err.message().contains("1 endpoints not healthy"));
+    assert!(err.message().contains("health check timed out"));
 }
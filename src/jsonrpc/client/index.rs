@@ -0,0 +1,357 @@
+use std::{collections::HashMap, future::Future, time::Duration};
+
+use crate::{
+    errors::{Error, Result},
+    ids,
+    jsonrpc::{self, index},
+};
+use futures::{stream, Stream, TryStreamExt};
+use tokio::time::sleep;
+
+/// Index of the accepted X-chain transactions.
+pub const X_TX: &str = "/ext/index/X/tx";
+/// Index of the accepted X-chain vertices (before linearization).
+pub const X_VTX: &str = "/ext/index/X/vtx";
+/// Index of the accepted X-chain blocks (after linearization).
+pub const X_BLOCK: &str = "/ext/index/X/block";
+/// Index of the accepted P-chain blocks.
+pub const P_BLOCK: &str = "/ext/index/P/block";
+/// Index of the accepted C-chain blocks.
+pub const C_BLOCK: &str = "/ext/index/C/block";
+
+/// Error message returned by "index.getLastAccepted" when the index is empty.
+/// ref. "avalanchego/indexer#errNoneAccepted"
+const ERR_NONE_ACCEPTED: &str = "no containers have been accepted";
+
+impl super::Client {
+    #[must_use]
+    pub fn index(&self) -> Index {
+        Index {
+            inner: self.clone(),
+        }
+    }
+}
+
+/// Client for the "index.*" APIs on the "/ext/index/[CHAIN]/[tx|block|vtx]" paths
+/// (e.g., "X_TX"). The node must run with "index-enabled" set to true.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api>
+#[derive(Clone, Debug)]
+pub struct Index {
+    pub inner: super::Client,
+}
+
+impl Index {
+    /// e.g., "index.getLastAccepted" on "http://[ADDR]:9650" and "/ext/index/X/tx" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetlastaccepted>
+    pub async fn get_last_accepted(
+        &self,
+        http_rpc: &str,
+        index_path: &str,
+    ) -> Result<index::GetContainerResponse> {
+        let u = super::url(http_rpc, index_path)?;
+        log::info!("getting last accepted container via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("index.getLastAccepted");
+        let mut params = HashMap::new();
+        params.insert(String::from("encoding"), String::from("hex"));
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "index.getContainerByIndex" on "http://[ADDR]:9650" and "/ext/index/X/tx" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerbyindex>
+    pub async fn get_container_by_index(
+        &self,
+        http_rpc: &str,
+        index_path: &str,
+        index: u64,
+    ) -> Result<index::GetContainerResponse> {
+        let u = super::url(http_rpc, index_path)?;
+        log::info!("getting container at index {index} via {u}");
+
+        let mut data = index::GetContainerByIndexRequest::default();
+        data.method = String::from("index.getContainerByIndex");
+        let params = index::GetContainerByIndexParams {
+            index,
+            encoding: String::from("hex"),
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "index.getContainerRange" on "http://[ADDR]:9650" and "/ext/index/X/tx" path.
+    /// "num_to_fetch" must be up to "index::MAX_FETCHED_BY_RANGE".
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange>
+    pub async fn get_container_range(
+        &self,
+        http_rpc: &str,
+        index_path: &str,
+        start_index: u64,
+        num_to_fetch: u64,
+    ) -> Result<index::GetContainerRangeResponse> {
+        let u = super::url(http_rpc, index_path)?;
+        log::info!("getting {num_to_fetch} containers from index {start_index} via {u}");
+
+        let mut data = index::GetContainerRangeRequest::default();
+        data.method = String::from("index.getContainerRange");
+        let params = index::GetContainerRangeParams {
+            start_index,
+            num_to_fetch,
+            encoding: String::from("hex"),
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "index.getIndex" on "http://[ADDR]:9650" and "/ext/index/X/tx" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetindex>
+    pub async fn get_index(
+        &self,
+        http_rpc: &str,
+        index_path: &str,
+        container_id: ids::Id,
+    ) -> Result<index::GetIndexResponse> {
+        let u = super::url(http_rpc, index_path)?;
+        log::info!("getting index of {container_id} via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("index.getIndex");
+        let mut params = HashMap::new();
+        params.insert(String::from("id"), container_id.to_string());
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "index.getContainerByID" on "http://[ADDR]:9650" and "/ext/index/X/tx" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerbyid>
+    pub async fn get_container_by_id(
+        &self,
+        http_rpc: &str,
+        index_path: &str,
+        container_id: ids::Id,
+    ) -> Result<index::GetContainerResponse> {
+        let u = super::url(http_rpc, index_path)?;
+        log::info!("getting container {container_id} via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("index.getContainerByID");
+        let mut params = HashMap::new();
+        params.insert(String::from("id"), container_id.to_string());
+        params.insert(String::from("encoding"), String::from("hex"));
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "index.isAccepted" on "http://[ADDR]:9650" and "/ext/index/X/tx" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexisaccepted>
+    pub async fn is_accepted(
+        &self,
+        http_rpc: &str,
+        index_path: &str,
+        container_id: ids::Id,
+    ) -> Result<index::IsAcceptedResponse> {
+        let u = super::url(http_rpc, index_path)?;
+        log::info!("checking if {container_id} is accepted via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("index.isAccepted");
+        let mut params = HashMap::new();
+        params.insert(String::from("id"), container_id.to_string());
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        self.inner.post(&u, d).await
+    }
+
+    /// Fetches the next batch of accepted containers starting at "next_index",
+    /// up to the last accepted container. Returns empty if there is none yet.
+    pub async fn next_containers(
+        &self,
+        http_rpc: &str,
+        index_path: &str,
+        next_index: u64,
+    ) -> Result<Vec<index::Container>> {
+        let resp = self.get_last_accepted(http_rpc, index_path).await?;
+        if let Some(e) = resp.error {
+            if e.message.contains(ERR_NONE_ACCEPTED) {
+                return Ok(Vec::new());
+            }
+            return Err(Error::API {
+                message: format!("failed index.getLastAccepted '{}'", e.message),
+                retryable: super::is_retryable_error_code(e.code),
+            });
+        }
+        let last_index = match resp.result {
+            Some(last) if last.index >= next_index => last.index,
+            _ => return Ok(Vec::new()),
+        };
+
+        let num_to_fetch = (last_index - next_index + 1).min(index::MAX_FETCHED_BY_RANGE);
+        let resp = self
+            .get_container_range(http_rpc, index_path, next_index, num_to_fetch)
+            .await?;
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!("failed index.getContainerRange '{}'", e.message),
+                retryable: super::is_retryable_error_code(e.code),
+            });
+        }
+        Ok(resp.result.unwrap_or_default().containers)
+    }
+
+    /// Follows the index and yields the accepted containers in order, starting at "start_index".
+    /// The stream never ends on its own: it polls every "poll_interval" once it catches up
+    /// with the last accepted container, and waits out the retryable errors (e.g., node restarts).
+    /// To resume after a restart, persist the "index" of the last processed container and
+    /// pass the next index as "start_index".
+    pub fn accepted_containers(
+        &self,
+        http_rpc: &str,
+        index_path: &str,
+        start_index: u64,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<index::Container>> {
+        let cli = self.clone();
+        let http_rpc = http_rpc.to_string();
+        let index_path = index_path.to_string();
+        follow_containers(start_index, poll_interval, move |next_index| {
+            let (cli, http_rpc, index_path) = (cli.clone(), http_rpc.clone(), index_path.clone());
+            async move {
+                cli.next_containers(&http_rpc, &index_path, next_index)
+                    .await
+            }
+        })
+    }
+}
+
+/// Yields the containers returned by "fetch" in the index order, starting at "start_index".
+/// "fetch" is called with the next index to fetch, and returns the containers
+/// from that index (empty if there is none yet, in which case it polls again after
+/// "poll_interval"). Retryable errors are retried after "poll_interval", and the
+/// stream ends after yielding a non-retryable error.
+pub fn follow_containers<F, Fut>(
+    start_index: u64,
+    poll_interval: Duration,
+    fetch: F,
+) -> impl Stream<Item = Result<index::Container>>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<Vec<index::Container>>>,
+{
+    stream::try_unfold(
+        (fetch, start_index),
+        move |(mut fetch, next_index): (F, u64)| async move {
+            loop {
+                let containers = match fetch(next_index).await {
+                    Ok(containers) => containers,
+                    Err(e) if e.retryable() => {
+                        log::warn!(
+                            "failed to fetch containers from index {next_index} '{}', retrying in {:?}",
+                            e.message(),
+                            poll_interval
+                        );
+                        sleep(poll_interval).await;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                };
+
+                // drop the containers before the requested index, if any
+                let containers: Vec<index::Container> = containers
+                    .into_iter()
+                    .filter(|c| c.index >= next_index)
+                    .collect();
+                match containers.last() {
+                    Some(last) => {
+                        let next_index = last.index + 1;
+                        return Ok(Some((containers, (fetch, next_index))));
+                    }
+                    None => {
+                        log::debug!("no container at index {next_index} yet, polling in {:?}", poll_interval);
+                        sleep(poll_interval).await;
+                    }
+                }
+            }
+        },
+    )
+    .map_ok(|containers| stream::iter(containers.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::client::index::test_follow_containers --exact --show-output
+#[tokio::test]
+async fn test_follow_containers() {
+    use std::collections::VecDeque;
+
+    use chrono::{TimeZone, Utc};
+    use futures::StreamExt;
+
+    let container = |i: u64| index::Container {
+        id: ids::Id::from_slice(&i.to_be_bytes()),
+        bytes: String::new(),
+        timestamp: Utc.timestamp_opt(0, 0).unwrap(),
+        encoding: String::from("hex"),
+        index: i,
+    };
+
+    let mut responses: VecDeque<Result<Vec<index::Container>>> = VecDeque::from(vec![
+        Ok(vec![container(5), container(6)]),
+        // caught up with the last accepted
+        Ok(Vec::new()),
+        // node restarting
+        Err(Error::API {
+            message: String::from("connection refused"),
+            retryable: true,
+        }),
+        // stale container before the requested index
+        Ok(vec![container(6), container(7)]),
+        Err(Error::API {
+            message: String::from("invalid index path"),
+            retryable: false,
+        }),
+    ]);
+    let mut next_indexes = Vec::new();
+    let got: Vec<Result<index::Container>> =
+        follow_containers(5, Duration::from_millis(1), |next_index| {
+            next_indexes.push(next_index);
+            let resp = responses.pop_front().unwrap();
+            async move { resp }
+        })
+        .collect()
+        .await;
+
+    assert_eq!(got.len(), 4);
+    let indexes: Vec<u64> = got[..3].iter().map(|c| c.as_ref().unwrap().index).collect();
+    assert_eq!(indexes, vec![5, 6, 7]);
+    assert!(got[3].is_err());
+    assert_eq!(next_indexes, vec![5, 7, 7, 7, 8]);
+}
@@ -0,0 +1,34 @@
+use crate::{errors::Result, jsonrpc::metrics};
+
+impl super::Client {
+    #[must_use]
+    pub fn metrics(&self) -> Metrics {
+        Metrics {
+            inner: self.clone(),
+        }
+    }
+}
+
+/// Client for the Prometheus metrics on the "/ext/metrics" path.
+#[derive(Clone, Debug)]
+pub struct Metrics {
+    pub inner: super::Client,
+}
+
+impl Metrics {
+    /// e.g., "GET /ext/metrics" on "http://[ADDR]:9650".
+    /// Fetches the Prometheus text exposition format as-is.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/metrics>
+    pub async fn fetch(&self, http_rpc: &str) -> Result<String> {
+        let u = super::url(http_rpc, "/ext/metrics")?;
+        log::info!("fetching metrics via {u}");
+        self.inner.get_text(&u).await
+    }
+
+    /// Fetches and parses the metrics into the metric families.
+    /// Use "metrics::Metrics::diff" to compare the snapshots from the consecutive scrapes.
+    pub async fn scrape(&self, http_rpc: &str) -> Result<metrics::Metrics> {
+        let s = self.fetch(http_rpc).await?;
+        metrics::Metrics::parse(&s)
+    }
+}
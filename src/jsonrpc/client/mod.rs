@@ -1,6 +1,693 @@
 pub mod admin;
+pub mod c;
 pub mod evm;
 pub mod health;
+pub mod index;
 pub mod info;
+pub mod metrics;
 pub mod p;
 pub mod x;
+
+use std::{collections::HashSet, future::Future, time::Duration};
+
+use crate::{
+    errors::{Error, Result},
+    ids, jsonrpc, txs, utils,
+};
+use futures::{future, stream, Stream, StreamExt, TryStreamExt};
+use reqwest::{header::CONTENT_TYPE, Method, StatusCode};
+use serde::de::DeserializeOwned;
+use tokio::time::sleep;
+
+/// Default timeout for each request attempt, including reading the response body.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(15);
+/// Default timeout for establishing a new connection.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+/// Default duration to keep the idle connections in the pool.
+pub const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+/// Default maximum number of idle connections to keep per host.
+pub const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 32;
+/// Default number of retries after the first attempt for the retryable errors.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Default wait before the first retry, doubled for each following retry.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+/// Default upper bound of the wait between retries.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Maximum number of UTXOs returned by a single "getUTXOs" call.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.10.1/vms/components/avax/utxo_fetching.go> "MaxUTXOsToFetch"
+pub const MAX_UTXOS_TO_FETCH: u32 = 1024;
+
+/// Maximum number of addresses in a single "getUTXOs" call.
+/// ref. "avalanchego/vms/avm.maxGetUTXOsAddrs"
+pub const MAX_GET_UTXOS_ADDRESSES: usize = 1024;
+
+/// Long-lived JSON-RPC client for the avalanchego APIs.
+/// It owns a pooled HTTP client so that the connections are reused across the calls,
+/// and retries the retryable errors with exponential backoff.
+/// Cheap to clone, since the clones share the same connection pool.
+///
+/// The chain-specific APIs are exposed via the handles (e.g., "Client::p" for the P-chain),
+/// and each call takes the base HTTP URL so that the same client can round-robin
+/// multiple endpoints.
+#[derive(Clone, Debug)]
+pub struct Client {
+    http_client: reqwest::Client,
+
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Client {
+    /// Creates a new client with the default options.
+    pub fn new() -> Result<Self> {
+        Builder::new().build()
+    }
+
+    /// Sends the JSON-RPC request body via POST and decodes the response.
+    /// Transient failures (e.g., timeouts, HTTP 503, JSON-RPC internal errors)
+    /// are retried up to "max_retries" times. Once retries are exhausted or for
+    /// a non-retryable JSON-RPC error, the response is returned as-is so that
+    /// the caller can inspect its "error" field.
+    pub async fn post<T: DeserializeOwned>(&self, url: &str, body: String) -> Result<T> {
+        self.call(Method::POST, url, Some(body), true, decode_json)
+            .await
+    }
+
+    /// Same as "post" but sends the request only once without any retry,
+    /// for the non-idempotent calls (e.g., "platform.issueTx").
+    /// If the request times out after the node has accepted the tx,
+    /// the retry would fail with the duplicate tx error, thus the caller
+    /// should check the tx status before issuing the same tx again.
+    pub async fn post_once<T: DeserializeOwned>(&self, url: &str, body: String) -> Result<T> {
+        self.call_once(Method::POST, url, Some(body), false, decode_json)
+            .await
+    }
+
+    /// Sends the GET request and decodes the response body regardless of
+    /// the HTTP status code (e.g., health checks return 503 with the details).
+    /// Only the transport failures are retried.
+    pub async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        self.call(Method::GET, url, None, false, decode_json).await
+    }
+
+    /// Sends the GET request and returns the response body as text
+    /// (e.g., Prometheus metrics). Fails on the non-success status code.
+    /// Only the transport failures and the retryable status codes are retried.
+    pub async fn get_text(&self, url: &str) -> Result<String> {
+        self.call(Method::GET, url, None, false, decode_text).await
+    }
+
+    /// Returns the wait before the retry attempt (0-indexed).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1_u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .checked_mul(factor)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    async fn call<T>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        retry_on_status: bool,
+        decode: fn(StatusCode, &[u8]) -> Result<T>,
+    ) -> Result<T> {
+        let mut attempt = 0_u32;
+        loop {
+            let can_retry = attempt < self.max_retries;
+            match self
+                .call_once(
+                    method.clone(),
+                    url,
+                    body.clone(),
+                    retry_on_status && can_retry,
+                    decode,
+                )
+                .await
+            {
+                Err(e) if e.retryable() && can_retry => {
+                    let backoff = self.backoff(attempt);
+                    log::warn!(
+                        "retrying {} {url} in {:?} ({}/{}): {}",
+                        method,
+                        backoff,
+                        attempt + 1,
+                        self.max_retries,
+                        e.message()
+                    );
+                    sleep(backoff).await;
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    async fn call_once<T>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        retry_on_status: bool,
+        decode: fn(StatusCode, &[u8]) -> Result<T>,
+    ) -> Result<T> {
+        let mut req = self.http_client.request(method, url);
+        if let Some(body) = body {
+            req = req.header(CONTENT_TYPE, "application/json").body(body);
+        }
+        let resp = req.send().await.map_err(|e| Error::API {
+            message: format!("failed reqwest::Client.send '{}'", e),
+            retryable: is_retryable_reqwest_error(&e),
+        })?;
+
+        let status = resp.status();
+        let out = resp.bytes().await.map_err(|e| Error::API {
+            message: format!("failed reqwest response bytes '{}'", e),
+            retryable: is_retryable_reqwest_error(&e),
+        })?;
+
+        if retry_on_status {
+            if !status.is_success() && is_retryable_status(status) {
+                return Err(Error::API {
+                    message: format!("status code non-success {}", status),
+                    retryable: true,
+                });
+            }
+
+            // JSON-RPC errors are returned with the success status code
+            if let Some(code) = response_error_code(&out) {
+                if is_retryable_error_code(code) {
+                    return Err(Error::API {
+                        message: format!(
+                            "JSON-RPC error code {} '{}'",
+                            code,
+                            String::from_utf8_lossy(&out)
+                        ),
+                        retryable: true,
+                    });
+                }
+            }
+        }
+
+        decode(status, &out)
+    }
+}
+
+/// Builds the JSON-RPC client with the custom options.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    /// Timeout for each request attempt.
+    pub timeout: Duration,
+    /// Timeout for establishing a new connection.
+    pub connect_timeout: Duration,
+    /// Duration to keep the idle connections in the pool.
+    pub pool_idle_timeout: Duration,
+    /// Maximum number of idle connections to keep per host.
+    pub pool_max_idle_per_host: usize,
+    /// Set "true" to skip the TLS certificate verification
+    /// (e.g., for the self-signed certificates in the test networks).
+    /// Defaults to "true", set "false" to verify the certificates.
+    pub accept_invalid_certs: bool,
+
+    /// Number of retries after the first attempt, set zero to disable retries.
+    pub max_retries: u32,
+    /// Wait before the first retry, doubled for each following retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of the wait between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            pool_idle_timeout: DEFAULT_POOL_IDLE_TIMEOUT,
+            pool_max_idle_per_host: DEFAULT_POOL_MAX_IDLE_PER_HOST,
+            accept_invalid_certs: true,
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Sets the timeout for each request attempt.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the timeout for establishing a new connection.
+    #[must_use]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Sets the duration to keep the idle connections in the pool.
+    #[must_use]
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = pool_idle_timeout;
+        self
+    }
+
+    /// Sets the maximum number of idle connections to keep per host.
+    #[must_use]
+    pub fn pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = pool_max_idle_per_host;
+        self
+    }
+
+    /// Sets the flag to skip the TLS certificate verification.
+    #[must_use]
+    pub fn accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Sets the number of retries after the first attempt.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the wait before the first retry.
+    #[must_use]
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound of the wait between retries.
+    #[must_use]
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn build(&self) -> Result<Client> {
+        let http_client = reqwest::ClientBuilder::new()
+            .user_agent(env!("CARGO_PKG_NAME"))
+            .danger_accept_invalid_certs(self.accept_invalid_certs)
+            .timeout(self.timeout)
+            .connect_timeout(self.connect_timeout)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .build()
+            .map_err(|e| Error::Other {
+                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
+                retryable: false,
+            })?;
+
+        Ok(Client {
+            http_client,
+            max_retries: self.max_retries,
+            initial_backoff: self.initial_backoff,
+            max_backoff: self.max_backoff,
+        })
+    }
+}
+
+/// Represents a single page of the "getUTXOs" results.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct UtxosPage {
+    pub num_fetched: u32,
+    pub utxos: Vec<txs::utxo::Utxo>,
+    pub end_index: Option<jsonrpc::EndIndex>,
+}
+
+/// Represents the "getUTXOs" responses of the X-chain, P-chain and C-chain APIs,
+/// which share the same result fields.
+pub trait UtxosResponse {
+    /// Returns the page of the results, or the JSON-RPC error if any.
+    fn into_page(self) -> std::result::Result<UtxosPage, jsonrpc::ResponseError>;
+}
+
+macro_rules! impl_utxos_response {
+    ($t:ty) => {
+        impl UtxosResponse for $t {
+            fn into_page(self) -> std::result::Result<UtxosPage, jsonrpc::ResponseError> {
+                if let Some(e) = self.error {
+                    return Err(e);
+                }
+                let result = self.result.unwrap_or_default();
+                Ok(UtxosPage {
+                    num_fetched: result.num_fetched,
+                    utxos: result.utxos.unwrap_or_default(),
+                    end_index: result.end_index,
+                })
+            }
+        }
+    };
+}
+
+impl_utxos_response!(jsonrpc::avm::GetUtxosResponse);
+impl_utxos_response!(jsonrpc::platformvm::GetUtxosResponse);
+impl_utxos_response!(jsonrpc::evm::GetUtxosResponse);
+
+/// Fetches all UTXOs for the addresses by paginating the "getUTXOs" "method"
+/// (e.g., "avm.getUTXOs"). "fetch_page" is called with each chunk of at most
+/// "MAX_GET_UTXOS_ADDRESSES" addresses and the start index of each page.
+/// The UTXOs owned by the addresses of multiple chunks are returned only once.
+pub fn get_all_utxos<F, Fut, R>(
+    method: &'static str,
+    addresses: &[String],
+    fetch_page: F,
+) -> impl Stream<Item = Result<txs::utxo::Utxo>>
+where
+    F: Fn(Vec<String>, Option<jsonrpc::EndIndex>) -> Fut + Clone,
+    Fut: Future<Output = Result<R>>,
+    R: UtxosResponse,
+{
+    let chunks: Vec<Vec<String>> = addresses
+        .chunks(MAX_GET_UTXOS_ADDRESSES)
+        .map(|chunk| chunk.to_vec())
+        .collect();
+
+    let mut seen: HashSet<(ids::Id, u32)> = HashSet::new();
+    stream::iter(chunks)
+        .flat_map(move |chunk| {
+            let fetch_page = fetch_page.clone();
+            paginate_utxos(MAX_UTXOS_TO_FETCH, move |start_index| {
+                let resp = fetch_page(chunk.clone(), start_index);
+                async move {
+                    resp.await?.into_page().map_err(|e| Error::API {
+                        message: format!("failed {} '{}'", method, e.message),
+                        retryable: is_retryable_error_code(e.code),
+                    })
+                }
+            })
+        })
+        .try_filter(move |utxo| {
+            future::ready(seen.insert((utxo.utxo_id.tx_id, utxo.utxo_id.output_index)))
+        })
+}
+
+/// Fetches all UTXOs by following the "endIndex" cursor of each page
+/// as the "startIndex" of the next call, until a page returns less than "limit" UTXOs.
+/// "fetch" is called with the start index of each page ("None" for the first page).
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetutxos>
+pub fn paginate_utxos<F, Fut>(limit: u32, fetch: F) -> impl Stream<Item = Result<txs::utxo::Utxo>>
+where
+    F: FnMut(Option<jsonrpc::EndIndex>) -> Fut,
+    Fut: Future<Output = Result<UtxosPage>>,
+{
+    // "None" cursor marks the end of pages
+    stream::try_unfold(
+        (fetch, Some(None)),
+        move |(mut fetch, cursor): (F, Option<Option<jsonrpc::EndIndex>>)| async move {
+            let start_index = match cursor {
+                Some(start_index) => start_index,
+                None => return Ok(None),
+            };
+
+            let page = fetch(start_index).await?;
+            log::debug!(
+                "fetched UTXOs page: numFetched {}, endIndex {:?}",
+                page.num_fetched,
+                page.end_index
+            );
+            let next = match page.end_index {
+                Some(end_index) if page.num_fetched >= limit && !page.utxos.is_empty() => {
+                    Some(Some(end_index))
+                }
+                _ => None,
+            };
+            Ok(Some((page.utxos, (fetch, next))))
+        },
+    )
+    .map_ok(|utxos| stream::iter(utxos.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// Returns the URL of the API path on the host of "http_rpc",
+/// ignoring any URL path in "http_rpc" (defaults to "http" scheme).
+/// e.g., "http://127.0.0.1:9650/ext/bc/C/rpc" and "/ext/P" returns "http://127.0.0.1:9650/ext/P".
+pub fn url(http_rpc: &str, path: &str) -> Result<String> {
+    let (scheme, host, port, _, _) =
+        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
+            Error::Other {
+                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
+                retryable: false,
+            }
+        })?;
+    let scheme = scheme.unwrap_or_else(|| String::from("http"));
+    let u = if let Some(port) = port {
+        format!("{scheme}://{host}:{port}{path}")
+    } else {
+        format!("{scheme}://{host}{path}")
+    };
+    Ok(u)
+}
+
+/// Returns true if the HTTP status code is transient
+/// (e.g., request timeout, rate limited, or server-side errors).
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::REQUEST_TIMEOUT
+            | StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Returns true if the JSON-RPC error code is transient.
+/// Note that avalanchego returns "-32000" (server error) for the application errors
+/// (e.g., "problem decoding transaction"), which are not retryable.
+/// ref. <https://www.jsonrpc.org/specification#error_object>
+/// ref. <https://eips.ethereum.org/EIPS/eip-1474#error-codes>
+pub fn is_retryable_error_code(code: i32) -> bool {
+    matches!(
+        code,
+        -32603 // internal error
+        | -32002 // resource unavailable
+        | -32005 // limit exceeded
+    )
+}
+
+/// Decodes the JSON response body regardless of the HTTP status code.
+fn decode_json<T: DeserializeOwned>(status: StatusCode, out: &[u8]) -> Result<T> {
+    serde_json::from_slice(out).map_err(|e| {
+        if status.is_success() {
+            Error::Other {
+                message: format!("failed serde_json::from_slice '{}'", e),
+                retryable: false,
+            }
+        } else {
+            Error::API {
+                message: format!(
+                    "status code non-success {} '{}'",
+                    status,
+                    String::from_utf8_lossy(out)
+                ),
+                retryable: is_retryable_status(status),
+            }
+        }
+    })
+}
+
+/// Returns the response body as text for the success status code.
+fn decode_text(status: StatusCode, out: &[u8]) -> Result<String> {
+    if !status.is_success() {
+        return Err(Error::API {
+            message: format!(
+                "status code non-success {} '{}'",
+                status,
+                String::from_utf8_lossy(out)
+            ),
+            retryable: is_retryable_status(status),
+        });
+    }
+    String::from_utf8(out.to_vec()).map_err(|e| Error::Other {
+        message: format!("failed String::from_utf8 '{}'", e),
+        retryable: false,
+    })
+}
+
+/// Returns the "error.code" of the JSON-RPC response, if any.
+fn response_error_code(d: &[u8]) -> Option<i32> {
+    let v: serde_json::Value = serde_json::from_slice(d).ok()?;
+    let code = v.get("error")?.get("code")?.as_i64()?;
+    i32::try_from(code).ok()
+}
+
+fn is_retryable_reqwest_error(e: &reqwest::Error) -> bool {
+    e.is_timeout() || e.is_connect()
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::client::test_url --exact --show-output
+#[test]
+fn test_url() {
+    assert_eq!(
+        url("http://127.0.0.1:9650", "/ext/P").unwrap(),
+        "http://127.0.0.1:9650/ext/P"
+    );
+    assert_eq!(
+        url("https://api.avax-test.network/ext/bc/C/rpc", "/ext/bc/X").unwrap(),
+        "https://api.avax-test.network/ext/bc/X"
+    );
+    assert_eq!(
+        url("127.0.0.1:9650", "/ext/info").unwrap(),
+        "http://127.0.0.1:9650/ext/info"
+    );
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::client::test_retryable --exact --show-output
+#[test]
+fn test_retryable() {
+    assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+    assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+    assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
+    assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+
+    assert!(is_retryable_error_code(-32603));
+    assert!(!is_retryable_error_code(-32000));
+    assert!(!is_retryable_error_code(-32601));
+
+    assert_eq!(
+        response_error_code(
+            b"{\"jsonrpc\":\"2.0\",\"error\":{\"code\":-32000,\"message\":\"problem decoding transaction: invalid input checksum\",\"data\":null},\"id\":1}"
+        ),
+        Some(-32000)
+    );
+    assert_eq!(
+        response_error_code(b"{\"jsonrpc\":\"2.0\",\"result\":{},\"id\":1}"),
+        None
+    );
+    assert_eq!(response_error_code(b"not json"), None);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::client::test_backoff --exact --show-output
+#[test]
+fn test_backoff() {
+    let cli = Builder::new()
+        .initial_backoff(Duration::from_millis(100))
+        .max_backoff(Duration::from_secs(1))
+        .build()
+        .unwrap();
+    assert_eq!(cli.backoff(0), Duration::from_millis(100));
+    assert_eq!(cli.backoff(1), Duration::from_millis(200));
+    assert_eq!(cli.backoff(3), Duration::from_millis(800));
+    assert_eq!(cli.backoff(4), Duration::from_secs(1));
+    assert_eq!(cli.backoff(100), Duration::from_secs(1));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::client::test_post_once --exact --show-output
+#[tokio::test]
+async fn test_post_once() {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    // always responds with the retryable status code
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let u = format!("http://{}/ext/P", listener.local_addr().unwrap());
+    let requests = Arc::new(AtomicU32::new(0));
+    let counter = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+            let mut buf = vec![0; 4096];
+            let _ = stream.read(&mut buf).await;
+            let _ = stream
+                .write_all(b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                .await;
+        }
+    });
+
+    let cli = Builder::new()
+        .max_retries(2)
+        .initial_backoff(Duration::from_millis(1))
+        .build()
+        .unwrap();
+    let body = String::from("{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"platform.issueTx\"}");
+
+    assert!(cli
+        .post::<serde_json::Value>(&u, body.clone())
+        .await
+        .is_err());
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    assert!(cli.post_once::<serde_json::Value>(&u, body).await.is_err());
+    assert_eq!(requests.load(Ordering::SeqCst), 4);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::client::test_paginate_utxos --exact --show-output
+#[tokio::test]
+async fn test_paginate_utxos() {
+    let utxo = |i: u32| txs::utxo::Utxo {
+        utxo_id: txs::utxo::Id {
+            output_index: i,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let end_index = |i: u32| jsonrpc::EndIndex {
+        address: String::from("X-custom1qwmslrrqdv4slxvynhy9csq069l0u8mqwjzmcd"),
+        utxo: format!("utxo-{i}"),
+    };
+
+    // 2 full pages and 1 partial page with the limit 2
+    let mut start_indexes = Vec::new();
+    let utxos: Vec<txs::utxo::Utxo> = paginate_utxos(2, |start_index| {
+        start_indexes.push(start_index.clone());
+        let page = match start_index.map(|e| e.utxo) {
+            None => UtxosPage {
+                num_fetched: 2,
+                utxos: vec![utxo(0), utxo(1)],
+                end_index: Some(end_index(1)),
+            },
+            Some(s) if s == "utxo-1" => UtxosPage {
+                num_fetched: 2,
+                utxos: vec![utxo(2), utxo(3)],
+                end_index: Some(end_index(3)),
+            },
+            _ => UtxosPage {
+                num_fetched: 1,
+                utxos: vec![utxo(4)],
+                end_index: Some(end_index(4)),
+            },
+        };
+        async move { Ok(page) }
+    })
+    .try_collect()
+    .await
+    .unwrap();
+    assert_eq!(utxos, (0..5).map(utxo).collect::<Vec<_>>());
+    assert_eq!(
+        start_indexes,
+        vec![None, Some(end_index(1)), Some(end_index(3))]
+    );
+
+    // stops on the first error
+    let res: Result<Vec<txs::utxo::Utxo>> = paginate_utxos(2, |_| async {
+        Err(Error::API {
+            message: String::from("failed"),
+            retryable: false,
+        })
+    })
+    .try_collect()
+    .await;
+    assert!(res.is_err());
+}
@@ -1,814 +1,617 @@
-use std::{collections::HashMap, time::Duration};
+use std::collections::HashMap;
 
 use crate::{
     errors::{Error, Result},
-    ids,
+    ids::{self, node},
     jsonrpc::{self, platformvm},
-    utils,
+    txs,
 };
-use reqwest::{header::CONTENT_TYPE, ClientBuilder};
-
-/// "platform.issueTx" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators>
-pub async fn issue_tx(http_rpc: &str, tx: &str) -> Result<platformvm::IssueTxResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
-        }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("issuing a transaction via {u}");
-
-    let mut data = platformvm::IssueTxRequest::default();
-    data.method = String::from("platform.issueTx");
-    let params = platformvm::IssueTxParams {
-        tx: prefix_manager::prepend_0x(tx),
-        encoding: String::from("hex"), // don't use "cb58"
-    };
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
+use futures::Stream;
+
+impl super::Client {
+    #[must_use]
+    pub fn p(&self) -> P {
+        P {
+            inner: self.clone(),
         }
-    })?;
-    let out: Vec<u8> = out.into();
+    }
+}
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
+/// Client for the P-chain "platform.*" APIs on the "/ext/P" path.
+#[derive(Clone, Debug)]
+pub struct P {
+    pub inner: super::Client,
 }
 
-/// "platform.getTx" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettx>
-pub async fn get_tx(http_rpc: &str, tx_id: &str) -> Result<platformvm::GetTxResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
+impl P {
+    /// "platform.issueTx" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Sent only once, since retrying an issued tx fails as a duplicate.
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators>
+    pub async fn issue_tx(&self, http_rpc: &str, tx: &str) -> Result<platformvm::IssueTxResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("issuing a transaction via {u}");
+
+        let mut data = platformvm::IssueTxRequest::default();
+        data.method = String::from("platform.issueTx");
+        let params = platformvm::IssueTxParams {
+            tx: prefix_manager::prepend_0x(tx),
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
-        }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting tx via {u}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getTx");
-    let mut params = HashMap::new();
-    params.insert(String::from("txID"), String::from(tx_id));
-    params.insert(String::from("encoding"), String::from("json")); // TODO: use "hex"?
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+
+        self.inner.post_once(&u, d).await
+    }
+
+    /// "platform.getTx" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettx>
+    pub async fn get_tx(&self, http_rpc: &str, tx_id: &str) -> Result<platformvm::GetTxResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting tx via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getTx");
+        let mut params = HashMap::new();
+        params.insert(String::from("txID"), String::from(tx_id));
+        params.insert(String::from("encoding"), String::from("json")); // TODO: use "hex"?
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getTxStatus" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettxstatus>
+    pub async fn get_tx_status(
+        &self,
+        http_rpc: &str,
+        tx_id: &str,
+    ) -> Result<platformvm::GetTxStatusResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting tx status via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getTxStatus");
+        let mut params = HashMap::new();
+        params.insert(String::from("txID"), String::from(tx_id));
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getTxStatus" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettxstatus>
-pub async fn get_tx_status(http_rpc: &str, tx_id: &str) -> Result<platformvm::GetTxStatusResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
+    /// "platform.getHeight" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetheight>
+    pub async fn get_height(&self, http_rpc: &str) -> Result<platformvm::GetHeightResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting height via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getHeight");
+
+        let params = HashMap::new();
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
-        }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting tx status via {u}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getTxStatus");
-    let mut params = HashMap::new();
-    params.insert(String::from("txID"), String::from(tx_id));
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getBalance" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetbalance>
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/45ec88151f8a0e3bca1d43fe902fd632c41cd956/vms/platformvm/service.go#L192-L194>
+    pub async fn get_balance(
+        &self,
+        http_rpc: &str,
+        paddr: &str,
+    ) -> Result<platformvm::GetBalanceResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting balance via {u} for {}", paddr);
+
+        let mut data = jsonrpc::RequestWithParamsHashMapToArray::default();
+        data.method = String::from("platform.getBalance");
+        let mut params = HashMap::new();
+        params.insert(String::from("addresses"), vec![paddr.to_string()]);
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getUTXOs" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Fetches the first page of UTXOs for the address.
+    /// Use "get_all_utxos" to fetch all UTXOs.
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetutxos>
+    pub async fn get_utxos(
+        &self,
+        http_rpc: &str,
+        paddr: &str,
+    ) -> Result<platformvm::GetUtxosResponse> {
+        self.get_utxos_page(
+            http_rpc,
+            &[paddr.to_string()],
+            None,
+            super::MAX_UTXOS_TO_FETCH,
+            None,
+        )
         .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+    }
+
+    /// "platform.getUTXOs" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Fetches a single page of UTXOs for the addresses, starting after "start_index"
+    /// (i.e., "endIndex" of the previous page). Set "source_chain" to fetch the atomic
+    /// UTXOs exported from the source chain.
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetutxos>
+    pub async fn get_utxos_page(
+        &self,
+        http_rpc: &str,
+        addresses: &[String],
+        source_chain: Option<&str>,
+        limit: u32,
+        start_index: Option<jsonrpc::EndIndex>,
+    ) -> Result<platformvm::GetUtxosResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!(
+            "getting UTXOs via {u} for {} addresses (source chain {:?}, start index {:?})",
+            addresses.len(),
+            source_chain,
+            start_index
+        );
+
+        let mut data = platformvm::GetUtxosRequest::default();
+        data.method = String::from("platform.getUTXOs");
+        let params = platformvm::GetUtxosParams {
+            addresses: addresses.to_vec(),
+            source_chain: source_chain.map(String::from),
+            limit,
+            start_index,
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getHeight" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetheight>
-pub async fn get_height(http_rpc: &str) -> Result<platformvm::GetHeightResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
-        }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting height via {u}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getHeight");
-
-    let params = HashMap::new();
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+    /// Fetches all UTXOs for the addresses by paginating "platform.getUTXOs".
+    /// Set "source_chain" to fetch the atomic UTXOs exported from the source chain.
+    pub fn get_all_utxos(
+        &self,
+        http_rpc: &str,
+        addresses: &[String],
+        source_chain: Option<&str>,
+    ) -> impl Stream<Item = Result<txs::utxo::Utxo>> {
+        let (cli, http_rpc, source_chain) = (
+            self.clone(),
+            http_rpc.to_string(),
+            source_chain.map(String::from),
+        );
+        super::get_all_utxos(
+            "platform.getUTXOs",
+            addresses,
+            move |addresses, start_index| {
+                let (cli, http_rpc, source_chain) =
+                    (cli.clone(), http_rpc.clone(), source_chain.clone());
+                async move {
+                    cli.get_utxos_page(
+                        &http_rpc,
+                        &addresses,
+                        source_chain.as_deref(),
+                        super::MAX_UTXOS_TO_FETCH,
+                        start_index,
+                    )
+                    .await
+                }
+            },
+        )
+    }
+
+    /// "platform.getCurrentValidators" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#ClientPermissionlessValidator>
+    pub async fn get_primary_network_validators(
+        &self,
+        http_rpc: &str,
+    ) -> Result<platformvm::GetCurrentValidatorsResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting primary network validators via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getCurrentValidators");
+        let params = HashMap::new();
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getCurrentValidators" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#ClientPermissionlessValidator>
+    pub async fn get_subnet_validators(
+        &self,
+        http_rpc: &str,
+        subnet_id: &str,
+    ) -> Result<platformvm::GetCurrentValidatorsResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting subnet validators via {u} for {subnet_id}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getCurrentValidators");
+        let mut params = HashMap::new();
+        params.insert(String::from("subnetID"), subnet_id.to_string());
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getBalance" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetbalance>
-/// ref. <https://github.com/ava-labs/avalanchego/blob/45ec88151f8a0e3bca1d43fe902fd632c41cd956/vms/platformvm/service.go#L192-L194>
-pub async fn get_balance(http_rpc: &str, paddr: &str) -> Result<platformvm::GetBalanceResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
+    /// "platform.getSubnets" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetsubnets>
+    pub async fn get_subnets(
+        &self,
+        http_rpc: &str,
+        subnet_ids: Option<Vec<ids::Id>>,
+    ) -> Result<platformvm::GetSubnetsResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting subnets via {u}");
+
+        let mut data = jsonrpc::RequestWithParamsHashMapToArray::default();
+        data.method = String::from("platform.getSubnets");
+        let mut ids = Vec::new();
+        if let Some(ss) = &subnet_ids {
+            for id in ss.iter() {
+                ids.push(id.to_string());
             }
-        })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
         }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting balance via {u} for {}", paddr);
-
-    let mut data = jsonrpc::RequestWithParamsHashMapToArray::default();
-    data.method = String::from("platform.getBalance");
-    let mut params = HashMap::new();
-    params.insert(String::from("addresses"), vec![paddr.to_string()]);
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+        let mut params = HashMap::new();
+        params.insert(String::from("ids"), ids);
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getUTXOs" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetutxos>
-pub async fn get_utxos(http_rpc: &str, paddr: &str) -> Result<platformvm::GetUtxosResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
-        }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting UTXOs via {u} for {}", paddr);
-
-    let mut data = platformvm::GetUtxosRequest::default();
-    data.method = String::from("platform.getUTXOs");
-    let params = platformvm::GetUtxosParams {
-        addresses: vec![paddr.to_string()],
-        limit: 100,
-        encoding: String::from("hex"), // don't use "cb58"
-    };
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+    /// "platform.getBlockchains" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockchains>
+    pub async fn get_blockchains(
+        &self,
+        http_rpc: &str,
+    ) -> Result<platformvm::GetBlockchainsResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting blockchain via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getBlockchains");
+        let params = HashMap::new();
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getBlockchainStatus" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockchainstatus>
+    pub async fn get_blockchain_status(
+        &self,
+        http_rpc: &str,
+        blockchain_id: ids::Id,
+    ) -> Result<platformvm::GetBlockchainStatusResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting blockchain status via {u} for {blockchain_id}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getBlockchainStatus");
+        let mut params = HashMap::new();
+        params.insert(String::from("blockchainID"), blockchain_id.to_string());
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getCurrentValidators" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators>
-/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#ClientPermissionlessValidator>
-pub async fn get_primary_network_validators(
-    http_rpc: &str,
-) -> Result<platformvm::GetCurrentValidatorsResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
+    /// "platform.getStake" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake>
+    pub async fn get_stake(
+        &self,
+        http_rpc: &str,
+        addresses: &[String],
+        validators_only: Option<bool>,
+    ) -> Result<platformvm::GetStakeResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting stake via {u} for {} addresses", addresses.len());
+
+        let mut data = platformvm::GetStakeRequest::default();
+        data.method = String::from("platform.getStake");
+        let params = platformvm::GetStakeParams {
+            addresses: addresses.to_vec(),
+            validators_only,
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getMinStake" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Set "subnet_id" to "None" for the primary network.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetminstake>
+    pub async fn get_min_stake(
+        &self,
+        http_rpc: &str,
+        subnet_id: Option<ids::Id>,
+    ) -> Result<platformvm::GetMinStakeResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting min stake via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getMinStake");
+        let mut params = HashMap::new();
+        if let Some(subnet_id) = &subnet_id {
+            params.insert(String::from("subnetID"), subnet_id.to_string());
         }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting primary network validators via {u}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getCurrentValidators");
-    let params = HashMap::new();
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getCurrentValidators" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators>
-/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#ClientPermissionlessValidator>
-pub async fn get_subnet_validators(
-    http_rpc: &str,
-    subnet_id: &str,
-) -> Result<platformvm::GetCurrentValidatorsResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
+    /// "platform.getCurrentSupply" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Set "subnet_id" to "None" for the primary network.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetcurrentsupply>
+    pub async fn get_current_supply(
+        &self,
+        http_rpc: &str,
+        subnet_id: Option<ids::Id>,
+    ) -> Result<platformvm::GetCurrentSupplyResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting current supply via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getCurrentSupply");
+        let mut params = HashMap::new();
+        if let Some(subnet_id) = &subnet_id {
+            params.insert(String::from("subnetID"), subnet_id.to_string());
         }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting subnet validators via {u} for {subnet_id}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getCurrentValidators");
-    let mut params = HashMap::new();
-    params.insert(String::from("subnetID"), subnet_id.to_string());
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getSubnets" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetsubnets>
-pub async fn get_subnets(
-    http_rpc: &str,
-    subnet_ids: Option<Vec<ids::Id>>,
-) -> Result<platformvm::GetSubnetsResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
+    /// "platform.getRewardUTXOs" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetrewardutxos>
+    pub async fn get_reward_utxos(
+        &self,
+        http_rpc: &str,
+        tx_id: &str,
+    ) -> Result<platformvm::GetRewardUtxosResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting reward UTXOs via {u} for {tx_id}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getRewardUTXOs");
+        let mut params = HashMap::new();
+        params.insert(String::from("txID"), String::from(tx_id));
+        params.insert(String::from("encoding"), String::from("hex")); // don't use "cb58"
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
-        }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting subnets via {u}");
-
-    let mut data = jsonrpc::RequestWithParamsHashMapToArray::default();
-    data.method = String::from("platform.getSubnets");
-    let mut ids = Vec::new();
-    if let Some(ss) = &subnet_ids {
-        for id in ss.iter() {
-            ids.push(id.to_string());
-        }
+
+        self.inner.post(&u, d).await
     }
-    let mut params = HashMap::new();
-    params.insert(String::from("ids"), ids);
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+
+    /// "platform.getTimestamp" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettimestamp>
+    pub async fn get_timestamp(&self, http_rpc: &str) -> Result<platformvm::GetTimestampResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting timestamp via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getTimestamp");
+        let params = HashMap::new();
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getValidatorsAt" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Set "subnet_id" to "None" for the primary network.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetvalidatorsat>
+    pub async fn get_validators_at(
+        &self,
+        http_rpc: &str,
+        height: u64,
+        subnet_id: Option<ids::Id>,
+    ) -> Result<platformvm::GetValidatorsAtResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting validators at height {height} via {u}");
+
+        let mut data = platformvm::GetValidatorsAtRequest::default();
+        data.method = String::from("platform.getValidatorsAt");
+        let params = platformvm::GetValidatorsAtParams { height, subnet_id };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getBlockchains" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockchains>
-pub async fn get_blockchains(http_rpc: &str) -> Result<platformvm::GetBlockchainsResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
+    /// "platform.sampleValidators" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Set "subnet_id" to "None" for the primary network.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformsamplevalidators>
+    pub async fn sample_validators(
+        &self,
+        http_rpc: &str,
+        size: u16,
+        subnet_id: Option<ids::Id>,
+    ) -> Result<platformvm::SampleValidatorsResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("sampling {size} validators via {u}");
+
+        let mut data = platformvm::SampleValidatorsRequest::default();
+        data.method = String::from("platform.sampleValidators");
+        let params = platformvm::SampleValidatorsParams { size, subnet_id };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
-        }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting blockchain via {u}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getBlockchains");
-    let params = HashMap::new();
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getBlock" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Use "GetBlockResult::parse_block" to decode the returned block.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblock>
+    pub async fn get_block(
+        &self,
+        http_rpc: &str,
+        block_id: ids::Id,
+    ) -> Result<platformvm::GetBlockResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting block {block_id} via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getBlock");
+        let mut params = HashMap::new();
+        params.insert(String::from("blockID"), block_id.to_string());
+        params.insert(String::from("encoding"), String::from("hex")); // don't use "cb58"
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getBlockByHeight" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Use "GetBlockResult::parse_block" to decode the returned block.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockbyheight>
+    pub async fn get_block_by_height(
+        &self,
+        http_rpc: &str,
+        height: u64,
+    ) -> Result<platformvm::GetBlockResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting block at height {height} via {u}");
+
+        let mut data = platformvm::GetBlockByHeightRequest::default();
+        data.method = String::from("platform.getBlockByHeight");
+        let params = platformvm::GetBlockByHeightParams {
+            height,
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// "platform.getBlockchainStatus" on "http://[ADDR]:9650" and "/ext/P" path.
-/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockchainstatus>
-pub async fn get_blockchain_status(
-    http_rpc: &str,
-    blockchain_id: ids::Id,
-) -> Result<platformvm::GetBlockchainStatusResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
+    /// "platform.getPendingValidators" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Set "subnet_id" to "None" for the primary network, and "node_ids" to "None" for all pending validators.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetpendingvalidators>
+    pub async fn get_pending_validators(
+        &self,
+        http_rpc: &str,
+        subnet_id: Option<ids::Id>,
+        node_ids: Option<Vec<node::Id>>,
+    ) -> Result<platformvm::GetPendingValidatorsResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting pending validators via {u}");
+
+        let mut data = platformvm::GetPendingValidatorsRequest::default();
+        data.method = String::from("platform.getPendingValidators");
+        let params = platformvm::GetPendingValidatorsParams {
+            subnet_id,
+            node_ids,
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/P")
-        } else {
-            format!("{scheme}://{host}/ext/P")
+
+        self.inner.post(&u, d).await
+    }
+
+    /// "platform.getTotalStake" on "http://[ADDR]:9650" and "/ext/P" path.
+    /// Set "subnet_id" to "None" for the primary network.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettotalstake>
+    pub async fn get_total_stake(
+        &self,
+        http_rpc: &str,
+        subnet_id: Option<ids::Id>,
+    ) -> Result<platformvm::GetTotalStakeResponse> {
+        let u = super::url(http_rpc, "/ext/P")?;
+        log::info!("getting total stake via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("platform.getTotalStake");
+        let mut params = HashMap::new();
+        if let Some(subnet_id) = &subnet_id {
+            params.insert(String::from("subnetID"), subnet_id.to_string());
         }
-    } else {
-        format!("http://{host}/ext/P")
-    };
-    log::info!("getting blockchain status via {u} for {blockchain_id}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getBlockchainStatus");
-    let mut params = HashMap::new();
-    params.insert(String::from("blockchainID"), blockchain_id.to_string());
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
+        self.inner.post(&u, d).await
+    }
 }
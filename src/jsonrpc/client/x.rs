@@ -1,439 +1,439 @@
-use std::{collections::HashMap, time::Duration};
+use std::collections::HashMap;
 
 use crate::{
     errors::{Error, Result},
+    ids,
     jsonrpc::{self, avm},
-    utils,
+    txs,
 };
-use reqwest::{header::CONTENT_TYPE, ClientBuilder};
-
-/// e.g., "avm.issueTx" on "http://[ADDR]:9650" and "/ext/bc/X" path.
-/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmissuetx>
-pub async fn issue_tx(http_rpc: &str, tx: &str) -> Result<avm::IssueTxResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/bc/X")
-        } else {
-            format!("{scheme}://{host}/ext/bc/X")
-        }
-    } else {
-        format!("http://{host}/ext/bc/X")
-    };
-    log::info!("issuing a transaction via {u}");
-
-    let mut data = avm::IssueTxRequest::default();
-    data.method = String::from("avm.issueTx");
-    let params = avm::IssueTxParams {
-        tx: prefix_manager::prepend_0x(tx),
-        encoding: String::from("hex"), // don't use "cb58"
-    };
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+use futures::{stream, Stream, TryStreamExt};
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+/// Maximum number of tx Ids to fetch per "avm.getAddressTxs" call.
+/// ref. "avalanchego/vms/avm#maxPageSize"
+pub const MAX_ADDRESS_TXS_PAGE_SIZE: u64 = 1024;
 
-/// e.g., "avm.getTxStatus" on "http://[ADDR]:9650" and "/ext/bc/X" path.
-/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgettxstatus>
-pub async fn get_tx_status(http_rpc: &str, tx_id: &str) -> Result<avm::GetTxStatusResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/bc/X")
-        } else {
-            format!("{scheme}://{host}/ext/bc/X")
+impl super::Client {
+    #[must_use]
+    pub fn x(&self) -> X {
+        X {
+            inner: self.clone(),
         }
-    } else {
-        format!("http://{host}/ext/bc/X")
-    };
-    log::info!("getting tx status via {u}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("avm.getTxStatus");
-    let mut params = HashMap::new();
-    params.insert(String::from("txID"), String::from(tx_id));
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
-            retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+    }
+}
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
+/// Client for the X-chain "avm.*" APIs on the "/ext/bc/X" path.
+#[derive(Clone, Debug)]
+pub struct X {
+    pub inner: super::Client,
 }
 
-/// e.g., "avm.getBalance" on "http://[ADDR]:9650" and "/ext/bc/X" path.
-/// ref. <https://docs.avax.network/build/avalanchego-apis/x-chain#avmgetbalance>
-pub async fn get_balance(http_rpc: &str, xaddr: &str) -> Result<avm::GetBalanceResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
-        })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/bc/X")
-        } else {
-            format!("{scheme}://{host}/ext/bc/X")
-        }
-    } else {
-        format!("http://{host}/ext/bc/X")
-    };
-    log::info!("getting balance via {u} for {xaddr}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("avm.getBalance");
-    let mut params = HashMap::new();
-    params.insert(String::from("assetID"), String::from("AVAX"));
-    params.insert(String::from("address"), xaddr.to_string());
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+impl X {
+    /// e.g., "avm.issueTx" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Sent only once, since retrying an issued tx fails as a duplicate.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmissuetx>
+    pub async fn issue_tx(&self, http_rpc: &str, tx: &str) -> Result<avm::IssueTxResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("issuing a transaction via {u}");
+
+        let mut data = avm::IssueTxRequest::default();
+        data.method = String::from("avm.issueTx");
+        let params = avm::IssueTxParams {
+            tx: prefix_manager::prepend_0x(tx),
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+
+        self.inner.post_once(&u, d).await
+    }
+
+    /// e.g., "avm.getTxStatus" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgettxstatus>
+    pub async fn get_tx_status(
+        &self,
+        http_rpc: &str,
+        tx_id: &str,
+    ) -> Result<avm::GetTxStatusResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("getting tx status via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avm.getTxStatus");
+        let mut params = HashMap::new();
+        params.insert(String::from("txID"), String::from(tx_id));
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// e.g., "avm.getAssetDescription".
-/// ref. <https://docs.avax.network/build/avalanchego-apis/x-chain/#avmgetassetdescription>
-pub async fn get_asset_description(
-    http_rpc: &str,
-    asset_id: &str,
-) -> Result<avm::GetAssetDescriptionResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
-            }
+    /// e.g., "avm.getBalance" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/x-chain#avmgetbalance>
+    pub async fn get_balance(
+        &self,
+        http_rpc: &str,
+        xaddr: &str,
+    ) -> Result<avm::GetBalanceResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("getting balance via {u} for {xaddr}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avm.getBalance");
+        let mut params = HashMap::new();
+        params.insert(String::from("assetID"), String::from("AVAX"));
+        params.insert(String::from("address"), xaddr.to_string());
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/bc/X")
-        } else {
-            format!("{scheme}://{host}/ext/bc/X")
-        }
-    } else {
-        format!("http://{host}/ext/bc/X")
-    };
-    log::info!("getting asset description via {u}");
-
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("avm.getAssetDescription");
-    let mut params = HashMap::new();
-    params.insert(String::from("assetID"), String::from(asset_id));
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "avm.getAssetDescription".
+    /// ref. <https://docs.avax.network/build/avalanchego-apis/x-chain/#avmgetassetdescription>
+    pub async fn get_asset_description(
+        &self,
+        http_rpc: &str,
+        asset_id: &str,
+    ) -> Result<avm::GetAssetDescriptionResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("getting asset description via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avm.getAssetDescription");
+        let mut params = HashMap::new();
+        params.insert(String::from("assetID"), String::from(asset_id));
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "avm.getUTXOs" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Fetches the first page of UTXOs for the address.
+    /// Use "get_all_utxos" to fetch all UTXOs.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
+    pub async fn get_utxos(&self, http_rpc: &str, xaddr: &str) -> Result<avm::GetUtxosResponse> {
+        self.get_utxos_page(
+            http_rpc,
+            &[xaddr.to_string()],
+            None,
+            super::MAX_UTXOS_TO_FETCH,
+            None,
+        )
         .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+    }
+
+    /// e.g., "avm.getUTXOs" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Fetches a single page of UTXOs for the addresses, starting after "start_index"
+    /// (i.e., "endIndex" of the previous page). Set "source_chain" to fetch the atomic
+    /// UTXOs exported from the source chain.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
+    pub async fn get_utxos_page(
+        &self,
+        http_rpc: &str,
+        addresses: &[String],
+        source_chain: Option<&str>,
+        limit: u32,
+        start_index: Option<jsonrpc::EndIndex>,
+    ) -> Result<avm::GetUtxosResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!(
+            "getting UTXOs via {u} for {} addresses (source chain {:?}, start index {:?})",
+            addresses.len(),
+            source_chain,
+            start_index
+        );
+
+        let mut data = avm::GetUtxosRequest::default();
+        data.method = String::from("avm.getUTXOs");
+        let params = avm::GetUtxosParams {
+            addresses: addresses.to_vec(),
+            source_chain: source_chain.map(String::from),
+            limit,
+            start_index,
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
 
-/// e.g., "avm.getUTXOs" on "http://[ADDR]:9650" and "/ext/bc/X" path.
-/// TODO: support paginated calls
-/// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos>
-pub async fn get_utxos(http_rpc: &str, xaddr: &str) -> Result<avm::GetUtxosResponse> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
+    /// Fetches all UTXOs for the addresses by paginating "avm.getUTXOs".
+    /// Set "source_chain" to fetch the atomic UTXOs exported from the source chain.
+    pub fn get_all_utxos(
+        &self,
+        http_rpc: &str,
+        addresses: &[String],
+        source_chain: Option<&str>,
+    ) -> impl Stream<Item = Result<txs::utxo::Utxo>> {
+        let (cli, http_rpc, source_chain) = (
+            self.clone(),
+            http_rpc.to_string(),
+            source_chain.map(String::from),
+        );
+        super::get_all_utxos("avm.getUTXOs", addresses, move |addresses, start_index| {
+            let (cli, http_rpc, source_chain) =
+                (cli.clone(), http_rpc.clone(), source_chain.clone());
+            async move {
+                cli.get_utxos_page(
+                    &http_rpc,
+                    &addresses,
+                    source_chain.as_deref(),
+                    super::MAX_UTXOS_TO_FETCH,
+                    start_index,
+                )
+                .await
             }
+        })
+    }
+
+    /// e.g., "avm.getTx" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Set "encoding" to "json" for the decoded tx object, or to "hex" for the
+    /// tx bytes (use "GetTxResult::parse_tx" to decode).
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgettx>
+    pub async fn get_tx(
+        &self,
+        http_rpc: &str,
+        tx_id: &str,
+        encoding: &str,
+    ) -> Result<avm::GetTxResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("getting tx {tx_id} via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avm.getTx");
+        let mut params = HashMap::new();
+        params.insert(String::from("txID"), String::from(tx_id));
+        params.insert(String::from("encoding"), String::from(encoding));
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/bc/X")
-        } else {
-            format!("{scheme}://{host}/ext/bc/X")
-        }
-    } else {
-        format!("http://{host}/ext/bc/X")
-    };
-    log::info!("getting UTXOs via {u} for {xaddr}");
-
-    let mut data = avm::GetUtxosRequest::default();
-    data.method = String::from("avm.getUTXOs");
-    let params = avm::GetUtxosParams {
-        addresses: vec![xaddr.to_string()],
-        limit: 1024,
-        encoding: String::from("hex"), // don't use "cb58"
-    };
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "avm.getAllBalances" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetallbalances>
+    pub async fn get_all_balances(
+        &self,
+        http_rpc: &str,
+        xaddr: &str,
+    ) -> Result<avm::GetAllBalancesResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("getting all balances via {u} for {xaddr}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avm.getAllBalances");
+        let mut params = HashMap::new();
+        params.insert(String::from("address"), xaddr.to_string());
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-    let out = resp.bytes().await.map_err(|e| {
-        // TODO: check retryable
-        Error::Other {
-            message: format!("failed reqwest response bytes '{}'", e),
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "avm.getAddressTxs" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Fetches a single page of the tx Ids that changed the balance of the asset
+    /// for the address. Set "cursor" to the "cursor" of the previous page.
+    /// Requires the node to run with "index-transactions" enabled.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetaddresstxs>
+    pub async fn get_address_txs(
+        &self,
+        http_rpc: &str,
+        xaddr: &str,
+        asset_id: &str,
+        cursor: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<avm::GetAddressTxsResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!(
+            "getting address txs via {u} for {xaddr} (cursor {:?})",
+            cursor
+        );
+
+        let mut data = avm::GetAddressTxsRequest::default();
+        data.method = String::from("avm.getAddressTxs");
+        let params = avm::GetAddressTxsParams {
+            address: xaddr.to_string(),
+            cursor,
+            asset_id: asset_id.to_string(),
+            page_size,
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        }
-    })?;
-    let out: Vec<u8> = out.into();
+        })?;
 
-    serde_json::from_slice(&out).map_err(|e| Error::Other {
-        message: format!("failed serde_json::from_slice '{}'", e),
-        retryable: false,
-    })
-}
+        self.inner.post(&u, d).await
+    }
+
+    /// Fetches all tx Ids that changed the balance of the asset for the address
+    /// by following the "avm.getAddressTxs" cursor.
+    pub fn get_all_address_txs(
+        &self,
+        http_rpc: &str,
+        xaddr: &str,
+        asset_id: &str,
+    ) -> impl Stream<Item = Result<ids::Id>> {
+        let cli = self.clone();
+        let http_rpc = http_rpc.to_string();
+        let xaddr = xaddr.to_string();
+        let asset_id = asset_id.to_string();
 
-/// e.g., "avm.issueStopVertex" on "http://[ADDR]:9650" and "/ext/bc/X" path.
-/// Issue itself is asynchronous, so the internal error is not exposed!
-pub async fn issue_stop_vertex(http_rpc: &str) -> Result<()> {
-    let (scheme, host, port, _, _) =
-        utils::urls::extract_scheme_host_port_path_chain_alias(http_rpc).map_err(|e| {
-            Error::Other {
-                message: format!("failed extract_scheme_host_port_path_chain_alias '{}'", e),
-                retryable: false,
+        // "None" once the last page has been fetched
+        stream::try_unfold(Some(None), move |cursor: Option<Option<u64>>| {
+            let (cli, http_rpc, xaddr, asset_id) = (
+                cli.clone(),
+                http_rpc.clone(),
+                xaddr.clone(),
+                asset_id.clone(),
+            );
+            async move {
+                let cursor = match cursor {
+                    Some(cursor) => cursor,
+                    None => return Ok(None),
+                };
+                let resp = cli
+                    .get_address_txs(
+                        &http_rpc,
+                        &xaddr,
+                        &asset_id,
+                        cursor,
+                        Some(MAX_ADDRESS_TXS_PAGE_SIZE),
+                    )
+                    .await?;
+                if let Some(e) = resp.error {
+                    return Err(Error::API {
+                        message: format!("failed avm.getAddressTxs '{}'", e.message),
+                        retryable: super::is_retryable_error_code(e.code),
+                    });
+                }
+                let result = resp.result.unwrap_or_default();
+                if result.tx_ids.is_empty() {
+                    return Ok(None);
+                }
+
+                // a short page means there are no more txs to fetch
+                let next = if (result.tx_ids.len() as u64) < MAX_ADDRESS_TXS_PAGE_SIZE {
+                    None
+                } else {
+                    Some(Some(result.cursor))
+                };
+                Ok(Some((result.tx_ids, next)))
             }
+        })
+        .map_ok(|tx_ids| stream::iter(tx_ids.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// e.g., "avm.getBlock" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Only available once the X-chain has been linearized.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblock>
+    pub async fn get_block(
+        &self,
+        http_rpc: &str,
+        block_id: ids::Id,
+    ) -> Result<avm::GetBlockResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("getting block {block_id} via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avm.getBlock");
+        let mut params = HashMap::new();
+        params.insert(String::from("blockID"), block_id.to_string());
+        params.insert(String::from("encoding"), String::from("hex")); // don't use "cb58"
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let u = if let Some(scheme) = scheme {
-        if let Some(port) = port {
-            format!("{scheme}://{host}:{port}/ext/bc/X")
-        } else {
-            format!("{scheme}://{host}/ext/bc/X")
-        }
-    } else {
-        format!("http://{host}/ext/bc/X")
-    };
-    log::info!("issuing a stop vertex transaction via {u}");
-
-    let mut data = avm::IssueStopVertexRequest::default();
-    data.method = String::from("avm.issueStopVertex");
-    let params = avm::IssueStopVertexParams {};
-    data.params = Some(params);
-    let d = data.encode_json().map_err(|e| Error::Other {
-        message: format!("failed encode_json '{}'", e),
-        retryable: false,
-    })?;
-
-    let req_cli_builder = ClientBuilder::new()
-        .user_agent(env!("CARGO_PKG_NAME"))
-        .danger_accept_invalid_certs(true)
-        .timeout(Duration::from_secs(15))
-        .connection_verbose(true)
-        .build()
-        .map_err(|e| {
-            // TODO: check retryable
-            Error::Other {
-                message: format!("failed reqwest::ClientBuilder.build '{}'", e),
-                retryable: false,
-            }
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "avm.getBlockByHeight" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Only available once the X-chain has been linearized.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblockbyheight>
+    pub async fn get_block_by_height(
+        &self,
+        http_rpc: &str,
+        height: u64,
+    ) -> Result<avm::GetBlockResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("getting block at height {height} via {u}");
+
+        let mut data = avm::GetBlockByHeightRequest::default();
+        data.method = String::from("avm.getBlockByHeight");
+        let params = avm::GetBlockByHeightParams {
+            height,
+            encoding: String::from("hex"), // don't use "cb58"
+        };
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
         })?;
-    let resp = req_cli_builder
-        .post(&u)
-        .header(CONTENT_TYPE, "application/json")
-        .body(d)
-        .send()
-        .await
-        .map_err(|e|
-            // TODO: check retryable
-            Error::API {
-                message: format!("failed reqwest::Client.send '{}'", e),
-                retryable: false,
-            })?;
-
-    if !resp.status().is_success() {
-        return Err(Error::API {
-            message: format!("status code non-success {}", resp.status()),
+
+        self.inner.post(&u, d).await
+    }
+
+    /// e.g., "avm.getHeight" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Only available once the X-chain has been linearized.
+    /// ref. <https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetheight>
+    pub async fn get_height(&self, http_rpc: &str) -> Result<avm::GetHeightResponse> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("getting height via {u}");
+
+        let mut data = jsonrpc::Request::default();
+        data.method = String::from("avm.getHeight");
+        let params = HashMap::new();
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
             retryable: false,
-        });
+        })?;
+
+        self.inner.post(&u, d).await
     }
 
-    Ok(())
+    /// e.g., "avm.issueStopVertex" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+    /// Issue itself is asynchronous, so the internal error is not exposed!
+    pub async fn issue_stop_vertex(&self, http_rpc: &str) -> Result<()> {
+        let u = super::url(http_rpc, "/ext/bc/X")?;
+        log::info!("issuing a stop vertex transaction via {u}");
+
+        let mut data = avm::IssueStopVertexRequest::default();
+        data.method = String::from("avm.issueStopVertex");
+        let params = avm::IssueStopVertexParams {};
+        data.params = Some(params);
+        let d = data.encode_json().map_err(|e| Error::Other {
+            message: format!("failed encode_json '{}'", e),
+            retryable: false,
+        })?;
+
+        let _: serde_json::Value = self.inner.post(&u, d).await?;
+        Ok(())
+    }
 }
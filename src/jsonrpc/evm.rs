@@ -1,6 +1,14 @@
-use crate::codec::serde::{hex_0x_bytes::Hex0xBytes, hex_0x_primitive_types_h256::Hex0xH256};
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    choices,
+    codec::serde::{
+        hex_0x_bytes::Hex0xBytes, hex_0x_primitive_types_h256::Hex0xH256, hex_0x_utxo::Hex0xUtxo,
+    },
+    ids, txs,
+};
 use serde::{Deserialize, Serialize};
-use serde_with::serde_as;
+use serde_with::{serde_as, DisplayFromStr};
 
 /// Response for "eth_blockNumber".
 /// ref. <https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_blocknumber>
@@ -340,3 +348,446 @@ fn test_send_raw_transaction() {
     };
     assert_eq!(resp, expected);
 }
+
+/// Request for "avax.getUTXOs" on the C-chain, which returns the atomic UTXOs
+/// exported from the source chain to the given addresses.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetutxos>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetUtxosRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetUtxosParams>,
+}
+
+impl Default for GetUtxosRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetUtxosRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetutxos>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUtxosParams {
+    pub addresses: Vec<String>,
+    /// Chain alias or blockchain Id that exported the UTXOs (e.g., "X", "P").
+    pub source_chain: String,
+    pub limit: u32,
+    /// Set to the "endIndex" of the previous call to fetch the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<super::EndIndex>,
+    pub encoding: String,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_get_utxos_request --exact --show-output
+#[test]
+fn test_get_utxos_request() {
+    let mut data = GetUtxosRequest::default();
+    data.method = String::from("avax.getUTXOs");
+    data.params = Some(GetUtxosParams {
+        addresses: vec![String::from(
+            "C-custom18jma8ppw3nhx5r4ap8clazz0dps7rv5u9xde7p",
+        )],
+        source_chain: String::from("X"),
+        limit: 1024,
+        start_index: None,
+        encoding: String::from("hex"),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"avax.getUTXOs\",\"params\":{\"addresses\":[\"C-custom18jma8ppw3nhx5r4ap8clazz0dps7rv5u9xde7p\"],\"sourceChain\":\"X\",\"limit\":1024,\"encoding\":\"hex\"}}"
+    );
+}
+
+/// Response for "avax.getAtomicTxStatus".
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetatomictxstatus>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetAtomicTxStatusResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetAtomicTxStatusResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<super::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetatomictxstatus>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAtomicTxStatusResult {
+    /// One of "Accepted", "Processing", "Dropped" or "Unknown".
+    #[serde_as(as = "DisplayFromStr")]
+    pub status: choices::status::Status,
+
+    /// Only set once the tx is accepted.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_height: Option<u64>,
+}
+
+impl Default for GetAtomicTxStatusResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetAtomicTxStatusResult {
+    pub fn default() -> Self {
+        Self {
+            status: choices::status::Status::Unknown(String::new()),
+            block_height: None,
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_get_atomic_tx_status --exact --show-output
+#[test]
+fn test_get_atomic_tx_status() {
+    // ref. https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetatomictxstatus
+    let resp: GetAtomicTxStatusResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"status\": \"Accepted\",
+        \"blockHeight\": \"1\"
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+    let expected = GetAtomicTxStatusResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetAtomicTxStatusResult {
+            status: choices::status::Status::Accepted,
+            block_height: Some(1),
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+
+    let resp: GetAtomicTxStatusResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"status\": \"Dropped\"
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+    assert_eq!(
+        resp.result.unwrap().status,
+        choices::status::Status::Unknown(String::from("Dropped"))
+    );
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetutxos>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetUtxosResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetUtxosResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<super::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetutxos>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetUtxosResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub num_fetched: u32,
+
+    #[serde_as(as = "Option<Vec<Hex0xUtxo>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utxos: Option<Vec<txs::utxo::Utxo>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_index: Option<super::EndIndex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+impl Default for GetUtxosResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetUtxosResult {
+    pub fn default() -> Self {
+        Self {
+            num_fetched: 0,
+            utxos: None,
+            end_index: None,
+            encoding: None,
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_get_utxos --exact --show-output
+#[test]
+fn test_get_utxos() {
+    let resp: GetUtxosResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"numFetched\": \"1\",
+        \"utxos\": [
+            \"0x000000000000000000000000000000000000000000000000000000000000000000000000000088eec2e099c6a528e689618e8721e04ae85ea574c7a15a7968644d14d54780140000000702c68af0bb1400000000000000000000000000010000000165844a05405f3662c1928142c6c2a783ef871de939b564db\"
+        ],
+        \"endIndex\": {
+            \"address\": \"C-avax1x459sj0ssujguq723cljfty4jlae28evjzt7xz\",
+            \"utxo\": \"LUC1cmcxnfNR9LdkACS2ccGKLEK7SYqB4gLLTycQfg1koyfSq\"
+        },
+        \"encoding\": \"hex\"
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+
+    let raw_utxo =  String::from("0x000000000000000000000000000000000000000000000000000000000000000000000000000088eec2e099c6a528e689618e8721e04ae85ea574c7a15a7968644d14d54780140000000702c68af0bb1400000000000000000000000000010000000165844a05405f3662c1928142c6c2a783ef871de939b564db");
+    let utxo = txs::utxo::Utxo::from_hex(&raw_utxo).unwrap();
+
+    let expected = GetUtxosResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetUtxosResult {
+            num_fetched: 1,
+            utxos: Some(vec![utxo]),
+            end_index: Some(super::EndIndex {
+                address: String::from("C-avax1x459sj0ssujguq723cljfty4jlae28evjzt7xz"),
+                utxo: String::from("LUC1cmcxnfNR9LdkACS2ccGKLEK7SYqB4gLLTycQfg1koyfSq"),
+            }),
+            encoding: Some(String::from("hex")),
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// Request for "avax.issueTx" to issue a signed atomic tx.
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxissuetx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct IssueTxRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<IssueTxParams>,
+}
+
+impl Default for IssueTxRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl IssueTxRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxissuetx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueTxParams {
+    pub tx: String,
+    pub encoding: String,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxissuetx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct IssueTxResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<IssueTxResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<super::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxissuetx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct IssueTxResult {
+    #[serde(rename = "txID")]
+    pub tx_id: ids::Id,
+}
+
+impl Default for IssueTxResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl IssueTxResult {
+    pub fn default() -> Self {
+        Self {
+            tx_id: ids::Id::empty(),
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_issue_tx --exact --show-output
+#[test]
+fn test_issue_tx() {
+    use std::str::FromStr;
+
+    let resp: IssueTxResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"txID\": \"G3BuH6ytQ2averrLxJJugjWZHTRubzCrUZEXoheG5JMqL5ccY\"
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+    let expected = IssueTxResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(IssueTxResult {
+            tx_id: ids::Id::from_str("G3BuH6ytQ2averrLxJJugjWZHTRubzCrUZEXoheG5JMqL5ccY").unwrap(),
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// Response for "avax.getAtomicTx".
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetatomictx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetAtomicTxResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetAtomicTxResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<super::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/c-chain#avaxgetatomictx>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAtomicTxResult {
+    /// Hex-encoded signed atomic tx with the checksum,
+    /// use "coreth::atomic::Tx::from_hex" to decode.
+    pub tx: String,
+    pub encoding: String,
+
+    /// Only set once the tx is accepted.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_height: Option<u64>,
+}
+
+impl Default for GetAtomicTxResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetAtomicTxResult {
+    pub fn default() -> Self {
+        Self {
+            tx: String::new(),
+            encoding: String::new(),
+            block_height: None,
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_get_atomic_tx --exact --show-output
+#[test]
+fn test_get_atomic_tx() {
+    let resp: GetAtomicTxResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"tx\": \"0x000000000000000030390000\",
+        \"encoding\": \"hex\",
+        \"blockHeight\": \"8\"
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+    let expected = GetAtomicTxResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetAtomicTxResult {
+            tx: String::from("0x000000000000000030390000"),
+            encoding: String::from("hex"),
+            block_height: Some(8),
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
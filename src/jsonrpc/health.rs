@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     io::{Error, ErrorKind},
     str::FromStr,
 };
@@ -23,6 +23,9 @@ pub struct Response {
 #[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CheckResult {
+    /// Check-specific details (e.g., the subnets still bootstrapping).
+    #[serde(default)]
+    pub message: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     #[serde_as(as = "crate::codec::serde::rfc_3339::DateTimeUtc")]
@@ -36,6 +39,22 @@ pub struct CheckResult {
     pub time_of_first_failure: Option<DateTime<Utc>>,
 }
 
+impl Response {
+    /// Returns the error of each failing check, keyed by the check name
+    /// (e.g., "bootstrapped" while the chains are still bootstrapping).
+    pub fn failing_checks(&self) -> BTreeMap<String, String> {
+        let mut failing = BTreeMap::new();
+        if let Some(checks) = &self.checks {
+            for (name, check) in checks.iter() {
+                if let Some(e) = &check.error {
+                    failing.insert(name.clone(), e.clone());
+                }
+            }
+        }
+        failing
+    }
+}
+
 /// ref. <https://doc.rust-lang.org/std/str/trait.FromStr.html>
 impl FromStr for Response {
     type Err = Error;
@@ -135,3 +154,46 @@ fn test_parse() {
     info!("parsed: {:?}", parsed);
     assert!(parsed.healthy);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::health::test_failing_checks --exact --show-output
+#[test]
+fn test_failing_checks() {
+    let data = r#"
+{
+    "checks": {
+        "bootstrapped": {
+            "message": [
+                "11111111111111111111111111111111LpoYY"
+            ],
+            "error": "subnets not bootstrapped",
+            "timestamp": "2022-02-16T08:15:01.766704522Z",
+            "duration": 8120,
+            "contiguousFailures": 3,
+            "timeOfFirstFailure": "2022-02-16T08:14:58.766704522Z"
+        },
+        "network": {
+            "message": {
+                "connectedPeers": 4
+            },
+            "timestamp": "2022-02-16T08:15:01.766702722Z",
+            "duration": 5600
+        }
+    },
+    "healthy": false
+}
+"#;
+
+    let parsed = Response::from_str(data).unwrap();
+    assert!(!parsed.healthy);
+
+    let bootstrapped = &parsed.checks.as_ref().unwrap()["bootstrapped"];
+    assert_eq!(bootstrapped.contiguous_failures, Some(3));
+    assert_eq!(
+        bootstrapped.message,
+        Some(serde_json::json!(["11111111111111111111111111111111LpoYY"]))
+    );
+
+    let failing = parsed.failing_checks();
+    assert_eq!(failing.len(), 1);
+    assert_eq!(failing["bootstrapped"], "subnets not bootstrapped");
+}
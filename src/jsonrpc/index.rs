@@ -0,0 +1,359 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::{ids, jsonrpc};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Maximum number of containers returned by a single "index.getContainerRange" call.
+/// ref. "avalanchego/indexer#MaxFetchedByRange"
+pub const MAX_FETCHED_BY_RANGE: u64 = 1024;
+
+/// Represents an accepted container (e.g., block, vertex, or tx) and its index.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/indexer#FormattedContainer>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Container {
+    pub id: ids::Id,
+    /// Hex-encoded container bytes with the checksum.
+    pub bytes: String,
+    /// Time when the node accepted the container.
+    #[serde_as(as = "crate::codec::serde::rfc_3339::DateTimeUtc")]
+    pub timestamp: DateTime<Utc>,
+    pub encoding: String,
+    /// Order in which the container was accepted, starting from zero.
+    #[serde_as(as = "DisplayFromStr")]
+    pub index: u64,
+}
+
+impl Container {
+    /// Decodes the hex-encoded container into the raw bytes,
+    /// to be parsed with the decoder of the indexed container type
+    /// (e.g., "avm::txs::SignedTx" for "/ext/index/X/tx").
+    pub fn container_bytes(&self) -> crate::errors::Result<Vec<u8>> {
+        let d = self.bytes.trim_start_matches("0x");
+        crate::formatting::decode_hex_with_checksum(d.as_bytes()).map_err(|e| {
+            crate::errors::Error::Other {
+                message: format!("failed formatting::decode_hex_with_checksum '{}'", e),
+                retryable: false,
+            }
+        })
+    }
+}
+
+/// Response for "index.getLastAccepted", "index.getContainerByIndex"
+/// and "index.getContainerByID".
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetlastaccepted>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetContainerResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Container>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::index::test_get_container --exact --show-output
+#[test]
+fn test_get_container() {
+    use std::str::FromStr;
+
+    let tx_bytes = vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05];
+    let resp: GetContainerResponse = serde_json::from_str(&format!(
+        r#"
+{{
+    "jsonrpc": "2.0",
+    "result": {{
+        "id": "6fXf5hncR8LXvwtM8iezFQBpK5cubV6y1dWgpJCcNyzGB1EzY",
+        "bytes": "0x{}",
+        "timestamp": "2021-04-02T15:34:00.262979-07:00",
+        "encoding": "hex",
+        "index": "0"
+    }},
+    "id": 1
+}}
+"#,
+        crate::formatting::encode_hex_with_checksum(&tx_bytes)
+    ))
+    .unwrap();
+
+    let container = resp.result.unwrap();
+    assert_eq!(
+        container.id,
+        ids::Id::from_str("6fXf5hncR8LXvwtM8iezFQBpK5cubV6y1dWgpJCcNyzGB1EzY").unwrap()
+    );
+    assert_eq!(
+        container.timestamp,
+        DateTime::parse_from_rfc3339("2021-04-02T22:34:00.262979Z").unwrap()
+    );
+    assert_eq!(container.index, 0);
+    assert_eq!(container.container_bytes().unwrap(), tx_bytes);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerbyindex>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetContainerByIndexRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetContainerByIndexParams>,
+}
+
+impl Default for GetContainerByIndexRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetContainerByIndexRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerbyindex>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetContainerByIndexParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub index: u64,
+    pub encoding: String,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::index::test_get_container_by_index --exact --show-output
+#[test]
+fn test_get_container_by_index() {
+    let mut data = GetContainerByIndexRequest::default();
+    data.method = String::from("index.getContainerByIndex");
+    data.params = Some(GetContainerByIndexParams {
+        index: 123,
+        encoding: String::from("hex"),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"index.getContainerByIndex\",\"params\":{\"index\":\"123\",\"encoding\":\"hex\"}}"
+    );
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetContainerRangeRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetContainerRangeParams>,
+}
+
+impl Default for GetContainerRangeRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetContainerRangeRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetContainerRangeParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub start_index: u64,
+    /// Up to "MAX_FETCHED_BY_RANGE".
+    #[serde_as(as = "DisplayFromStr")]
+    pub num_to_fetch: u64,
+    pub encoding: String,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetContainerRangeResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetContainerRangeResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetContainerRangeResult {
+    pub containers: Vec<Container>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::index::test_get_container_range --exact --show-output
+#[test]
+fn test_get_container_range() {
+    use std::str::FromStr;
+
+    let mut data = GetContainerRangeRequest::default();
+    data.method = String::from("index.getContainerRange");
+    data.params = Some(GetContainerRangeParams {
+        start_index: 10,
+        num_to_fetch: 100,
+        encoding: String::from("hex"),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"index.getContainerRange\",\"params\":{\"startIndex\":\"10\",\"numToFetch\":\"100\",\"encoding\":\"hex\"}}"
+    );
+
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange>
+    let resp: GetContainerRangeResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "containers": [
+            {
+                "id": "6fXf5hncR8LXvwtM8iezFQBpK5cubV6y1dWgpJCcNyzGB1EzY",
+                "bytes": "0x0000000000000000",
+                "timestamp": "2021-04-02T15:34:00.262979-07:00",
+                "encoding": "hex",
+                "index": "0"
+            }
+        ]
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let containers = resp.result.unwrap().containers;
+    assert_eq!(containers.len(), 1);
+    assert_eq!(
+        containers[0].id,
+        ids::Id::from_str("6fXf5hncR8LXvwtM8iezFQBpK5cubV6y1dWgpJCcNyzGB1EzY").unwrap()
+    );
+    assert_eq!(containers[0].index, 0);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetindex>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetIndexResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetIndexResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetindex>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetIndexResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub index: u64,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::index::test_get_index --exact --show-output
+#[test]
+fn test_get_index() {
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetindex>
+    let resp: GetIndexResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "index": "0"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetIndexResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetIndexResult { index: 0 }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexisaccepted>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct IsAcceptedResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<IsAcceptedResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexisaccepted>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsAcceptedResult {
+    pub is_accepted: bool,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::index::test_is_accepted --exact --show-output
+#[test]
+fn test_is_accepted() {
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/index-api#indexisaccepted>
+    let resp: IsAcceptedResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "isAccepted": true
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = IsAcceptedResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(IsAcceptedResult { is_accepted: true }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
@@ -369,6 +369,18 @@ pub struct VmVersions {
     pub subnets: HashMap<String, String>,
 }
 
+impl VmVersions {
+    /// Returns the version of the VM by its name (e.g., "platform", "subnet-evm").
+    pub fn get(&self, vm: &str) -> Option<&str> {
+        match vm {
+            "avm" => Some(self.avm.as_str()),
+            "evm" => Some(self.evm.as_str()),
+            "platform" => Some(self.platform.as_str()),
+            _ => self.subnets.get(vm).map(String::as_str),
+        }
+    }
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::info::test_get_node_version --exact --show-output
 #[test]
 fn test_get_node_version() {
@@ -411,6 +423,11 @@ fn test_get_node_version() {
         error: None,
     };
     assert_eq!(resp, expected);
+
+    let vm_versions = resp.result.unwrap().vm_versions;
+    assert_eq!(vm_versions.get("platform"), Some("v1.10.1"));
+    assert_eq!(vm_versions.get("subnet-evm"), Some("v0.5.1"));
+    assert_eq!(vm_versions.get("xsvm"), None);
 }
 
 /// ref. <https://docs.avax.network/build/avalanchego-apis/info/#infogetvms>
@@ -651,6 +668,97 @@ fn test_uptime() {
     assert_eq!(resp, expected);
 }
 
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/info#infoacps>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct AcpsResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<AcpsResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/info#infoacps>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct AcpsResult {
+    /// Peer preferences keyed by the ACP number.
+    pub acps: HashMap<u32, Acp>,
+}
+
+/// Represents the stake-weighted preferences of the peers on an
+/// Avalanche Community Proposal (ACP).
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/api/info#ACP>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Acp {
+    #[serde_as(as = "DisplayFromStr")]
+    pub support_weight: u64,
+    #[serde(default)]
+    pub supporters: Vec<node::Id>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub object_weight: u64,
+    #[serde(default)]
+    pub objectors: Vec<node::Id>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub abstain_weight: u64,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::info::test_acps --exact --show-output
+#[test]
+fn test_acps() {
+    use std::str::FromStr;
+
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/info#infoacps>
+    let resp: AcpsResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "acps": {
+            "23": {
+                "supportWeight": "0",
+                "supporters": [],
+                "objectWeight": "0",
+                "objectors": [],
+                "abstainWeight": "161147778098286584"
+            },
+            "24": {
+                "supportWeight": "2000000000000",
+                "supporters": [
+                    "NodeID-8PYXX47kqLDe2wD4oPbvRRchcnSzMA4J4"
+                ],
+                "objectWeight": "0",
+                "objectors": [],
+                "abstainWeight": "161145778098286584"
+            }
+        }
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let acps = resp.result.unwrap().acps;
+    assert_eq!(acps.len(), 2);
+    assert_eq!(acps[&23].abstain_weight, 161147778098286584);
+    assert_eq!(
+        acps[&24],
+        Acp {
+            support_weight: 2000000000000,
+            supporters: vec![
+                node::Id::from_str("NodeID-8PYXX47kqLDe2wD4oPbvRRchcnSzMA4J4").unwrap()
+            ],
+            abstain_weight: 161145778098286584,
+            ..Acp::default()
+        }
+    );
+}
+
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/info#infopeers>
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct PeersRequest {
@@ -728,7 +836,6 @@ impl PeersResult {
     }
 }
 
-/// TODO: add "benched"
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/info#infopeers>
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -746,11 +853,17 @@ pub struct Peer {
     pub last_sent: DateTime<Utc>,
     #[serde_as(as = "crate::codec::serde::rfc_3339::DateTimeUtc")]
     pub last_received: DateTime<Utc>,
+    /// Uptime percentage of the local node as observed by the peer.
     #[serde_as(as = "DisplayFromStr")]
     pub observed_uptime: u32,
     #[serde_as(as = "HashMap<_, DisplayFromStr>")]
+    #[serde(default)]
     pub observed_subnet_uptimes: HashMap<ids::Id, u32>,
+    #[serde(default)]
     pub tracked_subnets: Vec<ids::Id>,
+    /// Chains on which the peer is benched for being unresponsive.
+    #[serde(default)]
+    pub benched: Vec<ids::Id>,
 }
 
 impl Default for Peer {
@@ -771,6 +884,7 @@ impl Peer {
             observed_uptime: 0,
             observed_subnet_uptimes: HashMap::new(),
             tracked_subnets: Vec::new(),
+            benched: Vec::new(),
         }
     }
 }
@@ -801,8 +915,7 @@ fn test_peers() {
                 \"benched\": [],
                 \"observedUptime\": \"99\",
                 \"observedSubnetUptimes\": {},
-                \"trackedSubnets\": [],
-                \"benched\": []
+                \"trackedSubnets\": []
             },
             {
                 \"ip\": \"158.255.67.151:9651\",
@@ -811,15 +924,16 @@ fn test_peers() {
                 \"version\": \"avalanche/1.9.4\",
                 \"lastSent\": \"2020-06-01T15:23:02Z\",
                 \"lastReceived\": \"2020-06-01T15:22:34Z\",
-                \"benched\": [],
+                \"benched\": [
+                    \"2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5\"
+                ],
                 \"observedUptime\": \"75\",
                 \"observedSubnetUptimes\": {
                     \"29uVeLPJB1eQJkzRemU8g8wZDw5uJRqpab5U2mX9euieVwiEbL\": \"100\"
                 },
                 \"trackedSubnets\": [
                     \"29uVeLPJB1eQJkzRemU8g8wZDw5uJRqpab5U2mX9euieVwiEbL\"
-                ],
-                \"benched\": []
+                ]
             }
         ]
     },
@@ -884,6 +998,10 @@ fn test_peers() {
                         "29uVeLPJB1eQJkzRemU8g8wZDw5uJRqpab5U2mX9euieVwiEbL",
                     )
                     .unwrap()],
+                    benched: vec![ids::Id::from_str(
+                        "2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5",
+                    )
+                    .unwrap()],
                     ..Peer::default()
                 },
             ]),
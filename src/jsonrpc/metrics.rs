@@ -0,0 +1,758 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+
+use crate::errors::{Error, Result};
+
+/// Label names and values of a metric, sorted by the label name.
+pub type Labels = BTreeMap<String, String>;
+
+/// Represents the Prometheus metric type.
+/// ref. <https://prometheus.io/docs/instrumenting/exposition_formats/#comments-help-text-and-type-information>
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+    Histogram,
+    Summary,
+    Untyped,
+}
+
+impl MetricType {
+    /// Parses the type in the "# TYPE" line, unknown types are untyped.
+    pub fn from_type_str(s: &str) -> Self {
+        match s {
+            "counter" => MetricType::Counter,
+            "gauge" => MetricType::Gauge,
+            "histogram" => MetricType::Histogram,
+            "summary" => MetricType::Summary,
+            _ => MetricType::Untyped,
+        }
+    }
+}
+
+/// Represents all metrics of the same name (e.g., all label sets of a counter).
+/// ref. <https://pkg.go.dev/github.com/prometheus/client_model/go#MetricFamily>
+#[derive(Debug, PartialEq, Clone)]
+pub struct MetricFamily {
+    pub name: String,
+    pub help: String,
+    pub metric_type: MetricType,
+    /// Metrics in the order of appearance.
+    pub metrics: Vec<Metric>,
+}
+
+impl MetricFamily {
+    pub fn new(name: &str, metric_type: MetricType) -> Self {
+        Self {
+            name: name.to_string(),
+            help: String::new(),
+            metric_type,
+            metrics: Vec::new(),
+        }
+    }
+
+    /// Returns the first metric that has all the labels (i.e., a subset match).
+    /// Set "labels" to empty to return the first metric.
+    pub fn find(&self, labels: &[(&str, &str)]) -> Option<&Metric> {
+        self.metrics.iter().find(|m| m.matches(labels))
+    }
+
+    fn metric_mut(&mut self, labels: Labels, value: Value) -> &mut Metric {
+        let idx = match self.metrics.iter().position(|m| m.labels == labels) {
+            Some(idx) => idx,
+            None => {
+                self.metrics.push(Metric {
+                    labels,
+                    value,
+                    timestamp_ms: None,
+                });
+                self.metrics.len() - 1
+            }
+        };
+        &mut self.metrics[idx]
+    }
+}
+
+/// Represents a single metric of the family with its label set.
+/// ref. <https://pkg.go.dev/github.com/prometheus/client_model/go#Metric>
+#[derive(Debug, PartialEq, Clone)]
+pub struct Metric {
+    pub labels: Labels,
+    pub value: Value,
+    pub timestamp_ms: Option<i64>,
+}
+
+impl Metric {
+    /// Returns true if the metric has all the labels.
+    pub fn matches(&self, labels: &[(&str, &str)]) -> bool {
+        labels
+            .iter()
+            .all(|(k, v)| self.labels.get(*k).map(String::as_str) == Some(*v))
+    }
+
+    /// Returns the value of the counter, gauge or untyped metric.
+    pub fn scalar(&self) -> Option<f64> {
+        match &self.value {
+            Value::Counter(v) | Value::Gauge(v) | Value::Untyped(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+/// Represents the value of a metric for its type.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Value {
+    Counter(f64),
+    Gauge(f64),
+    Untyped(f64),
+    Histogram(Histogram),
+    Summary(Summary),
+}
+
+/// ref. <https://pkg.go.dev/github.com/prometheus/client_model/go#Histogram>
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Histogram {
+    pub sample_count: u64,
+    pub sample_sum: f64,
+    /// Buckets in the order of appearance (i.e., increasing upper bounds).
+    pub buckets: Vec<Bucket>,
+}
+
+/// ref. <https://pkg.go.dev/github.com/prometheus/client_model/go#Bucket>
+#[derive(Debug, PartialEq, Clone)]
+pub struct Bucket {
+    pub upper_bound: f64,
+    pub cumulative_count: u64,
+}
+
+/// ref. <https://pkg.go.dev/github.com/prometheus/client_model/go#Summary>
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Summary {
+    pub sample_count: u64,
+    pub sample_sum: f64,
+    pub quantiles: Vec<Quantile>,
+}
+
+/// ref. <https://pkg.go.dev/github.com/prometheus/client_model/go#Quantile>
+#[derive(Debug, PartialEq, Clone)]
+pub struct Quantile {
+    pub quantile: f64,
+    pub value: f64,
+}
+
+/// Represents the change of a single time series between two snapshots.
+/// Histograms and summaries are compared by their "_count" and "_sum" series.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Delta {
+    pub name: String,
+    pub labels: Labels,
+    /// "None" if the series is new in the current snapshot.
+    pub previous: Option<f64>,
+    pub current: f64,
+}
+
+impl Delta {
+    /// Returns the difference from the previous value (zero if new).
+    pub fn delta(&self) -> f64 {
+        self.current - self.previous.unwrap_or_default()
+    }
+}
+
+/// Represents a snapshot of all metric families scraped from the node,
+/// parsed from the Prometheus text exposition format.
+/// ref. <https://prometheus.io/docs/instrumenting/exposition_formats/#text-based-format>
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Metrics {
+    /// Metric families keyed by the family name.
+    pub families: BTreeMap<String, MetricFamily>,
+}
+
+impl Metrics {
+    /// Parses the Prometheus text exposition format.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut families: BTreeMap<String, MetricFamily> = BTreeMap::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            parse_line(&mut families, line).map_err(|e| Error::Other {
+                message: format!(
+                    "failed to parse line {} '{}' ({})",
+                    i + 1,
+                    line,
+                    e.message()
+                ),
+                retryable: false,
+            })?;
+        }
+        Ok(Self { families })
+    }
+
+    /// Returns the metric family by its name.
+    pub fn get(&self, name: &str) -> Option<&MetricFamily> {
+        self.families.get(name)
+    }
+
+    /// Returns the first metric of the family that has all the labels.
+    pub fn find(&self, name: &str, labels: &[(&str, &str)]) -> Option<&Metric> {
+        self.families.get(name)?.find(labels)
+    }
+
+    /// Returns the value of the counter, gauge or untyped metric with the labels.
+    pub fn value(&self, name: &str, labels: &[(&str, &str)]) -> Option<f64> {
+        self.find(name, labels)?.scalar()
+    }
+
+    /// Returns all time series as (name, labels, value), flattening
+    /// histograms and summaries into their "_count" and "_sum" series.
+    pub fn series(&self) -> Vec<(String, Labels, f64)> {
+        let mut series = Vec::new();
+        for family in self.families.values() {
+            for m in family.metrics.iter() {
+                let (count, sum) = match &m.value {
+                    Value::Counter(v) | Value::Gauge(v) | Value::Untyped(v) => {
+                        series.push((family.name.clone(), m.labels.clone(), *v));
+                        continue;
+                    }
+                    Value::Histogram(h) => (h.sample_count, h.sample_sum),
+                    Value::Summary(s) => (s.sample_count, s.sample_sum),
+                };
+                series.push((
+                    format!("{}_count", family.name),
+                    m.labels.clone(),
+                    count as f64,
+                ));
+                series.push((format!("{}_sum", family.name), m.labels.clone(), sum));
+            }
+        }
+        series
+    }
+
+    /// Returns the series that changed or appeared since the previous snapshot.
+    pub fn diff(&self, previous: &Metrics) -> Vec<Delta> {
+        let prev: HashMap<(String, Labels), f64> = previous
+            .series()
+            .into_iter()
+            .map(|(name, labels, v)| ((name, labels), v))
+            .collect();
+
+        let mut deltas = Vec::new();
+        for (name, labels, current) in self.series() {
+            let previous = prev.get(&(name.clone(), labels.clone())).copied();
+            let unchanged = match previous {
+                Some(p) => p == current || (p.is_nan() && current.is_nan()),
+                None => false,
+            };
+            if !unchanged {
+                deltas.push(Delta {
+                    name,
+                    labels,
+                    previous,
+                    current,
+                });
+            }
+        }
+        deltas
+    }
+}
+
+/// ref. <https://doc.rust-lang.org/std/str/trait.FromStr.html>
+impl FromStr for Metrics {
+    type Err = Error;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn parse_line(families: &mut BTreeMap<String, MetricFamily>, line: &str) -> Result<()> {
+    if let Some(comment) = line.strip_prefix('#') {
+        let mut parts = comment.trim_start().splitn(3, char::is_whitespace);
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("HELP"), Some(name), help) => {
+                families
+                    .entry(name.to_string())
+                    .or_insert_with(|| MetricFamily::new(name, MetricType::Untyped))
+                    .help = unescape(help.unwrap_or_default().trim());
+            }
+            (Some("TYPE"), Some(name), Some(t)) => {
+                families
+                    .entry(name.to_string())
+                    .or_insert_with(|| MetricFamily::new(name, MetricType::Untyped))
+                    .metric_type = MetricType::from_type_str(t.trim());
+            }
+            // other comments are ignored
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    let (name, mut labels, value, timestamp_ms) = parse_sample(line)?;
+
+    // "_bucket", "_sum" and "_count" samples belong to the histogram or summary family
+    let mut family_name = name.as_str();
+    let mut suffix = "";
+    for s in ["_bucket", "_sum", "_count"] {
+        if let Some(base) = name.strip_suffix(s) {
+            if matches!(
+                families.get(base).map(|f| f.metric_type),
+                Some(MetricType::Histogram | MetricType::Summary)
+            ) {
+                family_name = base;
+                suffix = s;
+                break;
+            }
+        }
+    }
+    let family = families
+        .entry(family_name.to_string())
+        .or_insert_with(|| MetricFamily::new(family_name, MetricType::Untyped));
+
+    match family.metric_type {
+        MetricType::Counter | MetricType::Gauge | MetricType::Untyped => {
+            let value = match family.metric_type {
+                MetricType::Counter => Value::Counter(value),
+                MetricType::Gauge => Value::Gauge(value),
+                _ => Value::Untyped(value),
+            };
+            family.metrics.push(Metric {
+                labels,
+                value,
+                timestamp_ms,
+            });
+        }
+        MetricType::Histogram => {
+            let le = if suffix == "_bucket" {
+                Some(parse_float(&labels.remove("le").ok_or_else(|| {
+                    Error::Other {
+                        message: "no 'le' label in bucket".to_string(),
+                        retryable: false,
+                    }
+                })?)?)
+            } else {
+                None
+            };
+            let m = family.metric_mut(labels, Value::Histogram(Histogram::default()));
+            m.timestamp_ms = timestamp_ms.or(m.timestamp_ms);
+            if let Value::Histogram(h) = &mut m.value {
+                match suffix {
+                    "_bucket" => h.buckets.push(Bucket {
+                        upper_bound: le.unwrap_or(f64::INFINITY),
+                        cumulative_count: value as u64,
+                    }),
+                    "_sum" => h.sample_sum = value,
+                    "_count" => h.sample_count = value as u64,
+                    _ => {
+                        return Err(Error::Other {
+                            message: "unexpected histogram sample".to_string(),
+                            retryable: false,
+                        })
+                    }
+                }
+            }
+        }
+        MetricType::Summary => {
+            let quantile = match labels.remove("quantile") {
+                Some(q) if suffix.is_empty() => Some(parse_float(&q)?),
+                _ => None,
+            };
+            let m = family.metric_mut(labels, Value::Summary(Summary::default()));
+            m.timestamp_ms = timestamp_ms.or(m.timestamp_ms);
+            if let Value::Summary(s) = &mut m.value {
+                match (suffix, quantile) {
+                    ("", Some(quantile)) => s.quantiles.push(Quantile { quantile, value }),
+                    ("_sum", _) => s.sample_sum = value,
+                    ("_count", _) => s.sample_count = value as u64,
+                    _ => {
+                        return Err(Error::Other {
+                            message: "unexpected summary sample".to_string(),
+                            retryable: false,
+                        })
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses the sample line "name{label="value",...} value [timestamp]".
+fn parse_sample(line: &str) -> Result<(String, Labels, f64, Option<i64>)> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .ok_or_else(|| Error::Other {
+            message: "no value".to_string(),
+            retryable: false,
+        })?;
+    let name = line[..name_end].to_string();
+
+    let mut labels = Labels::new();
+    let mut rest = &line[name_end..];
+    if let Some(s) = rest.strip_prefix('{') {
+        let (parsed, end) = parse_labels(s)?;
+        labels = parsed;
+        rest = &s[end..];
+    }
+
+    let mut parts = rest.split_whitespace();
+    let value = parse_float(parts.next().ok_or_else(|| Error::Other {
+        message: "no value".to_string(),
+        retryable: false,
+    })?)?;
+    let timestamp_ms = match parts.next() {
+        Some(ts) => Some(ts.parse::<i64>().map_err(|e| Error::Other {
+            message: format!("invalid timestamp ({})", e),
+            retryable: false,
+        })?),
+        None => None,
+    };
+    Ok((name, labels, value, timestamp_ms))
+}
+
+/// Parses the labels after "{", and returns them with the byte offset after the closing "}".
+fn parse_labels(s: &str) -> Result<(Labels, usize)> {
+    let mut labels = Labels::new();
+    let mut label = String::new();
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '}' if label.is_empty() => return Ok((labels, i + 1)),
+            ',' if label.is_empty() => {}
+            c if c.is_whitespace() => {}
+            '=' => {
+                if !matches!(chars.next(), Some((_, '"'))) {
+                    return Err(Error::Other {
+                        message: "unquoted label value".to_string(),
+                        retryable: false,
+                    });
+                }
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, 'n')) => value.push('\n'),
+                            Some((_, c)) => value.push(c),
+                            None => break,
+                        },
+                        Some((_, '"')) => break,
+                        Some((_, c)) => value.push(c),
+                        None => {
+                            return Err(Error::Other {
+                                message: "unclosed label value".to_string(),
+                                retryable: false,
+                            })
+                        }
+                    }
+                }
+                labels.insert(std::mem::take(&mut label), value);
+            }
+            c => label.push(c),
+        }
+    }
+    Err(Error::Other {
+        message: "unclosed labels".to_string(),
+        retryable: false,
+    })
+}
+
+/// Parses the sample value, including "+Inf", "-Inf" and "NaN".
+fn parse_float(s: &str) -> Result<f64> {
+    match s {
+        "+Inf" | "Inf" => Ok(f64::INFINITY),
+        "-Inf" => Ok(f64::NEG_INFINITY),
+        "NaN" => Ok(f64::NAN),
+        _ => s.parse::<f64>().map_err(|e| Error::Other {
+            message: format!("invalid value '{}' ({})", s, e),
+            retryable: false,
+        }),
+    }
+}
+
+/// Unescapes "\\" and "\n" in the help text.
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('\\') => out.push('\\'),
+            Some(c) => {
+                out.push('\\');
+                out.push(c);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(feature = "proto")]
+impl From<&Metric> for crate::proto::pb::io::prometheus::client::Metric {
+    fn from(item: &Metric) -> Self {
+        use crate::proto::pb::io::prometheus::client as pb;
+
+        let mut m = pb::Metric {
+            label: item
+                .labels
+                .iter()
+                .map(|(name, value)| pb::LabelPair {
+                    name: Some(name.clone()),
+                    value: Some(value.clone()),
+                })
+                .collect(),
+            timestamp_ms: item.timestamp_ms,
+            ..Default::default()
+        };
+        match &item.value {
+            Value::Counter(v) => {
+                m.counter = Some(pb::Counter {
+                    value: Some(*v),
+                    exemplar: None,
+                })
+            }
+            Value::Gauge(v) => m.gauge = Some(pb::Gauge { value: Some(*v) }),
+            Value::Untyped(v) => m.untyped = Some(pb::Untyped { value: Some(*v) }),
+            Value::Histogram(h) => {
+                m.histogram = Some(pb::Histogram {
+                    sample_count: Some(h.sample_count),
+                    sample_sum: Some(h.sample_sum),
+                    bucket: h
+                        .buckets
+                        .iter()
+                        .map(|b| pb::Bucket {
+                            cumulative_count: Some(b.cumulative_count),
+                            upper_bound: Some(b.upper_bound),
+                            exemplar: None,
+                        })
+                        .collect(),
+                })
+            }
+            Value::Summary(s) => {
+                m.summary = Some(pb::Summary {
+                    sample_count: Some(s.sample_count),
+                    sample_sum: Some(s.sample_sum),
+                    quantile: s
+                        .quantiles
+                        .iter()
+                        .map(|q| pb::Quantile {
+                            quantile: Some(q.quantile),
+                            value: Some(q.value),
+                        })
+                        .collect(),
+                })
+            }
+        }
+        m
+    }
+}
+
+#[cfg(feature = "proto")]
+impl From<&MetricFamily> for crate::proto::pb::io::prometheus::client::MetricFamily {
+    fn from(item: &MetricFamily) -> Self {
+        use crate::proto::pb::io::prometheus::client as pb;
+
+        let metric_type = match item.metric_type {
+            MetricType::Counter => pb::MetricType::Counter,
+            MetricType::Gauge => pb::MetricType::Gauge,
+            MetricType::Histogram => pb::MetricType::Histogram,
+            MetricType::Summary => pb::MetricType::Summary,
+            MetricType::Untyped => pb::MetricType::Untyped,
+        };
+        pb::MetricFamily {
+            name: Some(item.name.clone()),
+            help: Some(item.help.clone()),
+            r#type: Some(metric_type as i32),
+            metric: item.metrics.iter().map(pb::Metric::from).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "proto")]
+impl Metrics {
+    /// Converts all metric families to the protobuf types.
+    pub fn to_proto(&self) -> Vec<crate::proto::pb::io::prometheus::client::MetricFamily> {
+        self.families.values().map(Into::into).collect()
+    }
+}
+
+#[cfg(test)]
+const TEST_METRICS: &str = r#"
+# HELP avalanche_X_blks_accepted_count Number of blocks accepted
+# TYPE avalanche_X_blks_accepted_count counter
+avalanche_X_blks_accepted_count 12
+# HELP avalanche_network_peers Number of network peers
+# TYPE avalanche_network_peers gauge
+avalanche_network_peers 4
+# HELP avalanche_requests_latency Request latency in seconds
+# TYPE avalanche_requests_latency histogram
+avalanche_requests_latency_bucket{chain="P",le="0.1"} 3
+avalanche_requests_latency_bucket{chain="P",le="1"} 5
+avalanche_requests_latency_bucket{chain="P",le="+Inf"} 6
+avalanche_requests_latency_sum{chain="P"} 4.5
+avalanche_requests_latency_count{chain="P"} 6
+# HELP go_gc_duration_seconds A summary of the pause duration of garbage collection cycles.
+# TYPE go_gc_duration_seconds summary
+go_gc_duration_seconds{quantile="0"} 2.1e-05
+go_gc_duration_seconds{quantile="0.5"} 6.1e-05
+go_gc_duration_seconds{quantile="1"} NaN
+go_gc_duration_seconds_sum 0.0012
+go_gc_duration_seconds_count 17
+# HELP avalanche_handler_msgs Messages handled, with "escaped\\help\ntext"
+# TYPE avalanche_handler_msgs counter
+avalanche_handler_msgs{chain="C",op="get, put",path="a\"b\\c"} 7 1395066363000
+avalanche_handler_msgs{chain="X",op="chits"} 3
+no_type_metric{a="b"} -Inf
+"#;
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::metrics::test_parse --exact --show-output
+#[test]
+fn test_parse() {
+    let metrics = Metrics::from_str(TEST_METRICS).unwrap();
+    assert_eq!(metrics.families.len(), 6);
+
+    assert_eq!(
+        metrics.value("avalanche_X_blks_accepted_count", &[]),
+        Some(12.0)
+    );
+    assert_eq!(
+        metrics.get("avalanche_network_peers").unwrap().metric_type,
+        MetricType::Gauge
+    );
+    assert_eq!(metrics.value("avalanche_network_peers", &[]), Some(4.0));
+
+    let family = metrics.get("avalanche_requests_latency").unwrap();
+    assert_eq!(family.metric_type, MetricType::Histogram);
+    assert_eq!(family.metrics.len(), 1);
+    let m = family.find(&[("chain", "P")]).unwrap();
+    assert_eq!(
+        m.value,
+        Value::Histogram(Histogram {
+            sample_count: 6,
+            sample_sum: 4.5,
+            buckets: vec![
+                Bucket {
+                    upper_bound: 0.1,
+                    cumulative_count: 3
+                },
+                Bucket {
+                    upper_bound: 1.0,
+                    cumulative_count: 5
+                },
+                Bucket {
+                    upper_bound: f64::INFINITY,
+                    cumulative_count: 6
+                },
+            ],
+        })
+    );
+
+    let m = metrics.find("go_gc_duration_seconds", &[]).unwrap();
+    match &m.value {
+        Value::Summary(s) => {
+            assert_eq!(s.sample_count, 17);
+            assert_eq!(s.sample_sum, 0.0012);
+            assert_eq!(s.quantiles.len(), 3);
+            assert_eq!(s.quantiles[1].quantile, 0.5);
+            assert_eq!(s.quantiles[1].value, 6.1e-05);
+            assert!(s.quantiles[2].value.is_nan());
+        }
+        v => panic!("unexpected value {:?}", v),
+    }
+
+    let family = metrics.get("avalanche_handler_msgs").unwrap();
+    assert_eq!(
+        family.help,
+        "Messages handled, with \"escaped\\help\ntext\""
+    );
+    let m = family.find(&[("chain", "C")]).unwrap();
+    assert_eq!(m.labels["op"], "get, put");
+    assert_eq!(m.labels["path"], "a\"b\\c");
+    assert_eq!(m.timestamp_ms, Some(1395066363000));
+    assert_eq!(
+        metrics.value("avalanche_handler_msgs", &[("chain", "X"), ("op", "chits")]),
+        Some(3.0)
+    );
+    assert!(metrics
+        .find("avalanche_handler_msgs", &[("chain", "P")])
+        .is_none());
+
+    let family = metrics.get("no_type_metric").unwrap();
+    assert_eq!(family.metric_type, MetricType::Untyped);
+    assert_eq!(family.metrics[0].value, Value::Untyped(f64::NEG_INFINITY));
+
+    assert!(Metrics::from_str("invalid_metric{a=\"b\" 1").is_err());
+    assert!(Metrics::from_str("invalid_metric abc").is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::metrics::test_diff --exact --show-output
+#[test]
+fn test_diff() {
+    let previous = Metrics::from_str(TEST_METRICS).unwrap();
+    let current = Metrics::from_str(
+        &TEST_METRICS
+            .replace(
+                "avalanche_X_blks_accepted_count 12",
+                "avalanche_X_blks_accepted_count 15",
+            )
+            .replace(
+                "avalanche_requests_latency_count{chain=\"P\"} 6",
+                "avalanche_requests_latency_count{chain=\"P\"} 8",
+            ),
+    )
+    .unwrap();
+    assert!(current.diff(&current).is_empty());
+
+    let deltas = current.diff(&previous);
+    assert_eq!(deltas.len(), 2);
+    assert_eq!(deltas[0].name, "avalanche_X_blks_accepted_count");
+    assert_eq!(deltas[0].previous, Some(12.0));
+    assert_eq!(deltas[0].delta(), 3.0);
+    assert_eq!(deltas[1].name, "avalanche_requests_latency_count");
+    assert_eq!(deltas[1].labels["chain"], "P");
+    assert_eq!(deltas[1].delta(), 2.0);
+
+    let deltas = current.diff(&Metrics::default());
+    assert_eq!(deltas.len(), current.series().len());
+    assert!(deltas.iter().all(|d| d.previous.is_none()));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::metrics::test_to_proto --exact --show-output
+#[cfg(feature = "proto")]
+#[test]
+fn test_to_proto() {
+    use crate::proto::pb::io::prometheus::client as pb;
+
+    let metrics = Metrics::from_str(TEST_METRICS).unwrap();
+    let mfs = metrics.to_proto();
+    assert_eq!(mfs.len(), 6);
+
+    let mf = mfs
+        .iter()
+        .find(|mf| mf.name.as_deref() == Some("avalanche_requests_latency"))
+        .unwrap();
+    assert_eq!(mf.r#type, Some(pb::MetricType::Histogram as i32));
+    let h = mf.metric[0].histogram.as_ref().unwrap();
+    assert_eq!(h.sample_count, Some(6));
+    assert_eq!(h.bucket.len(), 3);
+    assert_eq!(
+        mf.metric[0].label,
+        vec![pb::LabelPair {
+            name: Some(String::from("chain")),
+            value: Some(String::from("P")),
+        }]
+    );
+
+    let mf = mfs
+        .iter()
+        .find(|mf| mf.name.as_deref() == Some("avalanche_network_peers"))
+        .unwrap();
+    assert_eq!(mf.r#type, Some(pb::MetricType::Gauge as i32));
+    assert_eq!(mf.metric[0].gauge, Some(pb::Gauge { value: Some(4.0) }));
+    assert!(mf.metric[0].counter.is_none());
+}
@@ -0,0 +1,1677 @@
+//! Mock avalanchego JSON-RPC server for hermetic wallet tests.
+//!
+//! Serves the subset of "info.*", "avm.*", "platform.*" and "eth_*" APIs
+//! that the wallet and the JSON-RPC client depend on, from an in-memory
+//! UTXO set and validator set. Issued txs are decoded and verified
+//! against the owners of the consumed UTXOs (signatures, thresholds,
+//! amounts and fees) before they update the state, so the txs accepted
+//! by this server are expected to be accepted by avalanchego as well.
+//!
+//! ```ignore
+//! let mut state = mock::State::default();
+//! state.fund(&state.blockchain_id_x.clone(), key.short_address()?, 1_000_000_000);
+//! let server = mock::Server::start(state).await?;
+//! let wallet = wallet::Builder::new(&key)
+//!     .base_http_url(server.http_rpc())
+//!     .build()
+//!     .await?;
+//! ```
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    convert::Infallible,
+    net::SocketAddr,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::SystemTime,
+};
+
+use crate::{
+    avm, choices, constants,
+    errors::{Error, Result},
+    ids::{self, short},
+    jsonrpc, key, platformvm, txs,
+};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, StatusCode,
+};
+use primitive_types::{H160, U256};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio::sync::oneshot;
+
+/// Maximum number of UTXOs returned by a single "getUTXOs" call.
+/// ref. "avalanchego/vms/avm.maxPageSize"
+pub const MAX_UTXOS_PAGE_SIZE: u32 = 1024;
+
+/// Maximum number of addresses in a single "getUTXOs" call.
+/// ref. "avalanchego/vms/avm.maxGetUTXOsAddrs"
+pub const MAX_GET_UTXOS_ADDRESSES: usize = 1024;
+
+/// ref. <https://www.jsonrpc.org/specification#error_object>
+pub const ERROR_CODE_PARSE: i32 = -32700;
+pub const ERROR_CODE_METHOD_NOT_FOUND: i32 = -32601;
+pub const ERROR_CODE_INVALID_PARAMS: i32 = -32602;
+/// Server error for the txs that fail verification.
+pub const ERROR_CODE_SERVER: i32 = -32000;
+
+/// In-memory chain state served by the mock server.
+#[derive(Debug, Clone)]
+pub struct State {
+    pub network_id: u32,
+    pub network_name: String,
+
+    pub blockchain_id_x: ids::Id,
+    pub blockchain_id_p: ids::Id,
+    pub blockchain_id_c: ids::Id,
+    pub avax_asset_id: ids::Id,
+
+    /// Static fees returned by "info.getTxFee" and burned by the issued txs.
+    pub fees: jsonrpc::info::GetTxFeeResult,
+
+    /// EVM chain Id of the C-chain.
+    pub evm_chain_id: u64,
+    /// Returned by "eth_baseFee" and "eth_gasPrice".
+    pub evm_base_fee: U256,
+    pub evm_balances: HashMap<H160, U256>,
+    pub evm_nonces: HashMap<H160, u64>,
+
+    /// UTXOs of each chain, keyed by the blockchain Id.
+    pub utxos: HashMap<ids::Id, BTreeMap<txs::utxo::Id, txs::utxo::Utxo>>,
+    /// Atomic UTXOs in the shared memory, keyed by the destination
+    /// and source blockchain Ids in that order.
+    pub atomic_utxos: HashMap<(ids::Id, ids::Id), BTreeMap<txs::utxo::Id, txs::utxo::Utxo>>,
+    /// Number of UTXOs minted by "fund", to derive unique tx Ids.
+    pub funded: u64,
+
+    /// Current primary network validators.
+    pub validators: Vec<jsonrpc::platformvm::ApiPrimaryValidator>,
+
+    /// Statuses of the X-chain txs, "Rejected" if failed verification.
+    pub x_tx_statuses: HashMap<ids::Id, choices::status::Status>,
+    /// Statuses of the P-chain txs, "Dropped" if failed verification.
+    pub p_tx_statuses: HashMap<ids::Id, platformvm::txs::status::Status>,
+    /// Incremented for every committed P-chain tx.
+    pub p_height: u64,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl State {
+    /// Creates the state of a custom network with the local network fees.
+    pub fn default() -> Self {
+        let mut fees = jsonrpc::info::GetTxFeeResult::default();
+        fees.tx_fee = 1_000_000;
+        fees.create_asset_tx_fee = 10_000_000;
+        fees.create_subnet_tx_fee = 100_000_000;
+        fees.transform_subnet_tx_fee = 1_000_000_000;
+        fees.create_blockchain_tx_fee = 100_000_000;
+        fees.add_primary_network_validator_fee = 0;
+        fees.add_primary_network_delegator_fee = 0;
+        fees.add_subnet_validator_fee = 1_000_000;
+        fees.add_subnet_delegator_fee = 1_000_000;
+
+        Self {
+            network_id: constants::DEFAULT_CUSTOM_NETWORK_ID,
+            network_name: format!("network-{}", constants::DEFAULT_CUSTOM_NETWORK_ID),
+
+            blockchain_id_x: ids::Id::sha256("X"),
+            // same as avalanchego, the P-chain Id is the empty Id
+            blockchain_id_p: ids::Id::empty(),
+            blockchain_id_c: ids::Id::sha256("C"),
+            avax_asset_id: ids::Id::sha256("AVAX"),
+
+            fees,
+
+            evm_chain_id: 43112,
+            evm_base_fee: U256::from(25_000_000_000u64),
+            evm_balances: HashMap::new(),
+            evm_nonces: HashMap::new(),
+
+            utxos: HashMap::new(),
+            atomic_utxos: HashMap::new(),
+            funded: 0,
+
+            validators: Vec::new(),
+
+            x_tx_statuses: HashMap::new(),
+            p_tx_statuses: HashMap::new(),
+            p_height: 0,
+        }
+    }
+
+    /// Resolves the chain alias (e.g., "X") or the blockchain Id.
+    pub fn resolve_chain(&self, alias: &str) -> Option<ids::Id> {
+        match alias {
+            "X" => Some(self.blockchain_id_x),
+            "P" => Some(self.blockchain_id_p),
+            "C" => Some(self.blockchain_id_c),
+            _ => {
+                let id = ids::Id::from_str(alias).ok()?;
+                if id == self.blockchain_id_x
+                    || id == self.blockchain_id_p
+                    || id == self.blockchain_id_c
+                {
+                    Some(id)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Adds the UTXO to the chain.
+    pub fn add_utxo(&mut self, blockchain_id: &ids::Id, utxo: txs::utxo::Utxo) {
+        self.utxos
+            .entry(*blockchain_id)
+            .or_default()
+            .insert(utxo.utxo_id.clone(), utxo);
+    }
+
+    /// Mints an AVAX UTXO of the "amount" to the single owner on the chain,
+    /// and returns its UTXO Id.
+    pub fn fund(
+        &mut self,
+        blockchain_id: &ids::Id,
+        owner: short::Id,
+        amount: u64,
+    ) -> txs::utxo::Id {
+        let tx_id = ids::Id::sha256(format!("fund-{}", self.funded));
+        self.funded += 1;
+
+        let utxo_id = txs::utxo::Id::new(tx_id.as_ref(), 0, false).unwrap();
+        self.add_utxo(
+            blockchain_id,
+            txs::utxo::Utxo {
+                utxo_id: utxo_id.clone(),
+                asset_id: self.avax_asset_id,
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount,
+                    output_owners: key::secp256k1::txs::OutputOwners {
+                        locktime: 0,
+                        threshold: 1,
+                        addresses: vec![owner],
+                    },
+                }),
+                ..txs::utxo::Utxo::default()
+            },
+        );
+        utxo_id
+    }
+
+    /// Returns the total amount of the asset owned by the address on the chain,
+    /// including the locked outputs.
+    pub fn balance(&self, blockchain_id: &ids::Id, owner: &short::Id, asset_id: &ids::Id) -> u64 {
+        self.utxos
+            .get(blockchain_id)
+            .map(|utxos| {
+                utxos
+                    .values()
+                    .filter(|utxo| utxo.asset_id == *asset_id)
+                    .filter_map(|utxo| utxo_output(utxo).map(|(out, _)| out))
+                    .filter(|out| out.output_owners.addresses.contains(owner))
+                    .map(|out| out.amount)
+                    .sum()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn set_evm_balance(&mut self, eth_addr: H160, balance: U256) {
+        self.evm_balances.insert(eth_addr, balance);
+    }
+
+    pub fn add_validator(&mut self, validator: jsonrpc::platformvm::ApiPrimaryValidator) {
+        self.validators.push(validator);
+    }
+
+    /// Handles the JSON-RPC call on the API path, and returns the result
+    /// or the error object of the response.
+    pub fn handle(
+        &mut self,
+        path: &str,
+        method: &str,
+        params: &Value,
+    ) -> std::result::Result<Value, jsonrpc::ResponseError> {
+        match path {
+            "/ext/info" => self.handle_info(method, params),
+            "/ext/bc/X" => self.handle_avm(method, params),
+            "/ext/P" | "/ext/bc/P" => self.handle_platform(method, params),
+            "/ext/bc/C/rpc" => self.handle_eth(method, params),
+            _ => Err(method_not_found(method)),
+        }
+    }
+
+    fn handle_info(
+        &mut self,
+        method: &str,
+        params: &Value,
+    ) -> std::result::Result<Value, jsonrpc::ResponseError> {
+        match method {
+            "info.getNetworkID" => to_value(jsonrpc::info::GetNetworkIdResult {
+                network_id: self.network_id,
+            }),
+            "info.getNetworkName" => to_value(jsonrpc::info::GetNetworkNameResult {
+                network_name: self.network_name.clone(),
+            }),
+            "info.getBlockchainID" => {
+                let alias = param_str(params, "alias")?;
+                let blockchain_id = self.resolve_chain(alias).ok_or_else(|| {
+                    server_error(format!("there is no chain with alias/ID '{alias}'"))
+                })?;
+                to_value(jsonrpc::info::GetBlockchainIdResult { blockchain_id })
+            }
+            "info.getTxFee" => to_value(&self.fees),
+            "info.isBootstrapped" => to_value(jsonrpc::info::IsBootstrappedResult {
+                is_bootstrapped: true,
+            }),
+            _ => Err(method_not_found(method)),
+        }
+    }
+
+    fn handle_avm(
+        &mut self,
+        method: &str,
+        params: &Value,
+    ) -> std::result::Result<Value, jsonrpc::ResponseError> {
+        match method {
+            "avm.getAssetDescription" => {
+                let asset_id = param_str(params, "assetID")?;
+                if asset_id != "AVAX" && asset_id != self.avax_asset_id.to_string() {
+                    return Err(server_error(format!("asset '{asset_id}' not found")));
+                }
+                to_value(jsonrpc::avm::GetAssetDescriptionResult {
+                    asset_id: self.avax_asset_id,
+                    name: String::from("Avalanche"),
+                    symbol: String::from("AVAX"),
+                    denomination: 9,
+                })
+            }
+            "avm.getUTXOs" => {
+                let params: jsonrpc::avm::GetUtxosParams = parse_params(params)?;
+                let (utxos, end_index) = self.utxos_page(
+                    "X",
+                    &self.blockchain_id_x,
+                    &params.addresses,
+                    params.source_chain.as_deref(),
+                    params.limit,
+                    params.start_index.as_ref(),
+                )?;
+                to_value(jsonrpc::avm::GetUtxosResult {
+                    num_fetched: utxos.len() as u32,
+                    utxos: Some(utxos),
+                    end_index: Some(end_index),
+                    encoding: Some(String::from("hex")),
+                })
+            }
+            "avm.getBalance" => {
+                let owner = parse_address("X", param_str(params, "address")?)?;
+                let asset_id = param_str(params, "assetID")?;
+                let asset_id = if asset_id == "AVAX" {
+                    self.avax_asset_id
+                } else {
+                    ids::Id::from_str(asset_id).map_err(|e| invalid_params(e.to_string()))?
+                };
+                let utxo_ids =
+                    self.owned_utxos(&self.blockchain_id_x, std::slice::from_ref(&owner));
+                to_value(jsonrpc::avm::GetBalanceResult {
+                    balance: self.balance(&self.blockchain_id_x, &owner, &asset_id),
+                    utxo_ids: Some(
+                        utxo_ids
+                            .into_iter()
+                            .filter(|utxo| utxo.asset_id == asset_id)
+                            .map(|utxo| utxo.utxo_id)
+                            .collect(),
+                    ),
+                })
+            }
+            "avm.issueTx" => {
+                let params: jsonrpc::avm::IssueTxParams = parse_params(params)?;
+                let tx = avm::txs::SignedTx::from_hex(&params.tx)
+                    .map_err(|e| invalid_params(e.message()))?;
+                let tx_id = tx.tx_id();
+                match self.issue_x(&tx) {
+                    Ok(()) => {
+                        log::info!("accepted X-chain tx {tx_id}");
+                        self.x_tx_statuses
+                            .insert(tx_id, choices::status::Status::Accepted);
+                        to_value(jsonrpc::avm::IssueTxResult { tx_id })
+                    }
+                    Err(e) => {
+                        log::warn!("rejected X-chain tx {tx_id} '{}'", e.message());
+                        self.x_tx_statuses
+                            .entry(tx_id)
+                            .or_insert(choices::status::Status::Rejected);
+                        Err(server_error(e.message()))
+                    }
+                }
+            }
+            "avm.getTxStatus" => {
+                let tx_id = param_id(params, "txID")?;
+                let status =
+                    self.x_tx_statuses.get(&tx_id).cloned().unwrap_or_else(|| {
+                        choices::status::Status::Unknown(String::from("Unknown"))
+                    });
+                to_value(jsonrpc::avm::GetTxStatusResult { status })
+            }
+            _ => Err(method_not_found(method)),
+        }
+    }
+
+    fn handle_platform(
+        &mut self,
+        method: &str,
+        params: &Value,
+    ) -> std::result::Result<Value, jsonrpc::ResponseError> {
+        match method {
+            "platform.getUTXOs" => {
+                let params: jsonrpc::platformvm::GetUtxosParams = parse_params(params)?;
+                let (utxos, end_index) = self.utxos_page(
+                    "P",
+                    &self.blockchain_id_p,
+                    &params.addresses,
+                    params.source_chain.as_deref(),
+                    params.limit,
+                    params.start_index.as_ref(),
+                )?;
+                to_value(jsonrpc::platformvm::GetUtxosResult {
+                    num_fetched: utxos.len() as u32,
+                    utxos: Some(utxos),
+                    end_index: Some(end_index),
+                    encoding: Some(String::from("hex")),
+                })
+            }
+            "platform.getBalance" => {
+                let addresses: Vec<String> = match params_object(params).get("addresses") {
+                    Some(v) => serde_json::from_value(v.clone())
+                        .map_err(|e| invalid_params(e.to_string()))?,
+                    None => vec![param_str(params, "address")?.to_string()],
+                };
+                let owners = addresses
+                    .iter()
+                    .map(|addr| parse_address("P", addr))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+
+                let now = now_unix();
+                let (mut unlocked, mut locked_stakeable) = (0u64, 0u64);
+                let mut utxo_ids = Vec::new();
+                for utxo in self.owned_utxos(&self.blockchain_id_p, &owners) {
+                    if utxo.asset_id != self.avax_asset_id {
+                        continue;
+                    }
+                    let (out, locktime) = utxo_output(&utxo).unwrap();
+                    if locktime > now {
+                        locked_stakeable += out.amount;
+                    } else {
+                        unlocked += out.amount;
+                    }
+                    utxo_ids.push(utxo.utxo_id);
+                }
+
+                let mut result = jsonrpc::platformvm::GetBalanceResult::default();
+                result.balance = unlocked + locked_stakeable;
+                result.unlocked = unlocked;
+                result.locked_stakeable = Some(locked_stakeable);
+                result.locked_not_stakeable = Some(0);
+                result.utxo_ids = Some(utxo_ids);
+                to_value(result)
+            }
+            "platform.issueTx" => {
+                let params: jsonrpc::platformvm::IssueTxParams = parse_params(params)?;
+                let tx = platformvm::txs::SignedTx::from_hex(&params.tx)
+                    .map_err(|e| invalid_params(e.message()))?;
+                let tx_id = tx.tx_id();
+                match self.issue_p(&tx) {
+                    Ok(()) => {
+                        log::info!("committed P-chain tx {tx_id}");
+                        self.p_tx_statuses
+                            .insert(tx_id, platformvm::txs::status::Status::Committed);
+                        self.p_height += 1;
+                        to_value(jsonrpc::platformvm::IssueTxResult { tx_id })
+                    }
+                    Err(e) => {
+                        log::warn!("dropped P-chain tx {tx_id} '{}'", e.message());
+                        self.p_tx_statuses
+                            .entry(tx_id)
+                            .or_insert(platformvm::txs::status::Status::Dropped);
+                        Err(server_error(e.message()))
+                    }
+                }
+            }
+            "platform.getTxStatus" => {
+                let tx_id = param_id(params, "txID")?;
+                let status = self.p_tx_statuses.get(&tx_id).cloned().unwrap_or_else(|| {
+                    platformvm::txs::status::Status::Unknown(String::from("Unknown"))
+                });
+                to_value(jsonrpc::platformvm::GetTxStatusResult { status })
+            }
+            "platform.getHeight" => to_value(jsonrpc::platformvm::GetHeightResult {
+                height: self.p_height,
+            }),
+            "platform.getCurrentValidators" => {
+                // only the primary network validators are tracked
+                let primary_network = match params_object(params).get("subnetID") {
+                    Some(Value::String(subnet_id)) => {
+                        ids::Id::from_str(subnet_id).map_err(|e| invalid_params(e.to_string()))?
+                            == ids::Id::empty()
+                    }
+                    _ => true,
+                };
+                let validators = if primary_network {
+                    self.validators.clone()
+                } else {
+                    Vec::new()
+                };
+                to_value(jsonrpc::platformvm::GetCurrentValidatorsResult {
+                    validators: Some(validators),
+                })
+            }
+            _ => Err(method_not_found(method)),
+        }
+    }
+
+    fn handle_eth(
+        &mut self,
+        method: &str,
+        params: &Value,
+    ) -> std::result::Result<Value, jsonrpc::ResponseError> {
+        match method {
+            "eth_chainId" => Ok(json!(format!("{:#x}", self.evm_chain_id))),
+            "net_version" => Ok(json!(self.evm_chain_id.to_string())),
+            "eth_blockNumber" => Ok(json!("0x0")),
+            "eth_baseFee" | "eth_gasPrice" => Ok(json!(self.evm_base_fee)),
+            "eth_getBalance" => {
+                let eth_addr = param_h160(params)?;
+                let balance = self
+                    .evm_balances
+                    .get(&eth_addr)
+                    .cloned()
+                    .unwrap_or_default();
+                Ok(json!(balance))
+            }
+            "eth_getTransactionCount" => {
+                let eth_addr = param_h160(params)?;
+                let nonce = self.evm_nonces.get(&eth_addr).cloned().unwrap_or_default();
+                Ok(json!(U256::from(nonce)))
+            }
+            _ => Err(method_not_found(method)),
+        }
+    }
+
+    /// Returns the page of UTXOs owned by any of the addresses, after the "start_index".
+    fn utxos_page(
+        &self,
+        chain_alias: &str,
+        blockchain_id: &ids::Id,
+        addresses: &[String],
+        source_chain: Option<&str>,
+        limit: u32,
+        start_index: Option<&jsonrpc::EndIndex>,
+    ) -> std::result::Result<(Vec<txs::utxo::Utxo>, jsonrpc::EndIndex), jsonrpc::ResponseError>
+    {
+        if addresses.len() > MAX_GET_UTXOS_ADDRESSES {
+            return Err(invalid_params(format!(
+                "number of addresses given, {}, exceeds maximum, {}",
+                addresses.len(),
+                MAX_GET_UTXOS_ADDRESSES
+            )));
+        }
+        let owners = addresses
+            .iter()
+            .map(|addr| parse_address(chain_alias, addr))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let utxos = match source_chain {
+            Some(source_chain) => {
+                let source_blockchain_id = self.resolve_chain(source_chain).ok_or_else(|| {
+                    invalid_params(format!("unknown source chain '{source_chain}'"))
+                })?;
+                self.atomic_utxos
+                    .get(&(*blockchain_id, source_blockchain_id))
+            }
+            None => self.utxos.get(blockchain_id),
+        };
+
+        let limit = if limit == 0 || limit > MAX_UTXOS_PAGE_SIZE {
+            MAX_UTXOS_PAGE_SIZE
+        } else {
+            limit
+        };
+
+        let mut page = Vec::new();
+        let mut started = start_index.is_none();
+        for utxo in utxos.into_iter().flat_map(|utxos| utxos.values()) {
+            if !started {
+                started = utxo_key(&utxo.utxo_id) == start_index.unwrap().utxo;
+                continue;
+            }
+            let owned = utxo_output(utxo)
+                .map(|(out, _)| {
+                    out.output_owners
+                        .addresses
+                        .iter()
+                        .any(|addr| owners.contains(addr))
+                })
+                .unwrap_or_default();
+            if !owned {
+                continue;
+            }
+            page.push(utxo.clone());
+            if page.len() as u32 >= limit {
+                break;
+            }
+        }
+
+        let end_index = jsonrpc::EndIndex {
+            address: addresses.first().cloned().unwrap_or_default(),
+            utxo: match page.last() {
+                Some(utxo) => utxo_key(&utxo.utxo_id),
+                None => start_index.map(|idx| idx.utxo.clone()).unwrap_or_default(),
+            },
+        };
+        Ok((page, end_index))
+    }
+
+    /// Returns all UTXOs on the chain owned by any of the addresses.
+    fn owned_utxos(&self, blockchain_id: &ids::Id, owners: &[short::Id]) -> Vec<txs::utxo::Utxo> {
+        self.utxos
+            .get(blockchain_id)
+            .map(|utxos| {
+                utxos
+                    .values()
+                    .filter(|utxo| {
+                        utxo_output(utxo)
+                            .map(|(out, _)| {
+                                out.output_owners
+                                    .addresses
+                                    .iter()
+                                    .any(|addr| owners.contains(addr))
+                            })
+                            .unwrap_or_default()
+                    })
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Verifies the X-chain tx and applies it to the state.
+    fn issue_x(&mut self, tx: &avm::txs::SignedTx) -> Result<()> {
+        if self.x_tx_statuses.get(&tx.tx_id()) == Some(&choices::status::Status::Accepted) {
+            return Err(Error::Other {
+                message: format!("tx {} is already accepted", tx.tx_id()),
+                retryable: false,
+            });
+        }
+
+        let creds: Vec<&key::secp256k1::txs::Credential> =
+            tx.fx_creds().iter().map(|c| &c.cred).collect();
+        let spend = match tx {
+            avm::txs::SignedTx::Base(tx) => Spend::new(&tx.base_tx, creds, self.fees.tx_fee),
+            avm::txs::SignedTx::Import(tx) => Spend {
+                imported: Some((
+                    tx.source_chain_id,
+                    tx.source_chain_transferable_inputs
+                        .as_deref()
+                        .unwrap_or_default(),
+                )),
+                ..Spend::new(&tx.base_tx, creds, self.fees.tx_fee)
+            },
+            avm::txs::SignedTx::Export(tx) => Spend {
+                exported: Some((
+                    tx.destination_chain_id,
+                    tx.destination_chain_transferable_outputs
+                        .as_deref()
+                        .unwrap_or_default(),
+                )),
+                ..Spend::new(&tx.base_tx, creds, self.fees.tx_fee)
+            },
+            avm::txs::SignedTx::CreateAsset(tx) => {
+                let mut minted = Vec::new();
+                for state in tx.states.iter() {
+                    for out in state.outputs.iter() {
+                        match out {
+                            avm::txs::fx::Output::Secp256k1fxTransfer(out) => {
+                                minted.push(out.clone())
+                            }
+                            _ => {
+                                return Err(Error::Other {
+                                    message: format!(
+                                        "initial output type {} is not supported by the mock",
+                                        out.type_id()
+                                    ),
+                                    retryable: false,
+                                })
+                            }
+                        }
+                    }
+                }
+                Spend {
+                    minted,
+                    ..Spend::new(&tx.base_tx, creds, self.fees.create_asset_tx_fee)
+                }
+            }
+            avm::txs::SignedTx::Operation(_) => {
+                return Err(Error::Other {
+                    message: String::from("OperationTx is not supported by the mock"),
+                    retryable: false,
+                })
+            }
+        };
+        self.spend(&self.blockchain_id_x.clone(), &tx.tx_id(), spend)
+    }
+
+    /// Verifies the P-chain tx and applies it to the state.
+    fn issue_p(&mut self, tx: &platformvm::txs::SignedTx) -> Result<()> {
+        let tx_id = tx.tx_id();
+        if self.p_tx_statuses.get(&tx_id) == Some(&platformvm::txs::status::Status::Committed) {
+            return Err(Error::Other {
+                message: format!("tx {tx_id} is already committed"),
+                retryable: false,
+            });
+        }
+
+        let creds: Vec<&key::secp256k1::txs::Credential> = tx.creds().iter().collect();
+        let fees = self.fees.clone();
+        match tx {
+            platformvm::txs::SignedTx::Import(tx) => {
+                let spend = Spend {
+                    imported: Some((
+                        tx.source_chain_id,
+                        tx.source_chain_transferable_inputs.as_deref().unwrap_or_default(),
+                    )),
+                    ..Spend::new(&tx.base_tx, creds, fees.tx_fee)
+                };
+                self.spend(&self.blockchain_id_p.clone(), &tx_id, spend)
+            }
+            platformvm::txs::SignedTx::Export(tx) => {
+                let spend = Spend {
+                    exported: Some((
+                        tx.destination_chain_id,
+                        tx.destination_chain_transferable_outputs
+                            .as_deref()
+                            .unwrap_or_default(),
+                    )),
+                    ..Spend::new(&tx.base_tx, creds, fees.tx_fee)
+                };
+                self.spend(&self.blockchain_id_p.clone(), &tx_id, spend)
+            }
+            platformvm::txs::SignedTx::CreateSubnet(tx) => {
+                let spend = Spend::new(&tx.base_tx, creds, fees.create_subnet_tx_fee);
+                self.spend(&self.blockchain_id_p.clone(), &tx_id, spend)
+            }
+            platformvm::txs::SignedTx::AddValidator(tx) => {
+                self.check_new_validator(&tx.validator)?;
+                let spend = Spend {
+                    staked: tx.stake_transferable_outputs.as_deref().unwrap_or_default(),
+                    weight: tx.validator.weight,
+                    ..Spend::new(&tx.base_tx, creds, fees.add_primary_network_validator_fee)
+                };
+                self.spend(&self.blockchain_id_p.clone(), &tx_id, spend)?;
+                self.add_staker(&tx_id, &tx.validator, Some(tx.shares), None);
+                Ok(())
+            }
+            platformvm::txs::SignedTx::AddPermissionlessValidator(tx)
+                if tx.subnet_id == ids::Id::empty() =>
+            {
+                self.check_new_validator(&tx.validator)?;
+                let spend = Spend {
+                    staked: tx.stake_transferable_outputs.as_deref().unwrap_or_default(),
+                    weight: tx.validator.weight,
+                    ..Spend::new(&tx.base_tx, creds, fees.add_primary_network_validator_fee)
+                };
+                self.spend(&self.blockchain_id_p.clone(), &tx_id, spend)?;
+                self.add_staker(
+                    &tx_id,
+                    &tx.validator,
+                    Some(tx.delegation_shares),
+                    tx.signer.clone(),
+                );
+                Ok(())
+            }
+            platformvm::txs::SignedTx::AddDelegator(tx) => {
+                self.check_delegatee(&tx.validator)?;
+                let spend = Spend {
+                    staked: tx.stake_transferable_outputs.as_deref().unwrap_or_default(),
+                    weight: tx.validator.weight,
+                    ..Spend::new(&tx.base_tx, creds, fees.add_primary_network_delegator_fee)
+                };
+                self.spend(&self.blockchain_id_p.clone(), &tx_id, spend)?;
+                self.add_staker(&tx_id, &tx.validator, None, None);
+                Ok(())
+            }
+            platformvm::txs::SignedTx::AddPermissionlessDelegator(tx)
+                if tx.subnet_id == ids::Id::empty() =>
+            {
+                self.check_delegatee(&tx.validator)?;
+                let spend = Spend {
+                    staked: tx.stake_transferable_outputs.as_deref().unwrap_or_default(),
+                    weight: tx.validator.weight,
+                    ..Spend::new(&tx.base_tx, creds, fees.add_primary_network_delegator_fee)
+                };
+                self.spend(&self.blockchain_id_p.clone(), &tx_id, spend)?;
+                self.add_staker(&tx_id, &tx.validator, None, None);
+                Ok(())
+            }
+            _ => Err(Error::Other {
+                message: String::from(
+                    "only the import/export, create subnet and primary network staking txs are supported by the mock",
+                ),
+                retryable: false,
+            }),
+        }
+    }
+
+    fn check_new_validator(&self, validator: &platformvm::txs::Validator) -> Result<()> {
+        if self
+            .validators
+            .iter()
+            .any(|v| v.node_id == validator.node_id)
+        {
+            return Err(Error::Other {
+                message: format!(
+                    "{} is already a primary network validator",
+                    validator.node_id
+                ),
+                retryable: false,
+            });
+        }
+        if validator.start >= validator.end {
+            return Err(Error::Other {
+                message: format!(
+                    "staking period must end after it starts ({} >= {})",
+                    validator.start, validator.end
+                ),
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_delegatee(&self, validator: &platformvm::txs::Validator) -> Result<()> {
+        let delegatee = self
+            .validators
+            .iter()
+            .find(|v| v.node_id == validator.node_id)
+            .ok_or_else(|| Error::Other {
+                message: format!("{} is not a primary network validator", validator.node_id),
+                retryable: false,
+            })?;
+        if validator.start < delegatee.start_time || validator.end > delegatee.end_time {
+            return Err(Error::Other {
+                message: format!(
+                    "staking period must be a subset of the validator {}",
+                    validator.node_id
+                ),
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds the validator, or the delegator if "shares" is "None".
+    fn add_staker(
+        &mut self,
+        tx_id: &ids::Id,
+        validator: &platformvm::txs::Validator,
+        shares: Option<u32>,
+        signer: Option<key::bls::ProofOfPossession>,
+    ) {
+        if let Some(shares) = shares {
+            let mut v = jsonrpc::platformvm::ApiPrimaryValidator::default();
+            v.tx_id = *tx_id;
+            v.start_time = validator.start;
+            v.end_time = validator.end;
+            v.weight = Some(validator.weight);
+            v.stake_amount = Some(validator.weight);
+            v.node_id = validator.node_id;
+            v.potential_reward = Some(0);
+            v.delegation_fee = Some(shares as f32 / 10000.0);
+            v.uptime = Some(1.0);
+            v.connected = true;
+            v.signer = signer;
+            v.delegator_count = Some(0);
+            v.delegator_weight = Some(0);
+            self.validators.push(v);
+            return;
+        }
+
+        let v = self
+            .validators
+            .iter_mut()
+            .find(|v| v.node_id == validator.node_id)
+            .unwrap();
+        let mut d = jsonrpc::platformvm::ApiPrimaryDelegator::default();
+        d.tx_id = *tx_id;
+        d.start_time = validator.start;
+        d.end_time = validator.end;
+        d.stake_amount = validator.weight;
+        d.node_id = validator.node_id;
+        d.potential_reward = Some(0);
+        v.delegators.get_or_insert_with(Vec::new).push(d);
+        v.delegator_count = Some(v.delegator_count.unwrap_or_default() + 1);
+        v.delegator_weight = Some(v.delegator_weight.unwrap_or_default() + validator.weight);
+    }
+
+    /// Verifies the credentials and the flow of funds of the tx,
+    /// then consumes its inputs and produces its outputs.
+    /// Nothing is updated if the verification fails.
+    /// ref. "avalanchego/vms/components/avax.FlowChecker"
+    /// ref. "avalanchego/vms/secp256k1fx.Fx.VerifyTransfer"
+    fn spend(&mut self, blockchain_id: &ids::Id, tx_id: &ids::Id, spend: Spend) -> Result<()> {
+        let base_tx = spend.base_tx;
+        if base_tx.network_id != self.network_id {
+            return Err(Error::Other {
+                message: format!(
+                    "tx has wrong network ID {} (expected {})",
+                    base_tx.network_id, self.network_id
+                ),
+                retryable: false,
+            });
+        }
+        if base_tx.blockchain_id != *blockchain_id {
+            return Err(Error::Other {
+                message: format!(
+                    "tx has wrong chain ID {} (expected {})",
+                    base_tx.blockchain_id, blockchain_id
+                ),
+                retryable: false,
+            });
+        }
+        let tx_bytes_hash = match &base_tx.metadata {
+            Some(metadata) => crate::hash::sha256(&metadata.tx_bytes_with_no_signature),
+            None => {
+                return Err(Error::Other {
+                    message: String::from("tx is not signed"),
+                    retryable: false,
+                })
+            }
+        };
+
+        // the credentials are in the same order as the inputs,
+        // followed by the imported inputs
+        let ins = base_tx.transferable_inputs.as_deref().unwrap_or_default();
+        let imported = spend.imported.map(|(_, ins)| ins).unwrap_or_default();
+        if spend.creds.len() != ins.len() + imported.len() {
+            return Err(Error::Other {
+                message: format!(
+                    "tx has {} credentials but {} inputs. Should be same",
+                    spend.creds.len(),
+                    ins.len() + imported.len()
+                ),
+                retryable: false,
+            });
+        }
+
+        let atomic_key = spend
+            .imported
+            .map(|(source_chain_id, _)| (*blockchain_id, source_chain_id));
+        let empty = BTreeMap::new();
+        let utxos = self.utxos.get(blockchain_id).unwrap_or(&empty);
+        let atomic_utxos = atomic_key
+            .and_then(|k| self.atomic_utxos.get(&k))
+            .unwrap_or(&empty);
+
+        let now = now_unix();
+        let mut consumed: HashMap<ids::Id, u64> = HashMap::new();
+        let mut spent = BTreeSet::new();
+        for (i, (input, atomic)) in ins
+            .iter()
+            .map(|input| (input, false))
+            .chain(imported.iter().map(|input| (input, true)))
+            .enumerate()
+        {
+            if !spent.insert(input.utxo_id.clone()) {
+                return Err(Error::Other {
+                    message: format!("input {i} double spends {}", utxo_key(&input.utxo_id)),
+                    retryable: false,
+                });
+            }
+            let utxos = if atomic { atomic_utxos } else { utxos };
+            let utxo = utxos.get(&input.utxo_id).ok_or_else(|| Error::Other {
+                message: format!(
+                    "failed to fetch UTXO {}:{} for input {i}",
+                    input.utxo_id.tx_id, input.utxo_id.output_index
+                ),
+                retryable: false,
+            })?;
+            let amount =
+                verify_input(utxo, input, spend.creds[i], &tx_bytes_hash, now).map_err(|e| {
+                    Error::Other {
+                        message: format!("input {i} failed verification '{}'", e.message()),
+                        retryable: false,
+                    }
+                })?;
+
+            let total = consumed.entry(input.asset_id).or_default();
+            *total = total.checked_add(amount).ok_or_else(|| Error::Other {
+                message: String::from("consumed amount overflows"),
+                retryable: false,
+            })?;
+        }
+
+        let mut staked = 0u64;
+        for out in spend.staked.iter() {
+            let (transfer_output, _) = transferable_output(out)?;
+            staked = staked
+                .checked_add(transfer_output.amount)
+                .ok_or_else(|| Error::Other {
+                    message: String::from("staked amount overflows"),
+                    retryable: false,
+                })?;
+        }
+        if staked != spend.weight {
+            return Err(Error::Other {
+                message: format!(
+                    "validator weight {} does not match the staked amount {staked}", // ref. "errValidatorWeightMismatch"
+                    spend.weight
+                ),
+                retryable: false,
+            });
+        }
+
+        let outs = base_tx.transferable_outputs.as_deref().unwrap_or_default();
+        let exported = spend.exported.map(|(_, outs)| outs).unwrap_or_default();
+        let mut produced: HashMap<ids::Id, u64> = HashMap::new();
+        produced.insert(self.avax_asset_id, spend.fee);
+        for out in outs
+            .iter()
+            .chain(exported.iter())
+            .chain(spend.staked.iter())
+        {
+            let (transfer_output, _) = transferable_output(out)?;
+            if transfer_output.amount == 0 {
+                return Err(Error::Other {
+                    message: String::from("output has no value"), // ref. "errNoValueOutput"
+                    retryable: false,
+                });
+            }
+            let total = produced.entry(out.asset_id).or_default();
+            *total = total
+                .checked_add(transfer_output.amount)
+                .ok_or_else(|| Error::Other {
+                    message: String::from("produced amount overflows"),
+                    retryable: false,
+                })?;
+        }
+        for (asset_id, produced) in produced.iter() {
+            let consumed = consumed.get(asset_id).cloned().unwrap_or_default();
+            if consumed < *produced {
+                return Err(Error::Other {
+                    message: format!(
+                        "insufficient funds: asset {asset_id} consumed {consumed} < produced {produced} (including fee {})",
+                        if *asset_id == self.avax_asset_id { spend.fee } else { 0 }
+                    ),
+                    retryable: false,
+                });
+            }
+        }
+
+        // all verified, now update the state
+        for input in ins.iter() {
+            self.utxos
+                .get_mut(blockchain_id)
+                .unwrap()
+                .remove(&input.utxo_id);
+        }
+        if let Some(k) = atomic_key {
+            for input in imported.iter() {
+                self.atomic_utxos
+                    .get_mut(&k)
+                    .unwrap()
+                    .remove(&input.utxo_id);
+            }
+        }
+
+        // outputs are indexed in the order of the base outputs,
+        // the exported outputs and the minted outputs
+        let mut output_index = 0u32;
+        for out in outs.iter() {
+            let utxo = new_utxo(tx_id, output_index, out.asset_id, out)?;
+            self.add_utxo(blockchain_id, utxo);
+            output_index += 1;
+        }
+        if let Some((destination_chain_id, outs)) = spend.exported {
+            for out in outs.iter() {
+                let utxo = new_utxo(tx_id, output_index, out.asset_id, out)?;
+                self.atomic_utxos
+                    .entry((destination_chain_id, *blockchain_id))
+                    .or_default()
+                    .insert(utxo.utxo_id.clone(), utxo);
+                output_index += 1;
+            }
+        }
+        for out in spend.minted.into_iter() {
+            let utxo = txs::utxo::Utxo {
+                utxo_id: txs::utxo::Id::new(tx_id.as_ref(), output_index, false)?,
+                // the newly created asset Id is the tx Id
+                asset_id: *tx_id,
+                transfer_output: Some(out),
+                ..txs::utxo::Utxo::default()
+            };
+            self.add_utxo(blockchain_id, utxo);
+            output_index += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// UTXOs consumed and produced by a tx, regardless of the tx type.
+struct Spend<'a> {
+    base_tx: &'a txs::Tx,
+    creds: Vec<&'a key::secp256k1::txs::Credential>,
+    /// Static fee to burn in AVAX.
+    fee: u64,
+
+    /// Atomic inputs imported from the source chain.
+    imported: Option<(ids::Id, &'a [txs::transferable::Input])>,
+    /// Atomic outputs exported to the destination chain.
+    exported: Option<(ids::Id, &'a [txs::transferable::Output])>,
+    /// Outputs locked for staking, which do not become UTXOs
+    /// since the mock never ends the staking period.
+    staked: &'a [txs::transferable::Output],
+    /// Validator or delegator weight that the staked outputs add up to.
+    weight: u64,
+    /// Initial outputs of the newly created asset.
+    minted: Vec<key::secp256k1::txs::transfer::Output>,
+}
+
+impl<'a> Spend<'a> {
+    fn new(
+        base_tx: &'a txs::Tx,
+        creds: Vec<&'a key::secp256k1::txs::Credential>,
+        fee: u64,
+    ) -> Self {
+        Self {
+            base_tx,
+            creds,
+            fee,
+            imported: None,
+            exported: None,
+            staked: &[],
+            weight: 0,
+            minted: Vec::new(),
+        }
+    }
+}
+
+/// Verifies the input spends the UTXO with the signatures of its owners,
+/// and returns the consumed amount.
+fn verify_input(
+    utxo: &txs::utxo::Utxo,
+    input: &txs::transferable::Input,
+    cred: &key::secp256k1::txs::Credential,
+    tx_bytes_hash: &[u8],
+    now: u64,
+) -> Result<u64> {
+    if utxo.asset_id != input.asset_id {
+        return Err(Error::Other {
+            message: format!(
+                "input asset {} does not match UTXO asset {}",
+                input.asset_id, utxo.asset_id
+            ),
+            retryable: false,
+        });
+    }
+    let (out, locktime) = utxo_output(utxo).ok_or_else(|| Error::Other {
+        message: String::from("UTXO has no transfer output"),
+        retryable: false,
+    })?;
+    let transfer_input = match (&input.transfer_input, &input.stakeable_lock_in) {
+        (Some(transfer_input), _) => {
+            if locktime > now {
+                return Err(Error::Other {
+                    message: format!("UTXO is stakeable locked until {locktime}"),
+                    retryable: false,
+                });
+            }
+            transfer_input
+        }
+        (None, Some(lock_in)) => {
+            if lock_in.locktime != locktime {
+                return Err(Error::Other {
+                    message: format!(
+                        "input locktime {} does not match UTXO locktime {locktime}",
+                        lock_in.locktime
+                    ),
+                    retryable: false,
+                });
+            }
+            &lock_in.transfer_input
+        }
+        (None, None) => {
+            return Err(Error::Other {
+                message: String::from("input has no transfer input"),
+                retryable: false,
+            })
+        }
+    };
+
+    if transfer_input.amount != out.amount {
+        return Err(Error::Other {
+            message: format!(
+                "input amount {} does not match UTXO amount {}",
+                transfer_input.amount, out.amount
+            ),
+            retryable: false,
+        });
+    }
+    let owners = &out.output_owners;
+    if owners.locktime > now {
+        return Err(Error::Other {
+            message: format!("UTXO is locked until {}", owners.locktime),
+            retryable: false,
+        });
+    }
+    if transfer_input.sig_indices.len() != owners.threshold as usize {
+        return Err(Error::Other {
+            message: format!(
+                "input has {} signers but the threshold is {}",
+                transfer_input.sig_indices.len(),
+                owners.threshold
+            ),
+            retryable: false,
+        });
+    }
+    if cred.signatures.len() != transfer_input.sig_indices.len() {
+        return Err(Error::Other {
+            message: format!(
+                "credential has {} signatures but the input has {} signers",
+                cred.signatures.len(),
+                transfer_input.sig_indices.len()
+            ),
+            retryable: false,
+        });
+    }
+
+    for (sig_index, sig) in transfer_input
+        .sig_indices
+        .iter()
+        .zip(cred.signatures.iter())
+    {
+        let owner = owners
+            .addresses
+            .get(*sig_index as usize)
+            .ok_or_else(|| Error::Other {
+                message: format!("signature index {sig_index} is out of bounds"),
+                retryable: false,
+            })?;
+        let signer =
+            key::secp256k1::public_key::Key::from_signature(tx_bytes_hash, sig)?.to_short_id()?;
+        if signer != *owner {
+            return Err(Error::Other {
+                message: format!("wrong signature: signed by {signer}, expected owner {owner}"),
+                retryable: false,
+            });
+        }
+    }
+
+    Ok(transfer_input.amount)
+}
+
+/// Returns the transfer output of the UTXO with its stakeable locktime.
+fn utxo_output(utxo: &txs::utxo::Utxo) -> Option<(&key::secp256k1::txs::transfer::Output, u64)> {
+    match (&utxo.transfer_output, &utxo.stakeable_lock_out) {
+        (Some(out), _) => Some((out, 0)),
+        (None, Some(lock_out)) => Some((&lock_out.transfer_output, lock_out.locktime)),
+        (None, None) => None,
+    }
+}
+
+/// Returns the transfer output of the transferable output with its stakeable locktime.
+fn transferable_output(
+    out: &txs::transferable::Output,
+) -> Result<(&key::secp256k1::txs::transfer::Output, u64)> {
+    match (&out.transfer_output, &out.stakeable_lock_out) {
+        (Some(out), _) => Ok((out, 0)),
+        (None, Some(lock_out)) => Ok((&lock_out.transfer_output, lock_out.locktime)),
+        (None, None) => Err(Error::Other {
+            message: String::from("output has no transfer output"),
+            retryable: false,
+        }),
+    }
+}
+
+fn new_utxo(
+    tx_id: &ids::Id,
+    output_index: u32,
+    asset_id: ids::Id,
+    out: &txs::transferable::Output,
+) -> Result<txs::utxo::Utxo> {
+    Ok(txs::utxo::Utxo {
+        utxo_id: txs::utxo::Id::new(tx_id.as_ref(), output_index, false)?,
+        asset_id,
+        transfer_output: out.transfer_output.clone(),
+        stakeable_lock_out: out.stakeable_lock_out.clone(),
+    })
+}
+
+/// Returns the UTXO Id string used for the "getUTXOs" pagination.
+fn utxo_key(utxo_id: &txs::utxo::Id) -> String {
+    utxo_id
+        .tx_id
+        .prefix(&[utxo_id.output_index as u64])
+        .map(|id| id.to_string())
+        .unwrap_or_default()
+}
+
+fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("unexpected None duration_since")
+        .as_secs()
+}
+
+/// Mock avalanchego server that serves the in-memory state
+/// on a random local port until dropped.
+#[derive(Debug)]
+pub struct Server {
+    pub addr: SocketAddr,
+    pub state: Arc<Mutex<State>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl Server {
+    /// Starts serving the state on "127.0.0.1" with a random port.
+    pub async fn start(state: State) -> Result<Self> {
+        let state = Arc::new(Mutex::new(state));
+
+        let shared = state.clone();
+        let make_svc = make_service_fn(move |_| {
+            let state = shared.clone();
+            async move { Ok::<_, Infallible>(service_fn(move |req| serve(state.clone(), req))) }
+        });
+
+        let server = hyper::Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0)))
+            .map_err(|e| Error::Other {
+                message: format!("failed hyper::Server::try_bind '{}'", e),
+                retryable: false,
+            })?
+            .serve(make_svc);
+        let addr = server.local_addr();
+
+        let (tx, rx) = oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let graceful = server.with_graceful_shutdown(async {
+                rx.await.ok();
+            });
+            if let Err(e) = graceful.await {
+                log::warn!("mock server failed '{}'", e);
+            }
+        });
+        log::info!("started mock server on {addr}");
+
+        Ok(Self {
+            addr,
+            state,
+            shutdown: Some(tx),
+        })
+    }
+
+    /// Returns the base HTTP URL (e.g., "http://127.0.0.1:[PORT]")
+    /// to pass to "wallet::Builder::base_http_url".
+    pub fn http_rpc(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+async fn serve(
+    state: Arc<Mutex<State>>,
+    req: Request<Body>,
+) -> std::result::Result<Response<Body>, Infallible> {
+    let path = req.uri().path().to_string();
+    if req.method() == Method::GET {
+        return Ok(match path.as_str() {
+            "/ext/health" | "/ext/health/readiness" | "/ext/health/liveness" => {
+                json_response(&json!({"checks": {}, "healthy": true}))
+            }
+            _ => not_found(),
+        });
+    }
+    if req.method() != Method::POST {
+        return Ok(not_found());
+    }
+
+    let body = match hyper::body::to_bytes(req.into_body()).await {
+        Ok(b) => b,
+        Err(e) => {
+            return Ok(json_response(&error_response(
+                Value::Null,
+                error(ERROR_CODE_PARSE, e.to_string()),
+            )))
+        }
+    };
+    let req: Value = match serde_json::from_slice(&body) {
+        Ok(v) => v,
+        Err(e) => {
+            return Ok(json_response(&error_response(
+                Value::Null,
+                error(ERROR_CODE_PARSE, e.to_string()),
+            )))
+        }
+    };
+
+    let id = req.get("id").cloned().unwrap_or(Value::Null);
+    let method = req
+        .get("method")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let params = req.get("params").cloned().unwrap_or(Value::Null);
+    log::debug!("mock server handling {method} on {path}");
+
+    let resp = state.lock().unwrap().handle(&path, method, &params);
+    Ok(match resp {
+        Ok(result) => json_response(&json!({
+            "jsonrpc": jsonrpc::DEFAULT_VERSION,
+            "id": id,
+            "result": result,
+        })),
+        Err(e) => json_response(&error_response(id, e)),
+    })
+}
+
+fn json_response(v: &Value) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header("content-type", "application/json")
+        .body(Body::from(v.to_string()))
+        .unwrap()
+}
+
+fn not_found() -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .body(Body::from("404 page not found\n"))
+        .unwrap()
+}
+
+fn error_response(id: Value, e: jsonrpc::ResponseError) -> Value {
+    json!({
+        "jsonrpc": jsonrpc::DEFAULT_VERSION,
+        "id": id,
+        "error": e,
+    })
+}
+
+fn error(code: i32, message: String) -> jsonrpc::ResponseError {
+    jsonrpc::ResponseError {
+        code,
+        message,
+        data: None,
+    }
+}
+
+fn method_not_found(method: &str) -> jsonrpc::ResponseError {
+    error(
+        ERROR_CODE_METHOD_NOT_FOUND,
+        format!("the method {method} does not exist/is not available"),
+    )
+}
+
+fn invalid_params(message: String) -> jsonrpc::ResponseError {
+    error(ERROR_CODE_INVALID_PARAMS, message)
+}
+
+fn server_error(message: String) -> jsonrpc::ResponseError {
+    error(ERROR_CODE_SERVER, message)
+}
+
+fn to_value<T: serde::Serialize>(v: T) -> std::result::Result<Value, jsonrpc::ResponseError> {
+    serde_json::to_value(v).map_err(|e| server_error(e.to_string()))
+}
+
+/// Returns the params object, which may be wrapped in an array (e.g., "eth_*" calls).
+fn params_object(params: &Value) -> &Value {
+    match params {
+        Value::Array(arr) => arr.first().unwrap_or(&Value::Null),
+        _ => params,
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(
+    params: &Value,
+) -> std::result::Result<T, jsonrpc::ResponseError> {
+    serde_json::from_value(params_object(params).clone()).map_err(|e| invalid_params(e.to_string()))
+}
+
+fn param_str<'a>(
+    params: &'a Value,
+    key: &str,
+) -> std::result::Result<&'a str, jsonrpc::ResponseError> {
+    params_object(params)
+        .get(key)
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid_params(format!("missing param '{key}'")))
+}
+
+fn param_id(params: &Value, key: &str) -> std::result::Result<ids::Id, jsonrpc::ResponseError> {
+    ids::Id::from_str(param_str(params, key)?).map_err(|e| invalid_params(e.to_string()))
+}
+
+/// Parses the first param of the "eth_*" calls as the account address.
+fn param_h160(params: &Value) -> std::result::Result<H160, jsonrpc::ResponseError> {
+    params
+        .get(0)
+        .and_then(Value::as_str)
+        .and_then(|addr| H160::from_str(addr.trim_start_matches("0x")).ok())
+        .ok_or_else(|| invalid_params(String::from("missing or invalid address param")))
+}
+
+/// Parses the "X-" or "P-" prefixed bech32 address to its short address.
+fn parse_address(
+    chain_alias: &str,
+    addr: &str,
+) -> std::result::Result<short::Id, jsonrpc::ResponseError> {
+    let (_, b) = key::secp256k1::address::avax_address_to_short_bytes(chain_alias, addr)
+        .map_err(|e| invalid_params(format!("invalid address '{addr}' ({e})")))?;
+    Ok(short::Id::from_slice(&b))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features jsonrpc_mock -- jsonrpc::mock::test_utxos_page --exact --show-output
+#[test]
+fn test_utxos_page() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+    let owner = k.to_public_key().to_short_id().unwrap();
+    let x_addr = k.to_public_key().to_hrp_address(1000000, "X").unwrap();
+
+    let mut state = State::default();
+    let blockchain_id_x = state.blockchain_id_x;
+    for _ in 0..5 {
+        state.fund(&blockchain_id_x, owner.clone(), 100);
+    }
+    state.fund(&blockchain_id_x, short::Id::empty(), 100);
+    assert_eq!(
+        state.balance(&blockchain_id_x, &owner, &state.avax_asset_id),
+        500
+    );
+
+    let mut fetched = Vec::new();
+    let mut start_index = None;
+    loop {
+        let (page, end_index) = state
+            .utxos_page(
+                "X",
+                &blockchain_id_x,
+                std::slice::from_ref(&x_addr),
+                None,
+                2,
+                start_index.as_ref(),
+            )
+            .unwrap();
+        let n = page.len();
+        fetched.extend(page);
+        if n < 2 {
+            break;
+        }
+        start_index = Some(end_index);
+    }
+    assert_eq!(fetched.len(), 5);
+    let unique: BTreeSet<txs::utxo::Id> = fetched.iter().map(|u| u.utxo_id.clone()).collect();
+    assert_eq!(unique.len(), 5);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features jsonrpc_mock -- jsonrpc::mock::test_get_all_utxos --exact --show-output
+#[tokio::test]
+async fn test_get_all_utxos() {
+    use futures::TryStreamExt;
+
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+    let owner = k.to_public_key().to_short_id().unwrap();
+    let x_addr = k.to_public_key().to_hrp_address(1000000, "X").unwrap();
+
+    let mut state = State::default();
+    let blockchain_id_x = state.blockchain_id_x;
+    for _ in 0..3 {
+        state.fund(&blockchain_id_x, owner.clone(), 100);
+    }
+    let server = Server::start(state).await.unwrap();
+    let cli = jsonrpc::client::Client::new().unwrap();
+
+    // the owner is in both chunks of the addresses
+    let mut addresses = vec![x_addr.clone()];
+    for i in 1..MAX_GET_UTXOS_ADDRESSES {
+        let mut b = [0_u8; short::LEN];
+        b[..8].copy_from_slice(&(i as u64).to_be_bytes());
+        addresses.push(crate::formatting::address("X", "custom", &b).unwrap());
+    }
+    addresses.push(x_addr);
+
+    let resp = cli
+        .x()
+        .get_utxos_page(&server.http_rpc(), &addresses, None, 0, None)
+        .await
+        .unwrap();
+    assert!(resp
+        .error
+        .unwrap()
+        .message
+        .contains("exceeds maximum, 1024"));
+
+    let utxos: Vec<txs::utxo::Utxo> = cli
+        .x()
+        .get_all_utxos(&server.http_rpc(), &addresses, None)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(utxos.len(), 3);
+    let unique: BTreeSet<txs::utxo::Id> = utxos.iter().map(|u| u.utxo_id.clone()).collect();
+    assert_eq!(unique.len(), 3);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features jsonrpc_mock -- jsonrpc::mock::test_issue_x --exact --show-output
+#[tokio::test]
+async fn test_issue_x() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+    let owner = k.to_public_key().to_short_id().unwrap();
+    let other = key::secp256k1::TEST_KEYS[1].clone();
+
+    let mut state = State::default();
+    let (network_id, blockchain_id_x, avax_asset_id) =
+        (state.network_id, state.blockchain_id_x, state.avax_asset_id);
+    let utxo_id = state.fund(&blockchain_id_x, owner.clone(), 10_000_000);
+
+    let new_tx = move |amount: u64| {
+        avm::txs::Tx::new(txs::Tx {
+            network_id,
+            blockchain_id: blockchain_id_x,
+            transferable_outputs: Some(vec![txs::transferable::Output {
+                asset_id: avax_asset_id,
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount,
+                    output_owners: key::secp256k1::txs::OutputOwners {
+                        locktime: 0,
+                        threshold: 1,
+                        addresses: vec![short::Id::empty()],
+                    },
+                }),
+                ..Default::default()
+            }]),
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: utxo_id.clone(),
+                asset_id: avax_asset_id,
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 10_000_000,
+                    sig_indices: vec![0],
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        })
+    };
+
+    // signed by the key that does not own the UTXO
+    let mut tx = new_tx(1_000_000);
+    tx.sign(vec![vec![other.clone()]]).await.unwrap();
+    let err = state.issue_x(&avm::txs::SignedTx::Base(tx)).unwrap_err();
+    assert!(
+        err.message().contains("wrong signature"),
+        "{}",
+        err.message()
+    );
+
+    // not enough to pay the fee
+    let mut tx = new_tx(9_500_000);
+    tx.sign(vec![vec![k.clone()]]).await.unwrap();
+    let err = state.issue_x(&avm::txs::SignedTx::Base(tx)).unwrap_err();
+    assert!(
+        err.message().contains("insufficient funds"),
+        "{}",
+        err.message()
+    );
+    assert_eq!(
+        state.balance(&blockchain_id_x, &owner, &state.avax_asset_id),
+        10_000_000
+    );
+
+    let mut tx = new_tx(1_000_000);
+    tx.sign(vec![vec![k.clone()]]).await.unwrap();
+    let tx_id = tx.tx_id();
+    state
+        .issue_x(&avm::txs::SignedTx::Base(tx.clone()))
+        .unwrap();
+    assert_eq!(
+        state.balance(&blockchain_id_x, &owner, &state.avax_asset_id),
+        0
+    );
+    assert_eq!(
+        state.balance(&blockchain_id_x, &short::Id::empty(), &state.avax_asset_id),
+        1_000_000
+    );
+    assert!(state.utxos[&blockchain_id_x]
+        .contains_key(&txs::utxo::Id::new(tx_id.as_ref(), 0, false).unwrap()));
+
+    // the consumed UTXO cannot be spent again
+    let err = state.issue_x(&avm::txs::SignedTx::Base(tx)).unwrap_err();
+    assert!(
+        err.message().contains("failed to fetch UTXO"),
+        "{}",
+        err.message()
+    );
+}
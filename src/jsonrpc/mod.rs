@@ -3,13 +3,19 @@ pub mod avm;
 pub mod common;
 pub mod evm;
 pub mod health;
+pub mod index;
 pub mod info;
+pub mod metrics;
 pub mod platformvm;
 
 #[cfg(feature = "jsonrpc_client")]
 #[cfg_attr(docsrs, doc(cfg(feature = "jsonrpc_client")))]
 pub mod client;
 
+#[cfg(feature = "jsonrpc_mock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonrpc_mock")))]
+pub mod mock;
+
 use std::{
     collections::HashMap,
     io::{self, Error, ErrorKind},
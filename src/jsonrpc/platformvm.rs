@@ -10,6 +10,7 @@ use crate::{
     key::bls,
     platformvm, txs,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -438,7 +439,14 @@ impl GetUtxosRequest {
 #[serde(rename_all = "camelCase")]
 pub struct GetUtxosParams {
     pub addresses: Vec<String>,
+    /// Chain alias or blockchain Id that exported the atomic UTXOs (e.g., "C").
+    /// Set "None" to fetch the UTXOs on this chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_chain: Option<String>,
     pub limit: u32,
+    /// Set to the "endIndex" of the previous call to fetch the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_index: Option<super::EndIndex>,
     pub encoding: String,
 }
 
@@ -1591,3 +1599,810 @@ fn test_get_blockchain_status() {
     };
     assert_eq!(resp, expected);
 }
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetStakeRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetStakeParams>,
+}
+
+impl Default for GetStakeRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetStakeRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStakeParams {
+    pub addresses: Vec<String>,
+    /// Set "true" to only count the stakes of the validators (not delegators).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validators_only: Option<bool>,
+    pub encoding: String,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetStakeResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetStakeResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#GetStakeReply>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStakeResult {
+    /// Staked AVAX amount, deprecated in favor of "stakeds".
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub staked: Option<u64>,
+    /// Maps each asset Id to its staked amount.
+    #[serde_as(as = "Option<HashMap<_, DisplayFromStr>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stakeds: Option<HashMap<String, u64>>,
+    /// Hex-encoded staked outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub staked_outputs: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_stake --exact --show-output
+#[test]
+fn test_get_stake() {
+    let mut data = GetStakeRequest::default();
+    data.method = String::from("platform.getStake");
+    data.params = Some(GetStakeParams {
+        addresses: vec![String::from(
+            "P-custom1qwmslrrqdv4slxvynhy9csq069l0u8mqwjzmcd",
+        )],
+        validators_only: Some(true),
+        encoding: String::from("hex"),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"platform.getStake\",\"params\":{\"addresses\":[\"P-custom1qwmslrrqdv4slxvynhy9csq069l0u8mqwjzmcd\"],\"validatorsOnly\":true,\"encoding\":\"hex\"}}"
+    );
+
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake>
+    let resp: GetStakeResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "staked": "6500000000000",
+        "stakeds": {
+            "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z": "6500000000000"
+        },
+        "stakedOutputs": [
+            "0x000021e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000007000005e96630e8000000000000000000000000010000000161cd7d26c72edc631d4114d6eef2c4e9d48c3a6f6c8bb7df"
+        ],
+        "encoding": "hex"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let mut stakeds = HashMap::new();
+    stakeds.insert(
+        String::from("FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z"),
+        6500000000000_u64,
+    );
+    let expected = GetStakeResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetStakeResult {
+            staked: Some(6500000000000),
+            stakeds: Some(stakeds),
+            staked_outputs: Some(vec![String::from("0x000021e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000007000005e96630e8000000000000000000000000010000000161cd7d26c72edc631d4114d6eef2c4e9d48c3a6f6c8bb7df")]),
+            encoding: Some(String::from("hex")),
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetminstake>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetMinStakeResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetMinStakeResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetminstake>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMinStakeResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub min_validator_stake: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub min_delegator_stake: u64,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_min_stake --exact --show-output
+#[test]
+fn test_get_min_stake() {
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetminstake>
+    let resp: GetMinStakeResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "minValidatorStake": "2000000000000",
+        "minDelegatorStake": "25000000000"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetMinStakeResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetMinStakeResult {
+            min_validator_stake: 2000000000000,
+            min_delegator_stake: 25000000000,
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetcurrentsupply>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetCurrentSupplyResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetCurrentSupplyResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetcurrentsupply>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetCurrentSupplyResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub supply: u64,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_current_supply --exact --show-output
+#[test]
+fn test_get_current_supply() {
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetcurrentsupply>
+    let resp: GetCurrentSupplyResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "supply": "365865167637779183"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetCurrentSupplyResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetCurrentSupplyResult {
+            supply: 365865167637779183,
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetrewardutxos>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetRewardUtxosResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetRewardUtxosResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetrewardutxos>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRewardUtxosResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub num_fetched: u32,
+
+    #[serde_as(as = "Option<Vec<Hex0xUtxo>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utxos: Option<Vec<txs::utxo::Utxo>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_reward_utxos --exact --show-output
+#[test]
+fn test_get_reward_utxos() {
+    let resp: GetRewardUtxosResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "numFetched": "0",
+        "utxos": [],
+        "encoding": "hex"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetRewardUtxosResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetRewardUtxosResult {
+            num_fetched: 0,
+            utxos: Some(Vec::new()),
+            encoding: Some(String::from("hex")),
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettimestamp>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetTimestampResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetTimestampResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettimestamp>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetTimestampResult {
+    #[serde_as(as = "crate::codec::serde::rfc_3339::DateTimeUtc")]
+    pub timestamp: DateTime<Utc>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_timestamp --exact --show-output
+#[test]
+fn test_get_timestamp() {
+    use chrono::TimeZone;
+
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettimestamp>
+    let resp: GetTimestampResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "timestamp": "2021-09-07T00:00:00-04:00"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetTimestampResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetTimestampResult {
+            timestamp: Utc.with_ymd_and_hms(2021, 9, 7, 4, 0, 0).unwrap(),
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetvalidatorsat>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetValidatorsAtRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetValidatorsAtParams>,
+}
+
+impl Default for GetValidatorsAtRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetValidatorsAtRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetvalidatorsat>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetValidatorsAtParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+    /// Set "None" for the primary network.
+    #[serde(rename = "subnetID", skip_serializing_if = "Option::is_none")]
+    pub subnet_id: Option<ids::Id>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetvalidatorsat>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetValidatorsAtResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetValidatorsAtResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetvalidatorsat>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#GetValidatorsAtReply>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetValidatorsAtResult {
+    /// Maps each validator node Id to its weight.
+    pub validators: HashMap<node::Id, u64>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_validators_at --exact --show-output
+#[test]
+fn test_get_validators_at() {
+    use std::str::FromStr;
+
+    let mut data = GetValidatorsAtRequest::default();
+    data.method = String::from("platform.getValidatorsAt");
+    data.params = Some(GetValidatorsAtParams {
+        height: 1,
+        subnet_id: None,
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"platform.getValidatorsAt\",\"params\":{\"height\":\"1\"}}"
+    );
+
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetvalidatorsat>
+    let resp: GetValidatorsAtResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "validators": {
+            "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg": 2000000000000000,
+            "NodeID-GWPcbFJZFfZreETSoWjPimr846mXEKCtu": 2000000000000000
+        }
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let mut validators = HashMap::new();
+    validators.insert(
+        node::Id::from_str("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg").unwrap(),
+        2000000000000000_u64,
+    );
+    validators.insert(
+        node::Id::from_str("NodeID-GWPcbFJZFfZreETSoWjPimr846mXEKCtu").unwrap(),
+        2000000000000000_u64,
+    );
+    let expected = GetValidatorsAtResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetValidatorsAtResult { validators }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformsamplevalidators>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct SampleValidatorsRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<SampleValidatorsParams>,
+}
+
+impl Default for SampleValidatorsRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl SampleValidatorsRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformsamplevalidators>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct SampleValidatorsParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub size: u16,
+    /// Set "None" for the primary network.
+    #[serde(rename = "subnetID", skip_serializing_if = "Option::is_none")]
+    pub subnet_id: Option<ids::Id>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformsamplevalidators>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct SampleValidatorsResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<SampleValidatorsResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformsamplevalidators>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct SampleValidatorsResult {
+    pub validators: Vec<node::Id>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_sample_validators --exact --show-output
+#[test]
+fn test_sample_validators() {
+    use std::str::FromStr;
+
+    let mut data = SampleValidatorsRequest::default();
+    data.method = String::from("platform.sampleValidators");
+    data.params = Some(SampleValidatorsParams {
+        size: 2,
+        subnet_id: Some(
+            ids::Id::from_str("2bRCr6B4MiEfSjidDwxDpdCyviwnfUVqB2HGwhm947w9YYqb7r").unwrap(),
+        ),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"platform.sampleValidators\",\"params\":{\"size\":\"2\",\"subnetID\":\"2bRCr6B4MiEfSjidDwxDpdCyviwnfUVqB2HGwhm947w9YYqb7r\"}}"
+    );
+
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformsamplevalidators>
+    let resp: SampleValidatorsResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "id": 1,
+    "result": {
+        "validators": [
+            "NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ",
+            "NodeID-NFBbbJ4qCmNaCzeW7sxErhvWqvEQMnYcN"
+        ]
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = SampleValidatorsResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(SampleValidatorsResult {
+            validators: vec![
+                node::Id::from_str("NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ").unwrap(),
+                node::Id::from_str("NodeID-NFBbbJ4qCmNaCzeW7sxErhvWqvEQMnYcN").unwrap(),
+            ],
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockbyheight>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockByHeightRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetBlockByHeightParams>,
+}
+
+impl Default for GetBlockByHeightRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetBlockByHeightRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockbyheight>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockByHeightParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+    pub encoding: String,
+}
+
+/// Response for "platform.getBlock" and "platform.getBlockByHeight".
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetBlockResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblock>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockResult {
+    /// Hex-encoded block bytes with the checksum.
+    pub block: String,
+    pub encoding: String,
+}
+
+impl GetBlockResult {
+    /// Decodes the hex-encoded block.
+    pub fn parse_block(&self) -> crate::errors::Result<platformvm::blocks::Block> {
+        platformvm::blocks::Block::from_hex(&self.block)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_block --exact --show-output
+#[test]
+fn test_get_block() {
+    let mut data = GetBlockByHeightRequest::default();
+    data.method = String::from("platform.getBlockByHeight");
+    data.params = Some(GetBlockByHeightParams {
+        height: 1000001,
+        encoding: String::from("hex"),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"platform.getBlockByHeight\",\"params\":{\"height\":\"1000001\",\"encoding\":\"hex\"}}"
+    );
+
+    // "platformvm.CommitBlock" with the parent Id and the height
+    let mut blk_bytes = vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x02];
+    blk_bytes.extend_from_slice(&[0x01; 32]);
+    blk_bytes.extend_from_slice(&1000001_u64.to_be_bytes());
+    let resp: GetBlockResponse = serde_json::from_str(&format!(
+        "{{\"jsonrpc\":\"2.0\",\"result\":{{\"block\":\"{}\",\"encoding\":\"hex\"}},\"id\":1}}",
+        crate::formatting::encode_hex_with_checksum(&blk_bytes)
+    ))
+    .unwrap();
+    let result = resp.result.unwrap();
+    assert_eq!(result.encoding, "hex");
+
+    let blk = result.parse_block().unwrap();
+    assert!(matches!(blk, platformvm::blocks::Block::ApricotCommit(_)));
+    assert_eq!(blk.parent_id(), ids::Id::from_slice(&[0x01; 32]));
+    assert_eq!(blk.height(), 1000001);
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetpendingvalidators>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetPendingValidatorsRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetPendingValidatorsParams>,
+}
+
+impl Default for GetPendingValidatorsRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetPendingValidatorsRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to serialize JSON {}", e)))
+    }
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetpendingvalidators>
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetPendingValidatorsParams {
+    /// Set "None" for the primary network.
+    #[serde(rename = "subnetID", skip_serializing_if = "Option::is_none")]
+    pub subnet_id: Option<ids::Id>,
+    /// Set "None" to list all pending validators.
+    #[serde(rename = "nodeIDs", skip_serializing_if = "Option::is_none")]
+    pub node_ids: Option<Vec<node::Id>>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_pending_validators_request --exact --show-output
+#[test]
+fn test_get_pending_validators_request() {
+    use std::str::FromStr;
+
+    let mut data = GetPendingValidatorsRequest::default();
+    data.method = String::from("platform.getPendingValidators");
+    data.params = Some(GetPendingValidatorsParams {
+        subnet_id: None,
+        node_ids: Some(vec![node::Id::from_str(
+            "NodeID-5mb46qkSBj81k9g9e4VFjGGSbaaSLFRzD",
+        )
+        .unwrap()]),
+    });
+    assert_eq!(
+        data.encode_json().unwrap(),
+        "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"platform.getPendingValidators\",\"params\":{\"nodeIDs\":[\"NodeID-5mb46qkSBj81k9g9e4VFjGGSbaaSLFRzD\"]}}"
+    );
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettotalstake>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetTotalStakeResponse {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GetTotalStakeResult>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<jsonrpc::ResponseError>,
+}
+
+/// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettotalstake>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#GetTotalStakeReply>
+#[serde_as]
+#[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetTotalStakeResult {
+    /// Total staked AVAX, deprecated in favor of "weight".
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stake: Option<u64>,
+    /// Total weight of the subnet validators.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u64>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_total_stake --exact --show-output
+#[test]
+fn test_get_total_stake() {
+    // ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettotalstake>
+    let resp: GetTotalStakeResponse = serde_json::from_str(
+        r#"
+{
+    "jsonrpc": "2.0",
+    "result": {
+        "stake": "279825917679866811",
+        "weight": "279825917679866811"
+    },
+    "id": 1
+}
+"#,
+    )
+    .unwrap();
+
+    let expected = GetTotalStakeResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetTotalStakeResult {
+            stake: Some(279825917679866811),
+            weight: Some(279825917679866811),
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
@@ -69,26 +69,113 @@ where
     }
 
     /// Returns "None" if the threshold is NOT met.
+    /// The output can be any output with "OutputOwners"
+    /// (e.g., "secp256k1fx.TransferOutput", "secp256k1fx.MintOutput",
+    /// "nftfx.TransferOutput", "propertyfx.OwnedOutput", "platformvm.StakeableLockOut"),
+    /// and the returned input type is decided by the output.
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Keychain.Spend>
-    /// TODO: support spend on "secp256k1fx::MintOutput"
-    pub fn spend(
-        &self,
-        output: &key::secp256k1::txs::transfer::Output,
-        time: u64,
-    ) -> Option<(key::secp256k1::txs::transfer::Input, Vec<T>)> {
-        let res = self.match_threshold(&output.output_owners, time);
-        let threshold_met = res.is_some();
-        if !threshold_met {
-            return None;
-        }
-
-        let (sig_indices, keys) = res.unwrap();
-        Some((
-            key::secp256k1::txs::transfer::Input {
-                amount: output.amount,
-                sig_indices,
-            },
-            keys,
-        ))
+    pub fn spend<O: Spendable>(&self, output: &O, time: u64) -> Option<(O::Input, Vec<T>)> {
+        let (sig_indices, keys) = self.match_threshold(output.output_owners(), time)?;
+        Some((output.spend_input(sig_indices), keys))
     }
 }
+
+/// Output that is owned by "OutputOwners", thus can be spent by
+/// the keychain that holds enough keys to meet the threshold.
+pub trait Spendable {
+    /// Input that consumes the output
+    /// (e.g., "secp256k1fx.TransferInput" for "secp256k1fx.TransferOutput").
+    type Input;
+
+    fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners;
+
+    /// Builds the input that consumes the output with the signature indices
+    /// of the owner addresses.
+    fn spend_input(&self, sig_indices: Vec<u32>) -> Self::Input;
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::keychain::test_spend --exact --show-output
+#[test]
+fn test_spend() {
+    use crate::{nftfx, platformvm, propertyfx};
+
+    // ref. "avalanchego/vms/avm/vm_test.go"
+    let k = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    let addr = k.to_public_key().to_short_id().unwrap();
+    let keychain = Keychain::new(vec![k]);
+
+    // the keychain key is the second owner
+    let owners = key::secp256k1::txs::OutputOwners::new(
+        0,
+        1,
+        &[short::Id::from_slice(&[0x01]), addr.clone()],
+    );
+
+    let transfer_output = key::secp256k1::txs::transfer::Output::new(100, owners.clone());
+    let (input, keys) = keychain.spend(&transfer_output, 0).unwrap();
+    assert_eq!(input.amount, 100);
+    assert_eq!(input.sig_indices, vec![1]);
+    assert_eq!(keys.len(), 1);
+
+    let expected = key::secp256k1::txs::Input::new(vec![1]);
+    let (input, _) = keychain
+        .spend(&key::secp256k1::txs::mint::Output::new(owners.clone()), 0)
+        .unwrap();
+    assert_eq!(input, expected);
+    let (input, _) = keychain
+        .spend(&nftfx::MintOutput::new(1, owners.clone()), 0)
+        .unwrap();
+    assert_eq!(input, expected);
+    let (input, _) = keychain
+        .spend(
+            &nftfx::TransferOutput::new(1, vec![0x01], owners.clone()),
+            0,
+        )
+        .unwrap();
+    assert_eq!(input, expected);
+    let (input, _) = keychain
+        .spend(&propertyfx::MintOutput::new(owners.clone()), 0)
+        .unwrap();
+    assert_eq!(input, expected);
+    let (input, _) = keychain
+        .spend(&propertyfx::OwnedOutput::new(owners.clone()), 0)
+        .unwrap();
+    assert_eq!(input, expected);
+
+    // the locked output can be spent before its locktime (e.g., for staking)
+    let stakeable_lock_out = platformvm::txs::StakeableLockOut {
+        locktime: 1000,
+        transfer_output: transfer_output.clone(),
+    };
+    let (input, _) = keychain.spend(&stakeable_lock_out, 0).unwrap();
+    assert_eq!(input.locktime, 1000);
+    assert_eq!(input.transfer_input.amount, 100);
+    assert_eq!(input.transfer_input.sig_indices, vec![1]);
+
+    // threshold not met
+    let owners_threshold_2 = key::secp256k1::txs::OutputOwners {
+        threshold: 2,
+        ..owners.clone()
+    };
+    assert!(keychain
+        .spend(&propertyfx::OwnedOutput::new(owners_threshold_2), 0)
+        .is_none());
+
+    // output owners are still locked
+    let owners_locked = key::secp256k1::txs::OutputOwners {
+        locktime: 10,
+        ..owners
+    };
+    assert!(keychain
+        .spend(
+            &nftfx::TransferOutput::new(1, vec![], owners_locked.clone()),
+            9
+        )
+        .is_none());
+    assert!(keychain
+        .spend(&nftfx::TransferOutput::new(1, vec![], owners_locked), 10)
+        .is_some());
+}
@@ -0,0 +1,143 @@
+use crate::{
+    codec, errors, key,
+    packer::{self, Unpackable},
+};
+use serde::{Deserialize, Serialize};
+
+/// Grants the owners the authority to mint more of the variable-cap asset.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#MintOutput>
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct Output {
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Output {
+    pub fn default() -> Self {
+        Self {
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(output_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        Self { output_owners }
+    }
+
+    pub fn type_name() -> String {
+        "secp256k1fx.MintOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+/// Spent by "secp256k1fx.Input" as the mint input of "secp256k1fx.MintOperation".
+impl key::secp256k1::keychain::Spendable for Output {
+    type Input = key::secp256k1::txs::Input;
+
+    fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners {
+        &self.output_owners
+    }
+
+    fn spend_input(&self, sig_indices: Vec<u32>) -> Self::Input {
+        key::secp256k1::txs::Input::new(sig_indices)
+    }
+}
+
+/// Consumes a "secp256k1fx.MintOutput" to produce a new "MintOutput"
+/// (the minting authority) and a "TransferOutput" of the minted amount.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#MintOperation>
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct Operation {
+    #[serde(rename = "mintInput")]
+    pub mint_input: key::secp256k1::txs::Input,
+    #[serde(rename = "mintOutput")]
+    pub mint_output: Output,
+    #[serde(rename = "transferOutput")]
+    pub transfer_output: key::secp256k1::txs::transfer::Output,
+}
+
+impl Default for Operation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Operation {
+    pub fn default() -> Self {
+        Self {
+            mint_input: key::secp256k1::txs::Input::default(),
+            mint_output: Output::default(),
+            transfer_output: key::secp256k1::txs::transfer::Output::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "secp256k1fx.MintOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+impl packer::Packer {
+    /// Unpacks the "secp256k1fx.MintOutput" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#MintOutput>
+    pub fn unpack_mint_output(&self) -> errors::Result<Output> {
+        // "secp256k1fx.MintOutput.OutputOwners" is embedded, thus no type ID
+        Output::unpack_from(self)
+    }
+
+    /// Unpacks the "secp256k1fx.MintOperation" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#MintOperation>
+    pub fn unpack_mint_operation(&self) -> errors::Result<Operation> {
+        // all fields are structs embedded inline, thus no type ID
+        Operation::unpack_from(self)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::mint::test_mint_output --exact --show-output
+#[test]
+fn test_mint_output() {
+    use crate::{ids::short, packer::Packable};
+
+    let d = Output::new(key::secp256k1::txs::OutputOwners::new(
+        1,
+        1,
+        &[short::Id::from_slice(&[0x01, 0x02])],
+    ));
+
+    let json_encoded = serde_json::to_string(&d).unwrap();
+    assert!(json_encoded.contains("\"threshold\":1"));
+    let json_decoded: Output = serde_json::from_str(&json_encoded).unwrap();
+    assert_eq!(d, json_decoded);
+
+    let packer = packer::Packer::new(1024, 0);
+    d.pack_into(&packer).unwrap();
+    let b = packer.take_bytes();
+    let mut expected: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, // locktime
+        0x00, 0x00, 0x00, 0x01, // threshold
+        0x00, 0x00, 0x00, 0x01, // addresses.len()
+    ];
+    expected.extend_from_slice(short::Id::from_slice(&[0x01, 0x02]).as_ref());
+    assert_eq!(&b[..], &expected[..]);
+
+    let packer = packer::Packer::load_bytes_for_unpack(1024, &b);
+    assert_eq!(packer.unpack_mint_output().unwrap(), d);
+}
@@ -1,10 +1,14 @@
+pub mod mint;
 pub mod transfer;
 
 use std::cmp::Ordering;
 
 use crate::{
     codec::{self, serde::hex_0x_bytes::Hex0xBytes},
+    errors::{Error, Result},
     ids::short,
+    key,
+    packer::{self, Unpackable},
 };
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -95,6 +99,41 @@ fn test_credential_custom_de_serializer() {
     assert_eq!(d, json_decoded_2);
 }
 
+impl packer::Packer {
+    /// Unpacks the "secp256k1fx.Credential" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Credential>
+    pub fn unpack_credential(&self) -> Result<Credential> {
+        let sigs_len = self.unpack_u32()?;
+        let mut signatures: Vec<Vec<u8>> = Vec::new();
+        for _ in 0..sigs_len {
+            let sig = self.unpack_bytes(key::secp256k1::signature::LEN)?;
+            signatures.push(sig);
+        }
+        Ok(Credential { signatures })
+    }
+
+    /// Unpacks the list of "secp256k1fx.Credential" in the signed tx,
+    /// each of which is prefixed with its type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+    pub fn unpack_credentials(&self) -> Result<Vec<Credential>> {
+        let creds_len = self.unpack_u32()?;
+        let mut creds: Vec<Credential> = Vec::new();
+        for _ in 0..creds_len {
+            let type_id_cred = self.unpack_u32()?;
+            if type_id_cred != Credential::type_id() {
+                return Err(Error::UnexpectedTypeId {
+                    type_id: type_id_cred,
+                    type_name: None,
+                    interface: "verify.Verifiable".to_string(),
+                });
+            }
+            creds.push(self.unpack_credential()?);
+        }
+        Ok(creds)
+    }
+}
+
 #[derive(Eq)]
 pub struct Signatures(Vec<Vec<u8>>);
 
@@ -232,7 +271,7 @@ impl PartialEq for SigIndices {
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/fx#Owner>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners>
-#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, Clone, packer::Packable, packer::Unpackable)]
 pub struct OutputOwners {
     pub locktime: u64,
     pub threshold: u32,
@@ -269,6 +308,29 @@ impl OutputOwners {
     pub fn type_id() -> u32 {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+
+    /// ref. "avalanchego/vms/secp256k1fx.OutputOwners.Verify"
+    pub fn verify(&self) -> Result<()> {
+        if self.threshold as usize > self.addresses.len() {
+            return Err(Error::Other {
+                message: "output is unspendable".to_string(), // ref. "ErrOutputUnspendable"
+                retryable: false,
+            });
+        }
+        if self.threshold == 0 && !self.addresses.is_empty() {
+            return Err(Error::Other {
+                message: "output representation should be optimized".to_string(), // ref. "ErrOutputUnoptimized"
+                retryable: false,
+            });
+        }
+        if !cmp_manager::is_sorted_and_unique(&self.addresses) {
+            return Err(Error::Other {
+                message: "addresses not sorted and unique".to_string(), // ref. "ErrAddrsNotSortedUnique"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
 }
 
 impl Ord for OutputOwners {
@@ -296,6 +358,78 @@ impl PartialEq for OutputOwners {
     }
 }
 
+impl packer::Packer {
+    /// Unpacks the "secp256k1fx.OutputOwners" fields.
+    /// The caller must have already unpacked the type ID, if any.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners>
+    pub fn unpack_output_owners(&self) -> Result<OutputOwners> {
+        OutputOwners::unpack_from(self)
+    }
+
+    /// Unpacks the "secp256k1fx.Input" fields.
+    /// The caller must have already unpacked the type ID, if any.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input>
+    pub fn unpack_input(&self) -> Result<Input> {
+        Input::unpack_from(self)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::test_output_owners_verify --exact --show-output
+#[test]
+fn test_output_owners_verify() {
+    // ref. "avalanchego/vms/secp256k1fx/transfer_output_test.go" "TestOutputSerialize"
+    let expected_bytes: Vec<u8> = vec![
+        // codec version
+        0x00, 0x00, //
+        // amount
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x39, //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xd4, 0x31, //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x02, //
+        // addrs[0]
+        0x51, 0x02, 0x5c, 0x61, 0xfb, 0xcf, 0xc0, 0x78, //
+        0xf6, 0x93, 0x34, 0xf8, 0x34, 0xbe, 0x6d, 0xd2, //
+        0x6d, 0x55, 0xa9, 0x55, //
+        // addrs[1]
+        0xc3, 0x34, 0x41, 0x28, 0xe0, 0x60, 0x12, 0x8e, //
+        0xde, 0x35, 0x23, 0xa2, 0x4a, 0x46, 0x1c, 0x89, //
+        0x43, 0xab, 0x08, 0x59, //
+    ];
+    let packer = packer::Packer::load_bytes_for_unpack(1024, &expected_bytes);
+    assert_eq!(packer.unpack_u16().unwrap(), codec::VERSION);
+    let out = packer.unpack_transfer_output().unwrap();
+    assert_eq!(packer.get_offset(), expected_bytes.len());
+    assert_eq!(out.amount, 12345);
+    assert_eq!(out.output_owners.locktime, 54321);
+    out.output_owners.verify().unwrap();
+
+    let addrs = out.output_owners.addresses.clone();
+    let cases: Vec<(OutputOwners, &str)> = vec![
+        (OutputOwners::new(0, 3, &addrs), "output is unspendable"),
+        (
+            OutputOwners::new(0, 0, &addrs),
+            "output representation should be optimized",
+        ),
+        (
+            OutputOwners::new(0, 1, &[addrs[1].clone(), addrs[0].clone()]),
+            "addresses not sorted and unique",
+        ),
+        (
+            OutputOwners::new(0, 1, &[addrs[0].clone(), addrs[0].clone()]),
+            "addresses not sorted and unique",
+        ),
+    ];
+    for (owners, expected) in cases.iter() {
+        assert!(owners.verify().unwrap_err().contains(expected));
+    }
+
+    // no owner is valid, as the output can never be spent
+    OutputOwners::default().verify().unwrap();
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::test_sort_output_owners --exact --show-output
 #[test]
 fn test_sort_output_owners() {
@@ -365,7 +499,7 @@ fn test_sort_output_owners() {
 }
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input>
-#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, Clone, packer::Packable, packer::Unpackable)]
 pub struct Input {
     pub sig_indices: Vec<u32>,
 }
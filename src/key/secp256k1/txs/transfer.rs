@@ -3,14 +3,17 @@ use std::{
     io::{self, Error, ErrorKind},
 };
 
-use crate::{codec, key};
+use crate::{
+    codec, errors, key,
+    packer::{self, Unpackable},
+};
 use serde::{Deserialize, Serialize};
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOut>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput>
 /// ref. <https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettx>
-#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, Clone, packer::Packable, packer::Unpackable)]
 pub struct Output {
     pub amount: u64,
 
@@ -49,6 +52,40 @@ impl Output {
     }
 }
 
+/// Spent by "secp256k1fx.TransferInput" of the full amount.
+impl key::secp256k1::keychain::Spendable for Output {
+    type Input = Input;
+
+    fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners {
+        &self.output_owners
+    }
+
+    fn spend_input(&self, sig_indices: Vec<u32>) -> Self::Input {
+        Input {
+            amount: self.amount,
+            sig_indices,
+        }
+    }
+}
+
+impl packer::Packer {
+    /// Unpacks the "secp256k1fx.TransferOutput" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput>
+    pub fn unpack_transfer_output(&self) -> errors::Result<Output> {
+        // "secp256k1fx.TransferOutput.OutputOwners" is embedded, thus no type ID
+        Output::unpack_from(self)
+    }
+
+    /// Unpacks the "secp256k1fx.TransferInput" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput>
+    pub fn unpack_transfer_input(&self) -> errors::Result<Input> {
+        // "secp256k1fx.TransferInput.Input" is embedded, thus no type ID
+        Input::unpack_from(self)
+    }
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::transfer::test_transfer_output_custom_de_serializer --exact --show-output
 #[test]
 fn test_transfer_output_custom_de_serializer() {
@@ -220,7 +257,7 @@ fn test_sort_transfer_outputs() {
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableIn>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input>
-#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, Clone, packer::Packable, packer::Unpackable)]
 pub struct Input {
     pub amount: u64,
     #[serde(rename = "signatureIndices")]
@@ -365,3 +402,50 @@ fn test_sort_transfer_inputs() {
     assert!(cmp_manager::is_sorted_and_unique(&sorted_inputs));
     assert_eq!(inputs, sorted_inputs);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::transfer::test_transfer_output_packable --exact --show-output
+/// ref. "avalanchego/vms/avm.TestBaseTxSerialization"
+#[test]
+fn test_transfer_output_packable() {
+    use crate::{ids::short, packer::Packable};
+
+    let d = Output {
+        amount: 12345,
+        output_owners: key::secp256k1::txs::OutputOwners {
+            locktime: 0,
+            threshold: 1,
+            addresses: vec![short::Id::from_slice(&[
+                0xfc, 0xed, 0xa8, 0xf9, 0x0f, 0xcb, 0x5d, 0x30, //
+                0x61, 0x4b, 0x99, 0xd7, 0x9f, 0xc4, 0xba, 0xa2, //
+                0x93, 0x07, 0x76, 0x26, //
+            ])],
+        },
+    };
+
+    let packer = packer::Packer::new(1024, 0);
+    d.pack_into(&packer).unwrap();
+    let b = packer.take_bytes();
+
+    let expected: &[u8] = &[
+        // amount
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x39, //
+        //
+        // output_owners.locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // output_owners.threshold
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // output_owners.addrs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // output_owners.addrs[0]
+        0xfc, 0xed, 0xa8, 0xf9, 0x0f, 0xcb, 0x5d, 0x30, //
+        0x61, 0x4b, 0x99, 0xd7, 0x9f, 0xc4, 0xba, 0xa2, //
+        0x93, 0x07, 0x76, 0x26, //
+    ];
+    assert!(cmp_manager::eq_vectors(expected, &b));
+
+    let packer = packer::Packer::load_bytes_for_unpack(1024, &b);
+    assert_eq!(packer.unpack_transfer_output().unwrap(), d);
+}
@@ -1,5 +1,8 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+// so that "avalanche_types_derive" macros resolve "::avalanche_types" paths within this crate
+extern crate self as avalanche_types;
+
 pub mod avm;
 pub mod choices;
 pub mod codec;
@@ -10,9 +13,11 @@ pub mod hash;
 pub mod ids;
 pub mod jsonrpc;
 pub mod key;
+pub mod nftfx;
 pub mod node;
 pub mod packer;
 pub mod platformvm;
+pub mod propertyfx;
 pub mod txs;
 pub mod units;
 pub mod utils;
@@ -0,0 +1,353 @@
+//! Non-fungible token feature extension.
+//! ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx>
+use crate::{
+    codec::{self, serde::hex_0x_bytes::Hex0xBytes},
+    errors::{Error, Result},
+    key,
+    packer::{self, Unpackable},
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Maximum size of the NFT payload in bytes.
+/// ref. "avalanchego/vms/nftfx.MaxPayloadSize"
+pub const MAX_PAYLOAD_SIZE: usize = 1 << 10;
+
+/// Grants the owners the authority to mint NFTs of the group.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#MintOutput>
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct MintOutput {
+    #[serde(rename = "groupID")]
+    pub group_id: u32,
+
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for MintOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl MintOutput {
+    pub fn default() -> Self {
+        Self {
+            group_id: 0,
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(group_id: u32, output_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        Self {
+            group_id,
+            output_owners,
+        }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.MintOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+/// Owns a single NFT of the group with its payload.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#TransferOutput>
+#[serde_as]
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct TransferOutput {
+    #[serde(rename = "groupID")]
+    pub group_id: u32,
+    #[serde_as(as = "Hex0xBytes")]
+    pub payload: Vec<u8>,
+
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for TransferOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl TransferOutput {
+    pub fn default() -> Self {
+        Self {
+            group_id: 0,
+            payload: Vec::new(),
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(
+        group_id: u32,
+        payload: Vec<u8>,
+        output_owners: key::secp256k1::txs::OutputOwners,
+    ) -> Self {
+        Self {
+            group_id,
+            payload,
+            output_owners,
+        }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.TransferOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/nftfx.TransferOutput.Verify"
+    pub fn verify(&self) -> Result<()> {
+        if self.payload.len() > MAX_PAYLOAD_SIZE {
+            return Err(Error::Other {
+                message: format!(
+                    "payload too large ({} > {})",
+                    self.payload.len(),
+                    MAX_PAYLOAD_SIZE
+                ), // ref. "errPayloadTooLarge"
+                retryable: false,
+            });
+        }
+        self.output_owners.verify()
+    }
+}
+
+/// Spent by "secp256k1fx.Input" as the mint input of "nftfx.MintOperation".
+impl key::secp256k1::keychain::Spendable for MintOutput {
+    type Input = key::secp256k1::txs::Input;
+
+    fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners {
+        &self.output_owners
+    }
+
+    fn spend_input(&self, sig_indices: Vec<u32>) -> Self::Input {
+        key::secp256k1::txs::Input::new(sig_indices)
+    }
+}
+
+/// Spent by "secp256k1fx.Input" as the input of "nftfx.TransferOperation".
+impl key::secp256k1::keychain::Spendable for TransferOutput {
+    type Input = key::secp256k1::txs::Input;
+
+    fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners {
+        &self.output_owners
+    }
+
+    fn spend_input(&self, sig_indices: Vec<u32>) -> Self::Input {
+        key::secp256k1::txs::Input::new(sig_indices)
+    }
+}
+
+/// Consumes a "nftfx.MintOutput" to mint an NFT of the group
+/// for each of the owners, with the same payload.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#MintOperation>
+#[serde_as]
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct MintOperation {
+    #[serde(rename = "mintInput")]
+    pub mint_input: key::secp256k1::txs::Input,
+    #[serde(rename = "groupID")]
+    pub group_id: u32,
+    #[serde_as(as = "Hex0xBytes")]
+    pub payload: Vec<u8>,
+    pub outputs: Vec<key::secp256k1::txs::OutputOwners>,
+}
+
+impl Default for MintOperation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl MintOperation {
+    pub fn default() -> Self {
+        Self {
+            mint_input: key::secp256k1::txs::Input::default(),
+            group_id: 0,
+            payload: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.MintOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/nftfx.MintOperation.Verify"
+    pub fn verify(&self) -> Result<()> {
+        if self.payload.len() > MAX_PAYLOAD_SIZE {
+            return Err(Error::Other {
+                message: format!(
+                    "payload too large ({} > {})",
+                    self.payload.len(),
+                    MAX_PAYLOAD_SIZE
+                ), // ref. "errPayloadTooLarge"
+                retryable: false,
+            });
+        }
+        for out in self.outputs.iter() {
+            out.verify()?;
+        }
+        Ok(())
+    }
+}
+
+/// Consumes a "nftfx.TransferOutput" to move the NFT to the new owners.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#TransferOperation>
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct TransferOperation {
+    pub input: key::secp256k1::txs::Input,
+    pub output: TransferOutput,
+}
+
+impl Default for TransferOperation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl TransferOperation {
+    pub fn default() -> Self {
+        Self {
+            input: key::secp256k1::txs::Input::default(),
+            output: TransferOutput::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.TransferOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/nftfx.TransferOperation.Verify"
+    pub fn verify(&self) -> Result<()> {
+        self.output.verify()
+    }
+}
+
+/// Returns the type name of "nftfx.Credential", which embeds
+/// "secp256k1fx.Credential" and is used to spend the nftfx outputs.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#Credential>
+pub fn credential_type_name() -> String {
+    "nftfx.Credential".to_string()
+}
+
+pub fn credential_type_id() -> u32 {
+    *(codec::X_TYPES.get(&credential_type_name()).unwrap()) as u32
+}
+
+impl packer::Packer {
+    /// Unpacks the "nftfx.MintOutput" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#MintOutput>
+    pub fn unpack_nft_mint_output(&self) -> Result<MintOutput> {
+        // "nftfx.MintOutput.OutputOwners" is embedded, thus no type ID
+        MintOutput::unpack_from(self)
+    }
+
+    /// Unpacks the "nftfx.TransferOutput" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#TransferOutput>
+    pub fn unpack_nft_transfer_output(&self) -> Result<TransferOutput> {
+        // "nftfx.TransferOutput.OutputOwners" is embedded, thus no type ID
+        TransferOutput::unpack_from(self)
+    }
+
+    /// Unpacks the "nftfx.MintOperation" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#MintOperation>
+    pub fn unpack_nft_mint_operation(&self) -> Result<MintOperation> {
+        MintOperation::unpack_from(self)
+    }
+
+    /// Unpacks the "nftfx.TransferOperation" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#TransferOperation>
+    pub fn unpack_nft_transfer_operation(&self) -> Result<TransferOperation> {
+        TransferOperation::unpack_from(self)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- nftfx::test_outputs --exact --show-output
+#[test]
+fn test_outputs() {
+    use crate::{ids::short, packer::Packable};
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0x01, 0x02])]);
+
+    let mint_output = MintOutput::new(7, owners.clone());
+    let packer = packer::Packer::new(1024, 0);
+    mint_output.pack_into(&packer).unwrap();
+    let b = packer.take_bytes();
+    let mut expected: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x07, // group_id
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // locktime
+        0x00, 0x00, 0x00, 0x01, // threshold
+        0x00, 0x00, 0x00, 0x01, // addresses.len()
+    ];
+    expected.extend_from_slice(short::Id::from_slice(&[0x01, 0x02]).as_ref());
+    assert_eq!(&b[..], &expected[..]);
+    let packer = packer::Packer::load_bytes_for_unpack(1024, &b);
+    assert_eq!(packer.unpack_nft_mint_output().unwrap(), mint_output);
+
+    let transfer_output = TransferOutput::new(7, vec![0xaa, 0xbb], owners);
+    let packer = packer::Packer::new(1024, 0);
+    transfer_output.pack_into(&packer).unwrap();
+    let b = packer.take_bytes();
+    let mut expected: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x07, // group_id
+        0x00, 0x00, 0x00, 0x02, // payload.len()
+        0xaa, 0xbb, // payload
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // locktime
+        0x00, 0x00, 0x00, 0x01, // threshold
+        0x00, 0x00, 0x00, 0x01, // addresses.len()
+    ];
+    expected.extend_from_slice(short::Id::from_slice(&[0x01, 0x02]).as_ref());
+    assert_eq!(&b[..], &expected[..]);
+    let packer = packer::Packer::load_bytes_for_unpack(1024, &b);
+    assert_eq!(
+        packer.unpack_nft_transfer_output().unwrap(),
+        transfer_output
+    );
+
+    let json_encoded = serde_json::to_string(&transfer_output).unwrap();
+    assert!(json_encoded.contains("\"groupID\":7"));
+    assert!(json_encoded.contains("\"payload\":\"0xaabb\""));
+    let json_decoded: TransferOutput = serde_json::from_str(&json_encoded).unwrap();
+    assert_eq!(transfer_output, json_decoded);
+
+    assert!(transfer_output.verify().is_ok());
+    let too_large = TransferOutput {
+        payload: vec![0; MAX_PAYLOAD_SIZE + 1],
+        ..transfer_output
+    };
+    assert!(too_large
+        .verify()
+        .unwrap_err()
+        .contains("payload too large"));
+}
@@ -1,4 +1,10 @@
 pub mod ip;
+pub mod packable;
+pub mod reader;
+
+pub use avalanche_types_derive::{Packable, Unpackable};
+pub use packable::{Packable, Unpackable};
+pub use reader::PackerReader;
 
 use std::{cell::Cell, u16};
 
@@ -103,7 +109,9 @@ impl Packer {
     /// Be cautious! Once bytes are taken out, the "bytes" field is set to default (empty).
     /// To continue to write to bytes, remember to put it back with "set_bytes"
     /// because "bytes.take" leaves the field as "Default::default()".
-    /// TODO: make sure this does shallow copy!
+    /// "BytesMut::copy_to_bytes" is a shallow copy ("split_to" + "freeze"),
+    /// so this does not copy the underlying bytes.
+    /// Use "PackerReader" to read the bytes without copying on each "unpack_*".
     pub fn take_bytes(&self) -> Bytes {
         let mut b = self.bytes.take();
         let n = b.len();
@@ -0,0 +1,214 @@
+//! Linear codec encoding for the types that can be packed field by field.
+//!
+//! Use "#[derive(Packable, Unpackable)]" (re-exported in "packer") for the
+//! structs whose fields are all serialize:"true" and concrete, and mark the
+//! serialize:"false" fields with "#[packer(skip)]".
+//!
+//! ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/codec/linearcodec>
+//! ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/codec/reflectcodec>
+use crate::{
+    errors::{Error, Result},
+    ids::{self, node, short},
+    packer::Packer,
+};
+
+/// Packs the value into the packer at its current offset,
+/// in the same encoding as "avalanchego/codec/reflectcodec".
+pub trait Packable {
+    fn pack_into(&self, packer: &Packer) -> Result<()>;
+}
+
+/// Unpacks the value from the packer at its current offset,
+/// in the same encoding as "avalanchego/codec/reflectcodec".
+pub trait Unpackable: Sized {
+    fn unpack_from(packer: &Packer) -> Result<Self>;
+}
+
+macro_rules! impl_packable_primitive {
+    ($t:ty, $pack:ident, $unpack:ident) => {
+        impl Packable for $t {
+            fn pack_into(&self, packer: &Packer) -> Result<()> {
+                packer.$pack(*self)
+            }
+        }
+
+        impl Unpackable for $t {
+            fn unpack_from(packer: &Packer) -> Result<Self> {
+                packer.$unpack()
+            }
+        }
+    };
+}
+
+impl_packable_primitive!(u8, pack_byte, unpack_byte);
+impl_packable_primitive!(u16, pack_u16, unpack_u16);
+impl_packable_primitive!(u32, pack_u32, unpack_u32);
+impl_packable_primitive!(u64, pack_u64, unpack_u64);
+impl_packable_primitive!(bool, pack_bool, unpack_bool);
+
+/// Strings are prefixed with the 2-byte length.
+/// ref. "avalanchego/utils/wrappers.Packer.PackStr"
+impl Packable for String {
+    fn pack_into(&self, packer: &Packer) -> Result<()> {
+        packer.pack_str(self)
+    }
+}
+
+impl Unpackable for String {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        packer.unpack_str()
+    }
+}
+
+macro_rules! impl_packable_id {
+    ($t:ty, $len:expr) => {
+        /// IDs are fixed-size arrays, thus encoded without the length prefix.
+        impl Packable for $t {
+            fn pack_into(&self, packer: &Packer) -> Result<()> {
+                packer.pack_bytes(self.as_ref())
+            }
+        }
+
+        impl Unpackable for $t {
+            fn unpack_from(packer: &Packer) -> Result<Self> {
+                let b = packer.unpack_bytes($len)?;
+                Ok(<$t>::from_slice(&b))
+            }
+        }
+    };
+}
+
+impl_packable_id!(ids::Id, ids::LEN);
+impl_packable_id!(short::Id, short::LEN);
+impl_packable_id!(node::Id, node::LEN);
+// EVM addresses (e.g., "EVMInput.Address" in coreth atomic txs)
+impl_packable_id!(primitive_types::H160, 20);
+
+/// Fixed-size arrays are encoded element by element without the length prefix.
+/// ref. "avalanchego/codec/reflectcodec.genericCodec.marshal" for "reflect.Array"
+impl<T: Packable, const N: usize> Packable for [T; N] {
+    fn pack_into(&self, packer: &Packer) -> Result<()> {
+        for v in self.iter() {
+            v.pack_into(packer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Unpackable, const N: usize> Unpackable for [T; N] {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        let mut vs: Vec<T> = Vec::with_capacity(N);
+        for _ in 0..N {
+            vs.push(T::unpack_from(packer)?);
+        }
+        match vs.try_into() {
+            Ok(arr) => Ok(arr),
+            Err(_) => Err(Error::Other {
+                message: format!("failed to convert to [T; {}]", N),
+                retryable: false,
+            }),
+        }
+    }
+}
+
+/// Slices are prefixed with the 4-byte length.
+/// ref. "avalanchego/codec/reflectcodec.genericCodec.marshal" for "reflect.Slice"
+impl<T: Packable> Packable for Vec<T> {
+    fn pack_into(&self, packer: &Packer) -> Result<()> {
+        packer.pack_u32(self.len() as u32)?;
+        for v in self.iter() {
+            v.pack_into(packer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Unpackable> Unpackable for Vec<T> {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        let n = packer.unpack_u32()?;
+
+        // do not pre-allocate with the untrusted length,
+        // the bounds check on each element fails first
+        let mut vs: Vec<T> = Vec::new();
+        for _ in 0..n {
+            vs.push(T::unpack_from(packer)?);
+        }
+        Ok(vs)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- packer::packable::test_packable --exact --show-output
+#[test]
+fn test_packable() {
+    use crate::packer::{Packable, Unpackable};
+
+    #[derive(Debug, Default, PartialEq, Packable, Unpackable)]
+    struct Inner {
+        id: short::Id,
+        flags: [u8; 2],
+    }
+
+    #[derive(Debug, Default, PartialEq, Packable, Unpackable)]
+    struct Outer {
+        version: u16,
+        amount: u64,
+        enabled: bool,
+        name: String,
+        inners: Vec<Inner>,
+        memo: Vec<u8>,
+        #[packer(skip)]
+        cached: u32,
+    }
+
+    #[derive(Debug, PartialEq, Packable, Unpackable)]
+    struct Wrapper(ids::Id, node::Id);
+
+    let outer = Outer {
+        version: 1,
+        amount: 2,
+        enabled: true,
+        name: "ab".to_string(),
+        inners: vec![Inner {
+            id: short::Id::from_slice(&[0x01; short::LEN]),
+            flags: [0x02, 0x03],
+        }],
+        memo: Vec::new(),
+        cached: 100,
+    };
+
+    let packer = Packer::new(1024, 0);
+    outer.pack_into(&packer).unwrap();
+    let b = packer.take_bytes();
+
+    let mut expected: Vec<u8> = vec![
+        0x00, 0x01, // version
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, // amount
+        0x01, // enabled
+        0x00, 0x02, 0x61, 0x62, // name
+        0x00, 0x00, 0x00, 0x01, // inners.len()
+    ];
+    expected.extend_from_slice(&[0x01; short::LEN]); // inners[0].id
+    expected.extend_from_slice(&[0x02, 0x03]); // inners[0].flags
+    expected.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // memo.len()
+    assert_eq!(&b[..], &expected[..]);
+
+    let packer = Packer::load_bytes_for_unpack(1024, &b);
+    let unpacked = Outer::unpack_from(&packer).unwrap();
+    assert_eq!(unpacked, Outer { cached: 0, ..outer });
+    assert_eq!(packer.get_offset(), b.len());
+
+    // truncated bytes fail with the bounds check
+    let packer = Packer::load_bytes_for_unpack(1024, &b[..b.len() - 1]);
+    assert!(Outer::unpack_from(&packer).is_err());
+
+    let wrapper = Wrapper(
+        ids::Id::from_slice(&[0x04; ids::LEN]),
+        node::Id::from_slice(&[0x05; node::LEN]),
+    );
+    let packer = Packer::new(1024, 0);
+    wrapper.pack_into(&packer).unwrap();
+    let b = packer.take_bytes();
+    assert_eq!(b.len(), ids::LEN + node::LEN);
+    let packer = Packer::load_bytes_for_unpack(1024, &b);
+    assert_eq!(Wrapper::unpack_from(&packer).unwrap(), wrapper);
+}
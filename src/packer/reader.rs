@@ -0,0 +1,259 @@
+use std::cell::Cell;
+
+use crate::{
+    errors::{Error, Result},
+    packer::{BOOL_LEN, BYTE_LEN, U16_LEN, U32_LEN, U64_LEN},
+};
+
+/// Read-only packer over the borrowed bytes.
+///
+/// Unlike "Packer", it never copies the underlying bytes: "unpack_bytes"
+/// and "unpack_str" return slices borrowed from the input, which is useful
+/// for parsing blocks and vertices where most of the fields are discarded
+/// or only inspected. Bounds checks and error messages are identical to
+/// "Packer::unpack_*" so the two can be used interchangeably.
+///
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/wrappers#Packer>
+pub struct PackerReader<'a> {
+    /// borrowed byte array to read from
+    bytes: &'a [u8],
+    /// offset that is being read from in the byte array
+    offset: Cell<usize>,
+}
+
+impl<'a> PackerReader<'a> {
+    /// Creates a new reader from the existing bytes.
+    /// Fails if the bytes are larger than "max_size",
+    /// just as "Packer::expand" would have failed to write them.
+    pub fn new(max_size: usize, b: &'a [u8]) -> Result<Self> {
+        if b.len() > max_size {
+            return Err(Error::Other {
+                message: format!("needed_size {} exceeds max_size {}", b.len(), max_size),
+                retryable: false,
+            });
+        }
+        Ok(Self {
+            bytes: b,
+            offset: Cell::new(0),
+        })
+    }
+
+    /// Returns the "offset" value.
+    pub fn get_offset(&self) -> usize {
+        self.offset.get()
+    }
+
+    /// Returns the total length of the bytes array.
+    pub fn bytes_len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the number of bytes that are not read yet.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.get_offset()
+    }
+
+    /// Returns the bytes that are already read, without copying.
+    /// Useful to hash the unsigned tx bytes before the credentials.
+    pub fn read_bytes(&self) -> &'a [u8] {
+        &self.bytes[..self.get_offset()]
+    }
+
+    /// Returns the next "n" bytes and advances the offset.
+    /// ref. "avalanchego/utils/wrappers.Packer.CheckSpace"
+    fn advance(&self, n: usize) -> Result<&'a [u8]> {
+        let offset = self.get_offset();
+        let needed_size = offset.saturating_add(n);
+        let bytes_n = self.bytes.len();
+        if needed_size > bytes_n {
+            return Err(Error::Other {
+                message: format!(
+                    "bad length to read; offset + bytes ({}) to read exceeds current total bytes size {}",
+                    needed_size,
+                    bytes_n
+                ), // ref. "errBadLength"
+                retryable: false,
+            });
+        }
+        self.offset.set(needed_size);
+        Ok(&self.bytes[offset..needed_size])
+    }
+
+    /// Unpacks the byte in the "offset" position.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackByte"
+    pub fn unpack_byte(&self) -> Result<u8> {
+        let b = self.advance(BYTE_LEN)?;
+        Ok(b[0])
+    }
+
+    /// Unpacks the u16 in the "offset" position.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackShort"
+    pub fn unpack_u16(&self) -> Result<u16> {
+        let b = self.advance(U16_LEN)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    /// Unpacks the u32 in the "offset" position.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackInt"
+    pub fn unpack_u32(&self) -> Result<u32> {
+        let b = self.advance(U32_LEN)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    /// Unpacks the u64 in the "offset" position.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackLong"
+    pub fn unpack_u64(&self) -> Result<u64> {
+        let b = self.advance(U64_LEN)?;
+        Ok(u64::from_be_bytes([
+            b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7],
+        ]))
+    }
+
+    /// Unpacks the bool in the "offset" position.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackBool"
+    pub fn unpack_bool(&self) -> Result<bool> {
+        let b = self.advance(BOOL_LEN)?;
+        match b[0] {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => {
+                Err(Error::Other {
+                    message: "unexpected value when unpacking bool".to_string(), // ref. "errBadBool"
+                    retryable: false,
+                })
+            }
+        }
+    }
+
+    /// Unpacks the "u8" fixed-size array from the "offset" position,
+    /// borrowing from the underlying bytes.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackFixedBytes"
+    pub fn unpack_bytes(&self, n: usize) -> Result<&'a [u8]> {
+        self.advance(n)
+    }
+
+    /// Unpacks the "u8" slice prefixed with the 4-byte length,
+    /// borrowing from the underlying bytes.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackBytes"
+    pub fn unpack_bytes_with_header(&self) -> Result<&'a [u8]> {
+        let n = self.unpack_u32()?;
+        self.unpack_bytes(n as usize)
+    }
+
+    /// Unpacks the two-dimensional "u8" slice of the fixed-size "n",
+    /// borrowing from the underlying bytes.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackFixedByteSlices"
+    pub fn unpack_2d_bytes(&self, n: usize) -> Result<Vec<&'a [u8]>> {
+        let total = self.unpack_u32()?;
+        let mut rs: Vec<&'a [u8]> = Vec::new();
+        for _ in 0..total {
+            rs.push(self.unpack_bytes(n)?);
+        }
+        Ok(rs)
+    }
+
+    /// Unpacks the two-dimensional "u8" slice with the length headers,
+    /// borrowing from the underlying bytes.
+    /// ref. "avalanchego/utils/wrappers.Packer.Unpack2DByteSlice"
+    pub fn unpack_2d_bytes_with_header(&self) -> Result<Vec<&'a [u8]>> {
+        let total = self.unpack_u32()?;
+        let mut rs: Vec<&'a [u8]> = Vec::new();
+        for _ in 0..total {
+            rs.push(self.unpack_bytes_with_header()?);
+        }
+        Ok(rs)
+    }
+
+    /// Unpacks str from the offset, borrowing from the underlying bytes.
+    /// Unlike Go "UnpackStr", it does not deep-copy the bytes.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackStr"
+    pub fn unpack_str(&self) -> Result<&'a str> {
+        let n = self.unpack_u16()?;
+        let d = self.unpack_bytes(n as usize)?;
+        // same message as "Packer::unpack_str", whose "FromUtf8Error"
+        // displays the same as "Utf8Error"
+        std::str::from_utf8(d).map_err(|e| Error::Other {
+            message: format!("failed String::from_utf8 {}", e),
+            retryable: false,
+        })
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- packer::reader::test_reader --exact --show-output
+#[test]
+fn test_reader() {
+    use crate::packer::Packer;
+
+    let packer = Packer::new(1024, 0);
+    packer.pack_byte(0x01).unwrap();
+    packer.pack_u16(0x0203).unwrap();
+    packer.pack_u32(0x04050607).unwrap();
+    packer.pack_u64(0x08090a0b0c0d0e0f).unwrap();
+    packer.pack_bool(true).unwrap();
+    packer.pack_bytes(&[0x10, 0x11]).unwrap();
+    packer.pack_bytes_with_header(&[0x12, 0x13, 0x14]).unwrap();
+    packer
+        .pack_2d_bytes(vec![vec![0x15, 0x16], vec![0x17, 0x18]])
+        .unwrap();
+    packer
+        .pack_2d_bytes_with_header(vec![vec![0x19], vec![0x1a, 0x1b]])
+        .unwrap();
+    packer.pack_str("avax").unwrap();
+    let b = packer.take_bytes();
+
+    let reader = PackerReader::new(1024, &b).unwrap();
+    assert_eq!(reader.unpack_byte().unwrap(), 0x01);
+    assert_eq!(reader.unpack_u16().unwrap(), 0x0203);
+    assert_eq!(reader.unpack_u32().unwrap(), 0x04050607);
+    assert_eq!(reader.unpack_u64().unwrap(), 0x08090a0b0c0d0e0f);
+    assert!(reader.unpack_bool().unwrap());
+    assert_eq!(reader.unpack_bytes(2).unwrap(), &[0x10, 0x11]);
+    assert_eq!(
+        reader.unpack_bytes_with_header().unwrap(),
+        &[0x12, 0x13, 0x14]
+    );
+    assert_eq!(
+        reader.unpack_2d_bytes(2).unwrap(),
+        vec![&[0x15, 0x16][..], &[0x17, 0x18][..]]
+    );
+    assert_eq!(
+        reader.unpack_2d_bytes_with_header().unwrap(),
+        vec![&[0x19][..], &[0x1a, 0x1b][..]]
+    );
+    assert_eq!(reader.unpack_str().unwrap(), "avax");
+    assert_eq!(reader.remaining(), 0);
+    assert_eq!(reader.read_bytes(), &b[..]);
+
+    // the slices are borrowed from the input, not copied
+    let reader = PackerReader::new(1024, &b).unwrap();
+    reader.unpack_bytes(1 + 2 + 4 + 8 + 1).unwrap();
+    let borrowed = reader.unpack_bytes(2).unwrap();
+    assert_eq!(borrowed.as_ptr(), b[16..].as_ptr());
+
+    // same bounds checks as "Packer"
+    let reader = PackerReader::new(1024, &b[..3]).unwrap();
+    assert_eq!(reader.unpack_byte().unwrap(), 0x01);
+    let err = reader.unpack_u32().unwrap_err();
+    let packer = Packer::load_bytes_for_unpack(1024, &b[..3]);
+    packer.unpack_byte().unwrap();
+    assert_eq!(err.message(), packer.unpack_u32().unwrap_err().message());
+    // failed read does not advance the offset
+    assert_eq!(reader.get_offset(), 1);
+    assert_eq!(reader.unpack_u16().unwrap(), 0x0203);
+
+    let reader = PackerReader::new(1024, &[0x02]).unwrap();
+    assert!(reader.unpack_bool().is_err());
+
+    let invalid_str = [0x00, 0x02, 0xc3, 0x28];
+    let reader = PackerReader::new(1024, &invalid_str).unwrap();
+    let packer = Packer::load_bytes_for_unpack(1024, &invalid_str);
+    assert_eq!(
+        reader.unpack_str().unwrap_err().message(),
+        packer.unpack_str().unwrap_err().message()
+    );
+
+    let reader = PackerReader::new(1024, &[0x00, 0x00, 0x00, 0xff]).unwrap();
+    assert!(reader.unpack_bytes_with_header().is_err());
+
+    assert!(PackerReader::new(2, &b).is_err());
+}
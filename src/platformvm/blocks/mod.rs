@@ -0,0 +1,578 @@
+use crate::{
+    codec,
+    errors::{Error, Result},
+    formatting, hash, ids, packer,
+    platformvm::txs::SignedTx,
+};
+
+/// Fields shared by all P-chain blocks.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#CommonBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CommonBlock {
+    pub parent_id: ids::Id,
+    pub height: u64,
+
+    /// Block ID computed from the block bytes (not serialized).
+    pub id: ids::Id,
+    /// Block bytes with the codec version (not serialized).
+    pub bytes: Vec<u8>,
+}
+
+impl Default for CommonBlock {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl CommonBlock {
+    pub fn default() -> Self {
+        Self {
+            parent_id: ids::Id::empty(),
+            height: 0,
+            id: ids::Id::empty(),
+            bytes: Vec::new(),
+        }
+    }
+}
+
+/// Proposes a single tx (e.g., "AdvanceTimeTx" or "RewardValidatorTx")
+/// followed by either a commit or an abort block.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotProposalBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ApricotProposalBlock {
+    pub common: CommonBlock,
+    pub tx: SignedTx,
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotAbortBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ApricotAbortBlock {
+    pub common: CommonBlock,
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotCommitBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ApricotCommitBlock {
+    pub common: CommonBlock,
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotStandardBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ApricotStandardBlock {
+    pub common: CommonBlock,
+    pub txs: Vec<SignedTx>,
+}
+
+/// Carries a single atomic tx (i.e., "ImportTx" or "ExportTx").
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotAtomicBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ApricotAtomicBlock {
+    pub common: CommonBlock,
+    pub tx: SignedTx,
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#BanffProposalBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BanffProposalBlock {
+    /// Unix time of the block.
+    pub time: u64,
+    /// Decision txs issued before the proposal tx.
+    pub transactions: Vec<SignedTx>,
+    pub common: CommonBlock,
+    pub tx: SignedTx,
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#BanffAbortBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BanffAbortBlock {
+    /// Unix time of the block.
+    pub time: u64,
+    pub common: CommonBlock,
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#BanffCommitBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BanffCommitBlock {
+    /// Unix time of the block.
+    pub time: u64,
+    pub common: CommonBlock,
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#BanffStandardBlock>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BanffStandardBlock {
+    /// Unix time of the block.
+    pub time: u64,
+    pub common: CommonBlock,
+    pub txs: Vec<SignedTx>,
+}
+
+/// P-chain block decoded from its binary encoding,
+/// dispatched by the type ID of the block.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#Block>
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.11/vms/platformvm/blocks/codec.go>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Block {
+    ApricotProposal(ApricotProposalBlock),
+    ApricotAbort(ApricotAbortBlock),
+    ApricotCommit(ApricotCommitBlock),
+    ApricotStandard(ApricotStandardBlock),
+    ApricotAtomic(ApricotAtomicBlock),
+    BanffProposal(BanffProposalBlock),
+    BanffAbort(BanffAbortBlock),
+    BanffCommit(BanffCommitBlock),
+    BanffStandard(BanffStandardBlock),
+}
+
+impl Block {
+    /// Parses the raw hex-encoded data from the "platform.getBlock" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        // ref. "utils/formatting.encode" prepends "0x" for "Hex" encoding
+        let d = d.trim_start_matches("0x");
+
+        let decoded =
+            formatting::decode_hex_with_checksum(d.as_bytes()).map_err(|e| Error::Other {
+                message: format!("failed formatting::decode_hex_with_checksum '{}'", e),
+                retryable: false,
+            })?;
+        Self::unpack(&decoded)
+    }
+
+    /// Parses the block bytes, reading the codec version and
+    /// the type ID to decide the underlying block type.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
+
+        let codec_version = packer.unpack_u16()?;
+        if codec_version != codec::VERSION {
+            return Err(Error::Other {
+                message: format!("unknown codec version {}", codec_version), // ref. "errUnknownVersion"
+                retryable: false,
+            });
+        }
+        let type_id = packer.unpack_u32()?;
+
+        // the embedded txs share the block codec version thus not prefixed with it,
+        // so the txs are unpacked from the copy of the block bytes
+        // where the version is written right before each tx
+        let mut txs_buf = d.to_vec();
+
+        let blk = match type_id {
+            // "platformvm.ProposalBlock"
+            0 => {
+                let common = unpack_common(&packer, d)?;
+                let tx = unpack_tx(&packer, &mut txs_buf)?;
+                Self::ApricotProposal(ApricotProposalBlock { common, tx })
+            }
+            // "platformvm.AbortBlock"
+            1 => {
+                let common = unpack_common(&packer, d)?;
+                Self::ApricotAbort(ApricotAbortBlock { common })
+            }
+            // "platformvm.CommitBlock"
+            2 => {
+                let common = unpack_common(&packer, d)?;
+                Self::ApricotCommit(ApricotCommitBlock { common })
+            }
+            // "platformvm.StandardBlock"
+            3 => {
+                let common = unpack_common(&packer, d)?;
+                let txs = unpack_txs(&packer, &mut txs_buf)?;
+                Self::ApricotStandard(ApricotStandardBlock { common, txs })
+            }
+            // "platformvm.AtomicBlock"
+            4 => {
+                let common = unpack_common(&packer, d)?;
+                let tx = unpack_tx(&packer, &mut txs_buf)?;
+                Self::ApricotAtomic(ApricotAtomicBlock { common, tx })
+            }
+            // "platformvm.BanffProposalBlock"
+            29 => {
+                let time = packer.unpack_u64()?;
+                let transactions = unpack_txs(&packer, &mut txs_buf)?;
+                let common = unpack_common(&packer, d)?;
+                let tx = unpack_tx(&packer, &mut txs_buf)?;
+                Self::BanffProposal(BanffProposalBlock {
+                    time,
+                    transactions,
+                    common,
+                    tx,
+                })
+            }
+            // "platformvm.BanffAbortBlock"
+            30 => {
+                let time = packer.unpack_u64()?;
+                let common = unpack_common(&packer, d)?;
+                Self::BanffAbort(BanffAbortBlock { time, common })
+            }
+            // "platformvm.BanffCommitBlock"
+            31 => {
+                let time = packer.unpack_u64()?;
+                let common = unpack_common(&packer, d)?;
+                Self::BanffCommit(BanffCommitBlock { time, common })
+            }
+            // "platformvm.BanffStandardBlock"
+            32 => {
+                let time = packer.unpack_u64()?;
+                let common = unpack_common(&packer, d)?;
+                let txs = unpack_txs(&packer, &mut txs_buf)?;
+                Self::BanffStandard(BanffStandardBlock { time, common, txs })
+            }
+            _ => {
+                return Err(Error::UnexpectedTypeId {
+                    type_id,
+                    type_name: codec::p_type_name(type_id),
+                    interface: "blocks.Block".to_string(),
+                });
+            }
+        };
+
+        if packer.get_offset() != d.len() {
+            return Err(Error::Other {
+                message: format!(
+                    "unpacked {} bytes but block has {} bytes (extra space)",
+                    packer.get_offset(),
+                    d.len()
+                ), // ref. "errExtraSpace"
+                retryable: false,
+            });
+        }
+        Ok(blk)
+    }
+
+    /// Returns the fields shared by all blocks.
+    pub fn common(&self) -> &CommonBlock {
+        match self {
+            Self::ApricotProposal(blk) => &blk.common,
+            Self::ApricotAbort(blk) => &blk.common,
+            Self::ApricotCommit(blk) => &blk.common,
+            Self::ApricotStandard(blk) => &blk.common,
+            Self::ApricotAtomic(blk) => &blk.common,
+            Self::BanffProposal(blk) => &blk.common,
+            Self::BanffAbort(blk) => &blk.common,
+            Self::BanffCommit(blk) => &blk.common,
+            Self::BanffStandard(blk) => &blk.common,
+        }
+    }
+
+    /// Returns the block ID computed from the block bytes.
+    pub fn id(&self) -> ids::Id {
+        self.common().id
+    }
+
+    pub fn parent_id(&self) -> ids::Id {
+        self.common().parent_id
+    }
+
+    pub fn height(&self) -> u64 {
+        self.common().height
+    }
+
+    /// Returns the block bytes with the codec version.
+    pub fn bytes(&self) -> &[u8] {
+        &self.common().bytes
+    }
+
+    /// Returns the block timestamp in unix seconds.
+    /// Only Banff blocks encode the timestamp, and "None" for Apricot blocks,
+    /// whose chain time is advanced with "AdvanceTimeTx" instead.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#BanffBlock>
+    pub fn timestamp(&self) -> Option<u64> {
+        match self {
+            Self::BanffProposal(blk) => Some(blk.time),
+            Self::BanffAbort(blk) => Some(blk.time),
+            Self::BanffCommit(blk) => Some(blk.time),
+            Self::BanffStandard(blk) => Some(blk.time),
+            _ => None,
+        }
+    }
+
+    /// Returns the txs in the block, with the proposal tx at last (if any).
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#Block>
+    pub fn txs(&self) -> Vec<&SignedTx> {
+        match self {
+            Self::ApricotProposal(blk) => vec![&blk.tx],
+            Self::ApricotAbort(_) | Self::ApricotCommit(_) => Vec::new(),
+            Self::ApricotStandard(blk) => blk.txs.iter().collect(),
+            Self::ApricotAtomic(blk) => vec![&blk.tx],
+            Self::BanffProposal(blk) => {
+                let mut txs: Vec<&SignedTx> = blk.transactions.iter().collect();
+                txs.push(&blk.tx);
+                txs
+            }
+            Self::BanffAbort(_) | Self::BanffCommit(_) => Vec::new(),
+            Self::BanffStandard(blk) => blk.txs.iter().collect(),
+        }
+    }
+}
+
+/// Unpacks the parent ID and the height, and initializes the block ID.
+/// ref. "avalanchego/vms/platformvm/blocks.CommonBlock.initialize"
+fn unpack_common(packer: &packer::Packer, d: &[u8]) -> Result<CommonBlock> {
+    let parent_id = packer.unpack_bytes(ids::LEN)?;
+    let parent_id = ids::Id::from_slice(&parent_id);
+    let height = packer.unpack_u64()?;
+
+    let id = hash::sha256(d);
+    Ok(CommonBlock {
+        parent_id,
+        height,
+        id: ids::Id::from_slice(&id),
+        bytes: d.to_vec(),
+    })
+}
+
+/// Unpacks the length-prefixed txs.
+fn unpack_txs(packer: &packer::Packer, txs_buf: &mut [u8]) -> Result<Vec<SignedTx>> {
+    let n = packer.unpack_u32()?;
+
+    // do not pre-allocate with the untrusted length
+    let mut txs: Vec<SignedTx> = Vec::new();
+    for _ in 0..n {
+        txs.push(unpack_tx(packer, txs_buf)?);
+    }
+    Ok(txs)
+}
+
+/// Unpacks the tx at the current offset, so that its ID is computed
+/// with the codec version just as "txs.Tx.Initialize" re-marshals the tx.
+fn unpack_tx(packer: &packer::Packer, txs_buf: &mut [u8]) -> Result<SignedTx> {
+    // the block header already consumed more than the version length,
+    // and those bytes are no longer needed
+    let offset = packer.get_offset();
+    let start = offset - 2;
+    txs_buf[start..offset].copy_from_slice(&codec::VERSION.to_be_bytes());
+
+    let tx = SignedTx::unpack_prefix(&txs_buf[start..])?;
+    let tx_len = match tx.metadata() {
+        Some(metadata) => metadata.tx_bytes_with_signatures.len(),
+        None => {
+            return Err(Error::Other {
+                message: "unexpected None metadata for the unpacked tx".to_string(),
+                retryable: false,
+            });
+        }
+    };
+
+    // skip the tx bytes that are already unpacked
+    packer.unpack_bytes(tx_len - 2)?;
+    Ok(tx)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::blocks::test_block_unpack --exact --show-output
+#[test]
+fn test_block_unpack() {
+    use crate::{
+        ids::short,
+        key,
+        platformvm::txs::{advance_time, create_subnet, import, reward_validator},
+        txs,
+    };
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN",
+    )
+    .expect("failed to load private key");
+    let test_key_short_addr = short::Id::from_slice(
+        &test_key
+            .to_public_key()
+            .to_short_bytes()
+            .expect("failed to_short_bytes"),
+    );
+
+    let mut create_subnet_tx_1 = create_subnet::Tx {
+        base_tx: txs::Tx {
+            network_id: 1337,
+            memo: Some(vec![0x01]),
+            ..txs::Tx::default()
+        },
+        owner: key::secp256k1::txs::OutputOwners::new(0, 1, &[test_key_short_addr]),
+        ..create_subnet::Tx::default()
+    };
+    ab!(create_subnet_tx_1.sign(vec![vec![test_key.clone()]])).expect("failed to sign");
+    let mut create_subnet_tx_2 = create_subnet::Tx {
+        base_tx: txs::Tx {
+            network_id: 1337,
+            memo: Some(vec![0x02, 0x03]),
+            ..txs::Tx::default()
+        },
+        ..create_subnet_tx_1.clone()
+    };
+    ab!(create_subnet_tx_2.sign(vec![vec![test_key]])).expect("failed to sign");
+    let mut import_tx = import::Tx {
+        base_tx: txs::Tx {
+            network_id: 1337,
+            ..txs::Tx::default()
+        },
+        source_chain_id: ids::Id::from_slice(&[0x09]),
+        ..import::Tx::default()
+    };
+    ab!(import_tx.sign(Vec::<Vec<key::secp256k1::private_key::Key>>::new()))
+        .expect("failed to sign");
+    let mut advance_time_tx = advance_time::Tx::new(1_669_000_000);
+    advance_time_tx.initialize().unwrap();
+    let mut reward_validator_tx = reward_validator::Tx::new(create_subnet_tx_1.tx_id());
+    reward_validator_tx.initialize().unwrap();
+
+    // embedded txs are encoded without the codec version
+    let tx_bytes = |tx: &SignedTx| -> Vec<u8> {
+        tx.metadata().unwrap().tx_bytes_with_signatures[2..].to_vec()
+    };
+    let create_subnet_tx_1 = SignedTx::CreateSubnet(create_subnet_tx_1);
+    let create_subnet_tx_2 = SignedTx::CreateSubnet(create_subnet_tx_2);
+    let import_tx = SignedTx::Import(import_tx);
+    let advance_time_tx = SignedTx::AdvanceTime(advance_time_tx);
+    let reward_validator_tx = SignedTx::RewardValidator(reward_validator_tx);
+
+    let parent_id = ids::Id::from_slice(&[0x0a, 0x0b, 0x0c]);
+    let new_packer = |type_id: u32| {
+        let packer = packer::Packer::new(1024, 0);
+        packer.pack_u16(codec::VERSION).unwrap();
+        packer.pack_u32(type_id).unwrap();
+        packer
+    };
+    let pack_common = |packer: &packer::Packer, height: u64| {
+        packer.pack_bytes(parent_id.as_ref()).unwrap();
+        packer.pack_u64(height).unwrap();
+    };
+
+    // "platformvm.ProposalBlock" with "platformvm.AdvanceTimeTx"
+    let packer = new_packer(0);
+    pack_common(&packer, 10);
+    packer.pack_bytes(&tx_bytes(&advance_time_tx)).unwrap();
+    let b = packer.take_bytes();
+    let blk = Block::unpack(&b).unwrap();
+    assert_eq!(
+        blk,
+        Block::ApricotProposal(ApricotProposalBlock {
+            common: CommonBlock {
+                parent_id,
+                height: 10,
+                id: ids::Id::from_slice(&hash::sha256(&b)),
+                bytes: b.to_vec(),
+            },
+            tx: advance_time_tx.clone(),
+        })
+    );
+    assert_eq!(blk.id(), ids::Id::from_slice(&hash::sha256(&b)));
+    assert_eq!(blk.parent_id(), parent_id);
+    assert_eq!(blk.height(), 10);
+    assert_eq!(blk.bytes(), &b[..]);
+    assert!(blk.timestamp().is_none());
+    assert_eq!(blk.txs()[0].tx_id(), advance_time_tx.tx_id());
+
+    // "platformvm.AbortBlock" and "platformvm.CommitBlock"
+    for type_id in [1, 2] {
+        let packer = new_packer(type_id);
+        pack_common(&packer, 11);
+        let b = packer.take_bytes();
+        let blk = Block::unpack(&b).unwrap();
+        match (type_id, &blk) {
+            (1, Block::ApricotAbort(_)) | (2, Block::ApricotCommit(_)) => {}
+            _ => panic!("unexpected block {:?}", blk),
+        }
+        assert_eq!(blk.height(), 11);
+        assert!(blk.txs().is_empty());
+    }
+
+    // "platformvm.StandardBlock" with two txs
+    let packer = new_packer(3);
+    pack_common(&packer, 12);
+    packer.pack_u32(2).unwrap();
+    packer.pack_bytes(&tx_bytes(&create_subnet_tx_1)).unwrap();
+    packer.pack_bytes(&tx_bytes(&create_subnet_tx_2)).unwrap();
+    let b = packer.take_bytes();
+    let blk = Block::unpack(&b).unwrap();
+    assert!(matches!(blk, Block::ApricotStandard(_)));
+    assert_eq!(blk.txs(), vec![&create_subnet_tx_1, &create_subnet_tx_2]);
+    assert_eq!(blk.txs()[1].tx_id(), create_subnet_tx_2.tx_id());
+
+    // "platformvm.AtomicBlock"
+    let packer = new_packer(4);
+    pack_common(&packer, 13);
+    packer.pack_bytes(&tx_bytes(&import_tx)).unwrap();
+    let b = packer.take_bytes();
+    let blk = Block::unpack(&b).unwrap();
+    assert!(matches!(blk, Block::ApricotAtomic(_)));
+    assert_eq!(blk.txs(), vec![&import_tx]);
+
+    // "platformvm.BanffProposalBlock" with "platformvm.RewardValidatorTx"
+    let packer = new_packer(29);
+    packer.pack_u64(1_669_000_001).unwrap();
+    packer.pack_u32(1).unwrap();
+    packer.pack_bytes(&tx_bytes(&create_subnet_tx_1)).unwrap();
+    pack_common(&packer, 14);
+    packer.pack_bytes(&tx_bytes(&reward_validator_tx)).unwrap();
+    let b = packer.take_bytes();
+    let blk = Block::unpack(&b).unwrap();
+    assert_eq!(
+        blk,
+        Block::BanffProposal(BanffProposalBlock {
+            time: 1_669_000_001,
+            transactions: vec![create_subnet_tx_1.clone()],
+            common: CommonBlock {
+                parent_id,
+                height: 14,
+                id: ids::Id::from_slice(&hash::sha256(&b)),
+                bytes: b.to_vec(),
+            },
+            tx: reward_validator_tx.clone(),
+        })
+    );
+    assert_eq!(blk.timestamp(), Some(1_669_000_001));
+    assert_eq!(blk.txs(), vec![&create_subnet_tx_1, &reward_validator_tx]);
+
+    // "platformvm.BanffAbortBlock" and "platformvm.BanffCommitBlock"
+    for type_id in [30, 31] {
+        let packer = new_packer(type_id);
+        packer.pack_u64(1_669_000_002).unwrap();
+        pack_common(&packer, 15);
+        let b = packer.take_bytes();
+        let blk = Block::unpack(&b).unwrap();
+        match (type_id, &blk) {
+            (30, Block::BanffAbort(_)) | (31, Block::BanffCommit(_)) => {}
+            _ => panic!("unexpected block {:?}", blk),
+        }
+        assert_eq!(blk.timestamp(), Some(1_669_000_002));
+        assert_eq!(blk.parent_id(), parent_id);
+        assert_eq!(blk.height(), 15);
+    }
+
+    // "platformvm.BanffStandardBlock"
+    let packer = new_packer(32);
+    packer.pack_u64(1_669_000_003).unwrap();
+    pack_common(&packer, 16);
+    packer.pack_u32(2).unwrap();
+    packer.pack_bytes(&tx_bytes(&import_tx)).unwrap();
+    packer.pack_bytes(&tx_bytes(&create_subnet_tx_2)).unwrap();
+    let b = packer.take_bytes();
+    let blk = Block::unpack(&b).unwrap();
+    assert!(matches!(blk, Block::BanffStandard(_)));
+    assert_eq!(blk.timestamp(), Some(1_669_000_003));
+    assert_eq!(blk.txs(), vec![&import_tx, &create_subnet_tx_2]);
+
+    let blk_hex = format!("0x{}", formatting::encode_hex_with_checksum(&b));
+    assert_eq!(Block::from_hex(&blk_hex).unwrap(), blk);
+
+    // trailing bytes are rejected
+    let mut extra = b.to_vec();
+    extra.push(0x00);
+    assert!(Block::unpack(&extra).is_err());
+
+    // truncated tx is rejected
+    assert!(Block::unpack(&b[..b.len() - 1]).is_err());
+
+    // "platformvm.AddValidatorTx" is not a block
+    let mut unknown = b.to_vec();
+    unknown[2..6].copy_from_slice(&12_u32.to_be_bytes());
+    assert!(Block::unpack(&unknown)
+        .unwrap_err()
+        .contains("platformvm.AddValidatorTx"));
+}
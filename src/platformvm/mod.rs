@@ -1,3 +1,4 @@
+pub mod blocks;
 pub mod txs;
 
 use crate::ids;
@@ -90,6 +90,83 @@ impl Tx {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let tx = Self::unpack_prefix(d)?;
+        if let Some(metadata) = &tx.base_tx.metadata {
+            metadata.check_extra_space(d)?;
+        }
+        Ok(tx)
+    }
+
+    /// Unpacks the signed tx from the beginning of the bytes,
+    /// ignoring the trailing bytes (e.g., the rest of the block).
+    pub fn unpack_prefix(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        // unpack the second field "validator" in the struct
+        let validator = packer.unpack_validator()?;
+
+        // unpack the third field "subnet_id" in the struct
+        let subnet_id = packer.unpack_bytes(ids::LEN)?;
+        let subnet_id = ids::Id::from_slice(&subnet_id);
+
+        // unpack the fourth field "signer" in the struct
+        // "signer.Signer" is an interface, thus prefixed with type ID
+        let type_id_signer = packer.unpack_u32()?;
+        let signer = match type_id_signer {
+            27 => None,
+            28 => {
+                let public_key = packer.unpack_bytes(key::bls::public_key::LEN)?;
+                let proof_of_possession = packer.unpack_bytes(key::bls::signature::LEN)?;
+                Some(key::bls::ProofOfPossession {
+                    public_key,
+                    proof_of_possession,
+                    ..Default::default()
+                })
+            }
+            _ => {
+                return Err(Error::UnexpectedTypeId {
+                    type_id: type_id_signer,
+                    type_name: codec::p_type_name(type_id_signer),
+                    interface: "signer.Signer".to_string(),
+                });
+            }
+        };
+
+        // unpack the fifth field "stake" in the struct
+        let stake_transferable_outputs = packer.unpack_transferable_outputs()?;
+
+        // unpack the sixth and seventh fields "rewards_owner" in the struct
+        let validator_rewards_owner = packer.unpack_owner()?;
+        let delegator_rewards_owner = packer.unpack_owner()?;
+
+        // unpack the eighth field "delegation_shares" in the struct
+        let delegation_shares = packer.unpack_u32()?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let creds = packer.unpack_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked_prefix(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        ));
+
+        Ok(Self {
+            base_tx,
+            validator,
+            subnet_id,
+            signer,
+            stake_transferable_outputs,
+            validator_rewards_owner,
+            delegator_rewards_owner,
+            delegation_shares,
+            creds,
+        })
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly + Clone>(
@@ -672,4 +749,8 @@ fn test_add_permissionless_validator_tx_serialization_with_one_signer() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
 }
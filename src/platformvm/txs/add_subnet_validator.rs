@@ -82,6 +82,49 @@ impl Tx {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let tx = Self::unpack_prefix(d)?;
+        if let Some(metadata) = &tx.base_tx.metadata {
+            metadata.check_extra_space(d)?;
+        }
+        Ok(tx)
+    }
+
+    /// Unpacks the signed tx from the beginning of the bytes,
+    /// ignoring the trailing bytes (e.g., the rest of the block).
+    pub fn unpack_prefix(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        // unpack the second field "validator" in the struct
+        let validator = packer.unpack_validator()?;
+        let subnet_id = packer.unpack_bytes(ids::LEN)?;
+        let subnet_id = ids::Id::from_slice(&subnet_id);
+
+        // unpack the third field "subnet_auth" in the struct
+        let subnet_auth = packer.unpack_subnet_auth()?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let creds = packer.unpack_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked_prefix(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        ));
+
+        Ok(Self {
+            base_tx,
+            validator: Validator {
+                validator,
+                subnet_id,
+            },
+            subnet_auth,
+            creds,
+        })
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
@@ -426,4 +469,8 @@ fn test_add_subnet_validator_tx_serialization_with_one_signer() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
 }
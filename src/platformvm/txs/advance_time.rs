@@ -0,0 +1,180 @@
+use crate::{
+    codec,
+    errors::{Error, Result},
+    ids, key, packer, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Proposes to advance the chain time, only issued by the block builder
+/// in an Apricot proposal block, thus never signed.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#AdvanceTimeTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// Unix time this block proposes increasing the chain time to.
+    pub time: u64,
+
+    /// Only non-empty once the tx is initialized or unpacked.
+    #[serde(skip)]
+    pub metadata: Option<txs::Metadata>, // skip serialization due to serialize:"false"
+
+    /// Always empty since the tx is not signed.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            time: 0,
+            metadata: None,
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(time: u64) -> Self {
+        Self {
+            time,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with "initialize" or "unpack".
+    pub fn tx_id(&self) -> ids::Id {
+        if let Some(m) = &self.metadata {
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.AdvanceTimeTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Unpacks the tx bytes in the same order as "Tx::initialize",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let tx = Self::unpack_prefix(d)?;
+        if let Some(metadata) = &tx.metadata {
+            metadata.check_extra_space(d)?;
+        }
+        Ok(tx)
+    }
+
+    /// Unpacks the tx from the beginning of the bytes,
+    /// ignoring the trailing bytes (e.g., the rest of the block).
+    pub fn unpack_prefix(d: &[u8]) -> Result<Self> {
+        let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
+
+        let codec_version = packer.unpack_u16()?;
+        if codec_version != codec::VERSION {
+            return Err(Error::Other {
+                message: format!("unknown codec version {}", codec_version), // ref. "errUnknownVersion"
+                retryable: false,
+            });
+        }
+        let type_id = packer.unpack_u32()?;
+        if type_id != Self::type_id() {
+            return Err(Error::UnexpectedTypeId {
+                type_id,
+                type_name: None,
+                interface: format!("UnsignedTx (expected type ID {})", Self::type_id()),
+            });
+        }
+
+        // unpack the first field "time" in the struct
+        let time = packer.unpack_u64()?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let creds = packer.unpack_credentials()?;
+        let metadata =
+            txs::Metadata::from_unpacked_prefix(&packer, d, tx_bytes_with_no_signature_len);
+
+        Ok(Self {
+            time,
+            metadata: Some(metadata),
+            creds,
+        })
+    }
+
+    /// Packs the tx with no credential and updates the metadata.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Initialize>
+    pub fn initialize(&mut self) -> Result<()> {
+        let packer = packer::Packer::new(2 + 4 + 8 + 4, 0);
+
+        // marshal "unsigned tx" with the codec version
+        packer.pack_u16(codec::VERSION)?;
+        packer.pack_u32(Self::type_id())?;
+        packer.pack_u64(self.time)?;
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // no credential
+        self.creds = Vec::new();
+        packer.pack_u32(0)?;
+        let tx_bytes_with_signatures = packer.take_bytes();
+
+        self.metadata = Some(txs::Metadata::new(
+            &tx_bytes_with_no_signature,
+            &tx_bytes_with_signatures,
+        ));
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::advance_time::test_advance_time_tx_serialization --exact --show-output
+#[test]
+fn test_advance_time_tx_serialization() {
+    use crate::hash;
+
+    let mut tx = Tx::new(1_669_000_000);
+    assert!(tx.tx_id().is_empty());
+    tx.initialize().unwrap();
+
+    let tx_bytes_with_signatures = tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures
+        .to_vec();
+    let expected_signed_bytes: &[u8] = &[
+        // codec version
+        0x00, 0x00, //
+        //
+        // platformvm.AdvanceTimeTx type ID
+        0x00, 0x00, 0x00, 0x13, //
+        //
+        // time
+        0x00, 0x00, 0x00, 0x00, 0x63, 0x7a, 0xeb, 0x40, //
+        //
+        // number of credentials
+        0x00, 0x00, 0x00, 0x00, //
+    ];
+    assert_eq!(&tx_bytes_with_signatures, expected_signed_bytes);
+    assert_eq!(
+        tx.tx_id(),
+        ids::Id::from_slice(&hash::sha256(expected_signed_bytes))
+    );
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+
+    // trailing bytes are only allowed with "unpack_prefix"
+    let mut with_trailing = tx_bytes_with_signatures.clone();
+    with_trailing.extend_from_slice(&[0x01, 0x02]);
+    assert!(Tx::unpack(&with_trailing).is_err());
+    assert_eq!(Tx::unpack_prefix(&with_trailing).unwrap(), tx);
+}
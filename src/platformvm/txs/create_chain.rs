@@ -69,6 +69,66 @@ impl Tx {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let tx = Self::unpack_prefix(d)?;
+        if let Some(metadata) = &tx.base_tx.metadata {
+            metadata.check_extra_space(d)?;
+        }
+        Ok(tx)
+    }
+
+    /// Unpacks the signed tx from the beginning of the bytes,
+    /// ignoring the trailing bytes (e.g., the rest of the block).
+    pub fn unpack_prefix(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        let subnet_id = packer.unpack_bytes(ids::LEN)?;
+        let subnet_id = ids::Id::from_slice(&subnet_id);
+
+        let chain_name = packer.unpack_str()?;
+
+        let vm_id = packer.unpack_bytes(ids::LEN)?;
+        let vm_id = ids::Id::from_slice(&vm_id);
+
+        let fx_ids_len = packer.unpack_u32()?;
+        let fx_ids = if fx_ids_len == 0 {
+            None
+        } else {
+            let mut fx_ids: Vec<ids::Id> = Vec::new();
+            for _ in 0..fx_ids_len {
+                let fx_id = packer.unpack_bytes(ids::LEN)?;
+                fx_ids.push(ids::Id::from_slice(&fx_id));
+            }
+            Some(fx_ids)
+        };
+
+        let genesis_data = packer.unpack_bytes_with_header()?;
+
+        let subnet_auth = packer.unpack_subnet_auth()?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let creds = packer.unpack_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked_prefix(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        ));
+
+        Ok(Self {
+            base_tx,
+            subnet_id,
+            chain_name,
+            vm_id,
+            fx_ids,
+            genesis_data,
+            subnet_auth,
+            creds,
+        })
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash
     pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
@@ -649,4 +709,8 @@ fn test_create_chain_tx_serialization_with_one_signer() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
 }
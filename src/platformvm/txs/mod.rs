@@ -1,18 +1,26 @@
+pub mod add_delegator;
+pub mod add_permissionless_delegator;
 pub mod add_permissionless_validator;
 pub mod add_subnet_validator;
 pub mod add_validator;
+pub mod advance_time;
 pub mod create_chain;
 pub mod create_subnet;
 pub mod export;
 pub mod import;
+pub mod remove_subnet_validator;
+pub mod reward_validator;
 pub mod status;
+pub mod transform_subnet;
 
 use std::cmp::Ordering;
 
 use crate::{
     codec::{self, serde::hex_0x_bytes::Hex0xBytes},
+    errors::{Error, Result},
+    formatting,
     ids::{self, node},
-    key,
+    key, packer,
     txs::transferable,
 };
 use serde::{Deserialize, Serialize};
@@ -165,6 +173,277 @@ fn test_json_deserialize() {
     );
 }
 
+/// Signed P-chain transaction decoded from its binary encoding,
+/// dispatched by the type ID of the unsigned tx.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.11/vms/platformvm/txs/codec.go>
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SignedTx {
+    AddValidator(add_validator::Tx),
+    AddDelegator(add_delegator::Tx),
+    AddSubnetValidator(add_subnet_validator::Tx),
+    CreateChain(create_chain::Tx),
+    CreateSubnet(create_subnet::Tx),
+    Import(import::Tx),
+    Export(export::Tx),
+    RemoveSubnetValidator(remove_subnet_validator::Tx),
+    TransformSubnet(transform_subnet::Tx),
+    AddPermissionlessValidator(add_permissionless_validator::Tx),
+    AddPermissionlessDelegator(add_permissionless_delegator::Tx),
+    AdvanceTime(advance_time::Tx),
+    RewardValidator(reward_validator::Tx),
+}
+
+impl SignedTx {
+    /// Parses the raw hex-encoded data from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        // ref. "utils/formatting.encode" prepends "0x" for "Hex" encoding
+        let d = d.trim_start_matches("0x");
+
+        let decoded =
+            formatting::decode_hex_with_checksum(d.as_bytes()).map_err(|e| Error::Other {
+                message: format!("failed formatting::decode_hex_with_checksum '{}'", e),
+                retryable: false,
+            })?;
+        Self::unpack(&decoded)
+    }
+
+    /// Parses the signed tx bytes, reading the codec version and
+    /// the type ID to decide the underlying tx type.
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let tx = Self::unpack_prefix(d)?;
+        if let Some(metadata) = tx.metadata() {
+            metadata.check_extra_space(d)?;
+        }
+        Ok(tx)
+    }
+
+    /// Parses the signed tx from the beginning of the bytes,
+    /// ignoring the trailing bytes (e.g., the rest of the block).
+    pub fn unpack_prefix(d: &[u8]) -> Result<Self> {
+        let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
+        let _codec_version = packer.unpack_u16()?;
+        let type_id = packer.unpack_u32()?;
+
+        let tx = match type_id {
+            // "platformvm.AddValidatorTx"
+            12 => Self::AddValidator(add_validator::Tx::unpack_prefix(d)?),
+            // "platformvm.AddSubnetValidatorTx"
+            13 => Self::AddSubnetValidator(add_subnet_validator::Tx::unpack_prefix(d)?),
+            // "platformvm.AddDelegatorTx"
+            14 => Self::AddDelegator(add_delegator::Tx::unpack_prefix(d)?),
+            // "platformvm.CreateChainTx"
+            15 => Self::CreateChain(create_chain::Tx::unpack_prefix(d)?),
+            // "platformvm.CreateSubnetTx"
+            16 => Self::CreateSubnet(create_subnet::Tx::unpack_prefix(d)?),
+            // "platformvm.ImportTx"
+            17 => Self::Import(import::Tx::unpack_prefix(d)?),
+            // "platformvm.ExportTx"
+            18 => Self::Export(export::Tx::unpack_prefix(d)?),
+            // "platformvm.AdvanceTimeTx"
+            19 => Self::AdvanceTime(advance_time::Tx::unpack_prefix(d)?),
+            // "platformvm.RewardValidatorTx"
+            20 => Self::RewardValidator(reward_validator::Tx::unpack_prefix(d)?),
+            // "platformvm.RemoveSubnetValidatorTx"
+            23 => Self::RemoveSubnetValidator(remove_subnet_validator::Tx::unpack_prefix(d)?),
+            // "platformvm.TransformSubnetTx"
+            24 => Self::TransformSubnet(transform_subnet::Tx::unpack_prefix(d)?),
+            // "platformvm.AddPermissionlessValidatorTx"
+            25 => Self::AddPermissionlessValidator(
+                add_permissionless_validator::Tx::unpack_prefix(d)?,
+            ),
+            // "platformvm.AddPermissionlessDelegatorTx"
+            26 => Self::AddPermissionlessDelegator(
+                add_permissionless_delegator::Tx::unpack_prefix(d)?,
+            ),
+            _ => {
+                return Err(Error::UnexpectedTypeId {
+                    type_id,
+                    type_name: codec::p_type_name(type_id),
+                    interface: "txs.UnsignedTx".to_string(),
+                });
+            }
+        };
+        Ok(tx)
+    }
+
+    /// Returns the transaction ID computed from the signed tx bytes.
+    pub fn tx_id(&self) -> ids::Id {
+        match self {
+            Self::AddValidator(tx) => tx.tx_id(),
+            Self::AddDelegator(tx) => tx.tx_id(),
+            Self::AddSubnetValidator(tx) => tx.tx_id(),
+            Self::CreateChain(tx) => tx.tx_id(),
+            Self::CreateSubnet(tx) => tx.tx_id(),
+            Self::Import(tx) => tx.tx_id(),
+            Self::Export(tx) => tx.tx_id(),
+            Self::RemoveSubnetValidator(tx) => tx.tx_id(),
+            Self::TransformSubnet(tx) => tx.tx_id(),
+            Self::AddPermissionlessValidator(tx) => tx.tx_id(),
+            Self::AddPermissionlessDelegator(tx) => tx.tx_id(),
+            Self::AdvanceTime(tx) => tx.tx_id(),
+            Self::RewardValidator(tx) => tx.tx_id(),
+        }
+    }
+
+    /// Returns the metadata with the signed tx bytes, if initialized.
+    pub fn metadata(&self) -> Option<&crate::txs::Metadata> {
+        match self {
+            Self::AdvanceTime(tx) => tx.metadata.as_ref(),
+            Self::RewardValidator(tx) => tx.metadata.as_ref(),
+            _ => self.base_tx().and_then(|base_tx| base_tx.metadata.as_ref()),
+        }
+    }
+
+    /// Returns the embedded "avax.BaseTx", or "None" for the txs
+    /// without one (i.e., "AdvanceTimeTx" and "RewardValidatorTx").
+    pub fn base_tx(&self) -> Option<&crate::txs::Tx> {
+        match self {
+            Self::AddValidator(tx) => Some(&tx.base_tx),
+            Self::AddDelegator(tx) => Some(&tx.base_tx),
+            Self::AddSubnetValidator(tx) => Some(&tx.base_tx),
+            Self::CreateChain(tx) => Some(&tx.base_tx),
+            Self::CreateSubnet(tx) => Some(&tx.base_tx),
+            Self::Import(tx) => Some(&tx.base_tx),
+            Self::Export(tx) => Some(&tx.base_tx),
+            Self::RemoveSubnetValidator(tx) => Some(&tx.base_tx),
+            Self::TransformSubnet(tx) => Some(&tx.base_tx),
+            Self::AddPermissionlessValidator(tx) => Some(&tx.base_tx),
+            Self::AddPermissionlessDelegator(tx) => Some(&tx.base_tx),
+            Self::AdvanceTime(_) | Self::RewardValidator(_) => None,
+        }
+    }
+
+    /// Returns the credentials of the signed tx.
+    pub fn creds(&self) -> &[key::secp256k1::txs::Credential] {
+        match self {
+            Self::AddValidator(tx) => &tx.creds,
+            Self::AddDelegator(tx) => &tx.creds,
+            Self::AddSubnetValidator(tx) => &tx.creds,
+            Self::CreateChain(tx) => &tx.creds,
+            Self::CreateSubnet(tx) => &tx.creds,
+            Self::Import(tx) => &tx.creds,
+            Self::Export(tx) => &tx.creds,
+            Self::RemoveSubnetValidator(tx) => &tx.creds,
+            Self::TransformSubnet(tx) => &tx.creds,
+            Self::AddPermissionlessValidator(tx) => &tx.creds,
+            Self::AddPermissionlessDelegator(tx) => &tx.creds,
+            Self::AdvanceTime(tx) => &tx.creds,
+            Self::RewardValidator(tx) => &tx.creds,
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::test_signed_tx_unpack --exact --show-output
+#[test]
+fn test_signed_tx_unpack() {
+    use crate::{ids::short, txs};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN",
+    )
+    .expect("failed to load private key");
+    let test_key_short_addr = short::Id::from_slice(
+        &test_key
+            .to_public_key()
+            .to_short_bytes()
+            .expect("failed to_short_bytes"),
+    );
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, &[test_key_short_addr]);
+
+    // locked inputs are encoded with the nested "secp256k1fx.TransferInput" type ID
+    let mut tx = create_subnet::Tx {
+        base_tx: txs::Tx {
+            network_id: 1337,
+            transferable_outputs: Some(vec![transferable::Output {
+                asset_id: ids::Id::from_slice(&[1, 2, 3]),
+                stakeable_lock_out: Some(StakeableLockOut {
+                    locktime: 123,
+                    transfer_output: key::secp256k1::txs::transfer::Output::new(
+                        1000,
+                        owners.clone(),
+                    ),
+                }),
+                ..transferable::Output::default()
+            }]),
+            transferable_inputs: Some(vec![transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&[4, 5, 6]),
+                    output_index: 1,
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: ids::Id::from_slice(&[1, 2, 3]),
+                stakeable_lock_in: Some(StakeableLockIn {
+                    locktime: 123,
+                    transfer_input: key::secp256k1::txs::transfer::Input::new(2000, vec![0]),
+                }),
+                ..transferable::Input::default()
+            }]),
+            memo: Some(vec![0x01, 0x02]),
+            ..txs::Tx::default()
+        },
+        owner: owners,
+        ..create_subnet::Tx::default()
+    };
+    ab!(tx.sign(vec![vec![test_key]])).expect("failed to sign");
+    let tx_bytes_with_signatures = tx
+        .base_tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures;
+
+    let tx_hex = format!(
+        "0x{}",
+        formatting::encode_hex_with_checksum(&tx_bytes_with_signatures)
+    );
+    let signed_tx = SignedTx::from_hex(&tx_hex).unwrap();
+    assert_eq!(signed_tx, SignedTx::CreateSubnet(tx.clone()));
+    assert_eq!(signed_tx.tx_id(), tx.tx_id());
+    assert_eq!(signed_tx.creds().len(), 1);
+
+    // trailing bytes are rejected
+    let mut extra = tx_bytes_with_signatures.clone();
+    extra.push(0x00);
+    assert!(SignedTx::unpack(&extra).is_err());
+    // unless unpacked as a prefix (e.g., embedded in a block)
+    let prefix = SignedTx::unpack_prefix(&extra).unwrap();
+    assert_eq!(prefix, signed_tx);
+    assert_eq!(
+        prefix.metadata().unwrap().tx_bytes_with_signatures,
+        tx_bytes_with_signatures
+    );
+
+    // "platformvm.StakeableLockOut" is not an unsigned tx
+    let mut unknown = tx_bytes_with_signatures;
+    unknown[2..6].copy_from_slice(&22_u32.to_be_bytes());
+    assert!(SignedTx::unpack(&unknown)
+        .unwrap_err()
+        .contains("platformvm.StakeableLockOut"));
+
+    // "platformvm.AdvanceTimeTx" has no "avax.BaseTx"
+    let mut advance_time_tx = advance_time::Tx::new(1_669_000_000);
+    advance_time_tx.initialize().unwrap();
+    let signed_tx = SignedTx::unpack(
+        &advance_time_tx
+            .metadata
+            .clone()
+            .unwrap()
+            .tx_bytes_with_signatures,
+    )
+    .unwrap();
+    assert_eq!(signed_tx, SignedTx::AdvanceTime(advance_time_tx.clone()));
+    assert_eq!(signed_tx.tx_id(), advance_time_tx.tx_id());
+    assert!(signed_tx.base_tx().is_none());
+    assert!(signed_tx.creds().is_empty());
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn>
 #[derive(Debug, Serialize, Deserialize, Eq, Clone)]
 pub struct StakeableLockIn {
@@ -341,6 +620,24 @@ impl PartialEq for StakeableLockOut {
     }
 }
 
+/// Spent by "platformvm.StakeableLockIn" with the same locktime,
+/// regardless of whether the output is still locked (e.g., to stake
+/// the locked funds).
+impl key::secp256k1::keychain::Spendable for StakeableLockOut {
+    type Input = StakeableLockIn;
+
+    fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners {
+        &self.transfer_output.output_owners
+    }
+
+    fn spend_input(&self, sig_indices: Vec<u32>) -> Self::Input {
+        StakeableLockIn {
+            locktime: self.locktime,
+            transfer_input: self.transfer_output.spend_input(sig_indices),
+        }
+    }
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::test_sort_stakeable_lock_outs --exact --show-output
 #[test]
 fn test_sort_stakeable_lock_outs() {
@@ -541,3 +838,51 @@ impl Validator {
         }
     }
 }
+
+impl packer::Packer {
+    /// Unpacks the "platformvm.Validator" fields.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/validator#Validator>
+    pub fn unpack_validator(&self) -> Result<Validator> {
+        let node_id = self.unpack_bytes(node::LEN)?;
+        let node_id = node::Id::from_slice(&node_id);
+        let start = self.unpack_u64()?;
+        let end = self.unpack_u64()?;
+        let weight = self.unpack_u64()?;
+        Ok(Validator {
+            node_id,
+            start,
+            end,
+            weight,
+        })
+    }
+
+    /// Unpacks the "fx.Owner" interface field, which must be
+    /// "secp256k1fx.OutputOwners" prefixed with its type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/fx#Owner>
+    pub fn unpack_owner(&self) -> Result<key::secp256k1::txs::OutputOwners> {
+        let type_id_owner = self.unpack_u32()?;
+        if type_id_owner != key::secp256k1::txs::OutputOwners::type_id() {
+            return Err(Error::UnexpectedTypeId {
+                type_id: type_id_owner,
+                type_name: codec::p_type_name(type_id_owner),
+                interface: "fx.Owner".to_string(),
+            });
+        }
+        self.unpack_output_owners()
+    }
+
+    /// Unpacks the subnet auth "verify.Verifiable" interface field, which must be
+    /// "secp256k1fx.Input" prefixed with its type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#AddSubnetValidatorTx>
+    pub fn unpack_subnet_auth(&self) -> Result<key::secp256k1::txs::Input> {
+        let type_id_subnet_auth = self.unpack_u32()?;
+        if type_id_subnet_auth != key::secp256k1::txs::Input::type_id() {
+            return Err(Error::UnexpectedTypeId {
+                type_id: type_id_subnet_auth,
+                type_name: codec::p_type_name(type_id_subnet_auth),
+                interface: "verify.Verifiable".to_string(),
+            });
+        }
+        self.unpack_input()
+    }
+}
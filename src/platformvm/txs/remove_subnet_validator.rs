@@ -0,0 +1,336 @@
+use crate::{
+    codec,
+    errors::Result,
+    hash,
+    ids::{self, node},
+    key, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Removes a validator from the subnet, authorized by the subnet owner.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#RemoveSubnetValidatorTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+
+    /// Node Id of the validator to be removed.
+    #[serde(rename = "nodeID")]
+    pub node_id: node::Id,
+    /// ID of the subnet the validator is removed from.
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+    /// Proves that the issuer has the right to remove the validator,
+    /// same as "AddSubnetValidatorTx.SubnetAuth".
+    #[serde(rename = "subnetAuthorization")]
+    pub subnet_auth: key::secp256k1::txs::Input,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            node_id: node::Id::empty(),
+            subnet_id: ids::Id::empty(),
+            subnet_auth: key::secp256k1::txs::Input::default(),
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.RemoveSubnetValidatorTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let tx = Self::unpack_prefix(d)?;
+        if let Some(metadata) = &tx.base_tx.metadata {
+            metadata.check_extra_space(d)?;
+        }
+        Ok(tx)
+    }
+
+    /// Unpacks the signed tx from the beginning of the bytes,
+    /// ignoring the trailing bytes (e.g., the rest of the block).
+    pub fn unpack_prefix(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        // unpack the second field "node_id" in the struct
+        let node_id = packer.unpack_bytes(node::LEN)?;
+        let node_id = node::Id::from_slice(&node_id);
+
+        // unpack the third field "subnet_id" in the struct
+        let subnet_id = packer.unpack_bytes(ids::LEN)?;
+        let subnet_id = ids::Id::from_slice(&subnet_id);
+
+        // unpack the fourth field "subnet_auth" in the struct
+        let subnet_auth = packer.unpack_subnet_auth()?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let creds = packer.unpack_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked_prefix(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        ));
+
+        Ok(Self {
+            base_tx,
+            node_id,
+            subnet_id,
+            subnet_auth,
+            creds,
+        })
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        // marshal "unsigned tx" with the codec version
+        let type_id = Self::type_id();
+        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+
+        // "avalanchego" marshals the whole struct again for signed bytes
+        // even when the underlying "unsigned_tx" is already once marshaled
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
+        //
+        // reuse the underlying packer to avoid marshaling the unsigned tx twice
+        // just marshal the next fields in the struct and pack them all together
+        // in the existing packer
+        let unsigned_tx_bytes = packer.take_bytes();
+        packer.set_bytes(&unsigned_tx_bytes);
+
+        // pack the second field "node_id" in the struct
+        packer.pack_bytes(self.node_id.as_ref())?;
+
+        // pack the third field "subnet_id" in the struct
+        packer.pack_bytes(self.subnet_id.as_ref())?;
+
+        // pack the fourth field "subnet_auth" in the struct
+        let subnet_auth_type_id = key::secp256k1::txs::Input::type_id();
+        packer.pack_u32(subnet_auth_type_id)?;
+        packer.pack_u32(self.subnet_auth.sig_indices.len() as u32)?;
+        for sig_idx in self.subnet_auth.sig_indices.iter() {
+            packer.pack_u32(*sig_idx)?;
+        }
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // compute sha256 for marshaled "unsigned tx" bytes
+        // IMPORTANT: take the hash only for the type "platformvm.RemoveSubnetValidatorTx" unsigned tx
+        // not other fields -- only hash "platformvm.RemoveSubnetValidatorTx.*" but not "platformvm.Tx.Creds"
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#RemoveSubnetValidatorTx
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        // number of of credentials
+        let creds_len = signers.len() as u32;
+        // pack the fourth field in the struct
+        packer.pack_u32(creds_len)?;
+
+        // sign the hash with the signers (in case of multi-sig)
+        // and combine all signatures into a secp256k1fx credential
+        self.creds = Vec::new();
+        for keys in signers.iter() {
+            let mut sigs: Vec<Vec<u8>> = Vec::new();
+            for k in keys.iter() {
+                let sig = k.sign_digest(&tx_bytes_hash).await?;
+                sigs.push(Vec::from(sig));
+            }
+
+            let mut cred = key::secp256k1::txs::Credential::default();
+            cred.signatures = sigs;
+
+            // add a new credential to "Tx"
+            self.creds.push(cred);
+        }
+        if creds_len > 0 {
+            // pack each "cred" which is "secp256k1fx.Credential"
+            // marshal type ID for "secp256k1fx.Credential"
+            let cred_type_id = key::secp256k1::txs::Credential::type_id();
+            for cred in self.creds.iter() {
+                // marshal type ID for "secp256k1fx.Credential"
+                packer.pack_u32(cred_type_id)?;
+
+                // marshal fields for "secp256k1fx.Credential"
+                packer.pack_u32(cred.signatures.len() as u32)?;
+                for sig in cred.signatures.iter() {
+                    packer.pack_bytes(sig)?;
+                }
+            }
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
+        // ref. "avalanchego/vms/platformvm.Tx.Sign"
+        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
+        self.base_tx.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::remove_subnet_validator::test_remove_subnet_validator_tx_serialization_with_two_signers --exact --show-output
+#[test]
+fn test_remove_subnet_validator_tx_serialization_with_two_signers() {
+    use crate::{ids::short, platformvm};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let avax_asset_id = ids::Id::from_slice(&<Vec<u8>>::from([
+        0x88, 0xee, 0xc2, 0xe0, 0x99, 0xc6, 0xa5, 0x28, //
+        0xe6, 0x89, 0x61, 0x8e, 0x87, 0x21, 0xe0, 0x4a, //
+        0xe8, 0x5e, 0xa5, 0x74, 0xc7, 0xa1, 0x5a, 0x79, //
+        0x68, 0x64, 0x4d, 0x14, 0xd5, 0x47, 0x80, 0x14, //
+    ]));
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 1000000,
+            transferable_outputs: Some(vec![txs::transferable::Output {
+                asset_id: avax_asset_id,
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount: 0x2c6874d5c56f500,
+                    output_owners: key::secp256k1::txs::OutputOwners {
+                        locktime: 0x00,
+                        threshold: 0x01,
+                        addresses: vec![short::Id::from_slice(&<Vec<u8>>::from([
+                            0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+                            0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+                        ]))],
+                    },
+                }),
+                ..txs::transferable::Output::default()
+            }]),
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    output_index: 0,
+                    tx_id: ids::Id::from_slice(&[0x01; ids::LEN]),
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: avax_asset_id,
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 0x2c6874d62d06000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        node_id: node::Id::from_slice(&<Vec<u8>>::from([
+            0x9c, 0xd7, 0xb3, 0xe4, 0x79, 0x04, 0xf6, 0x7c, 0xc4, 0x8e, //
+            0xb5, 0xb9, 0xaf, 0xdb, 0x03, 0xe6, 0xd1, 0x8a, 0xcf, 0x6c, //
+        ])),
+        subnet_id: ids::Id::from_slice(&[0x07; ids::LEN]),
+        subnet_auth: key::secp256k1::txs::Input {
+            sig_indices: vec![0_u32],
+        },
+        ..Tx::default()
+    };
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key");
+    // one for the fee input, one for the subnet authorization
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> =
+        vec![vec![test_key.clone()], vec![test_key]];
+    ab!(tx.sign(signers)).expect("failed to sign");
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures.clone();
+    assert_eq!(
+        tx.tx_id(),
+        ids::Id::from_slice(&hash::sha256(&tx_bytes_with_signatures))
+    );
+
+    // "avax.BaseTx" with the "platformvm.RemoveSubnetValidatorTx" type ID
+    let mut expected_unsigned_bytes = tx
+        .base_tx
+        .pack(codec::VERSION, 23)
+        .unwrap()
+        .take_bytes()
+        .to_vec();
+    assert_eq!(&expected_unsigned_bytes[2..6], &[0x00, 0x00, 0x00, 0x17]);
+    expected_unsigned_bytes.extend_from_slice(&[
+        // node_id
+        0x9c, 0xd7, 0xb3, 0xe4, 0x79, 0x04, 0xf6, 0x7c, 0xc4, 0x8e, //
+        0xb5, 0xb9, 0xaf, 0xdb, 0x03, 0xe6, 0xd1, 0x8a, 0xcf, 0x6c, //
+    ]);
+    // subnet_id
+    expected_unsigned_bytes.extend_from_slice(&[0x07; ids::LEN]);
+    expected_unsigned_bytes.extend_from_slice(&[
+        // secp256k1fx.Input type ID
+        0x00, 0x00, 0x00, 0x0a, //
+        //
+        // "subnet_auth" sig_indices.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "subnet_auth" sig_indices[0]
+        0x00, 0x00, 0x00, 0x00, //
+    ]);
+    assert!(cmp_manager::eq_vectors(
+        &expected_unsigned_bytes,
+        &tx_metadata.tx_bytes_with_no_signature
+    ));
+
+    // two credentials, each with one 65-byte signature
+    assert_eq!(
+        tx_bytes_with_signatures.len(),
+        expected_unsigned_bytes.len() + 4 + 2 * (4 + 4 + 65)
+    );
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
+
+    let signed = platformvm::txs::SignedTx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(signed, platformvm::txs::SignedTx::RemoveSubnetValidator(tx));
+}
@@ -0,0 +1,182 @@
+use crate::{
+    codec,
+    errors::{Error, Result},
+    ids, key, packer, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Proposes to remove the staker whose staking period ended and to pay
+/// the rewards, only issued by the block builder in a proposal block,
+/// thus never signed.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#RewardValidatorTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// ID of the "AddValidatorTx", "AddDelegatorTx", or the permissionless
+    /// staker tx to remove from the current staker set.
+    #[serde(rename = "txID")]
+    pub staker_tx_id: ids::Id,
+
+    /// Only non-empty once the tx is initialized or unpacked.
+    #[serde(skip)]
+    pub metadata: Option<txs::Metadata>, // skip serialization due to serialize:"false"
+
+    /// Always empty since the tx is not signed.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            staker_tx_id: ids::Id::empty(),
+            metadata: None,
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(staker_tx_id: ids::Id) -> Self {
+        Self {
+            staker_tx_id,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with "initialize" or "unpack".
+    pub fn tx_id(&self) -> ids::Id {
+        if let Some(m) = &self.metadata {
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.RewardValidatorTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Unpacks the tx bytes in the same order as "Tx::initialize",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let tx = Self::unpack_prefix(d)?;
+        if let Some(metadata) = &tx.metadata {
+            metadata.check_extra_space(d)?;
+        }
+        Ok(tx)
+    }
+
+    /// Unpacks the tx from the beginning of the bytes,
+    /// ignoring the trailing bytes (e.g., the rest of the block).
+    pub fn unpack_prefix(d: &[u8]) -> Result<Self> {
+        let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
+
+        let codec_version = packer.unpack_u16()?;
+        if codec_version != codec::VERSION {
+            return Err(Error::Other {
+                message: format!("unknown codec version {}", codec_version), // ref. "errUnknownVersion"
+                retryable: false,
+            });
+        }
+        let type_id = packer.unpack_u32()?;
+        if type_id != Self::type_id() {
+            return Err(Error::UnexpectedTypeId {
+                type_id,
+                type_name: None,
+                interface: format!("UnsignedTx (expected type ID {})", Self::type_id()),
+            });
+        }
+
+        // unpack the first field "staker_tx_id" in the struct
+        let staker_tx_id = packer.unpack_bytes(ids::LEN)?;
+        let staker_tx_id = ids::Id::from_slice(&staker_tx_id);
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let creds = packer.unpack_credentials()?;
+        let metadata =
+            txs::Metadata::from_unpacked_prefix(&packer, d, tx_bytes_with_no_signature_len);
+
+        Ok(Self {
+            staker_tx_id,
+            metadata: Some(metadata),
+            creds,
+        })
+    }
+
+    /// Packs the tx with no credential and updates the metadata.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Initialize>
+    pub fn initialize(&mut self) -> Result<()> {
+        let packer = packer::Packer::new(2 + 4 + ids::LEN + 4, 0);
+
+        // marshal "unsigned tx" with the codec version
+        packer.pack_u16(codec::VERSION)?;
+        packer.pack_u32(Self::type_id())?;
+        packer.pack_bytes(self.staker_tx_id.as_ref())?;
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // no credential
+        self.creds = Vec::new();
+        packer.pack_u32(0)?;
+        let tx_bytes_with_signatures = packer.take_bytes();
+
+        self.metadata = Some(txs::Metadata::new(
+            &tx_bytes_with_no_signature,
+            &tx_bytes_with_signatures,
+        ));
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::reward_validator::test_reward_validator_tx_serialization --exact --show-output
+#[test]
+fn test_reward_validator_tx_serialization() {
+    use crate::hash;
+
+    let mut tx = Tx::new(ids::Id::from_slice(&[0x01, 0x02, 0x03]));
+    assert!(tx.tx_id().is_empty());
+    tx.initialize().unwrap();
+
+    let tx_bytes_with_signatures = tx
+        .metadata
+        .clone()
+        .unwrap()
+        .tx_bytes_with_signatures
+        .to_vec();
+    let expected_signed_bytes: &[u8] = &[
+        // codec version
+        0x00, 0x00, //
+        //
+        // platformvm.RewardValidatorTx type ID
+        0x00, 0x00, 0x00, 0x14, //
+        //
+        // staker tx ID
+        0x01, 0x02, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // number of credentials
+        0x00, 0x00, 0x00, 0x00, //
+    ];
+    assert_eq!(&tx_bytes_with_signatures, expected_signed_bytes);
+    assert_eq!(
+        tx.tx_id(),
+        ids::Id::from_slice(&hash::sha256(expected_signed_bytes))
+    );
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert!(Tx::unpack(&tx_bytes_with_signatures[..20]).is_err());
+}
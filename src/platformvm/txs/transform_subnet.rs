@@ -0,0 +1,698 @@
+use crate::{
+    codec,
+    errors::{Error, Result},
+    hash, ids, key, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Denominator for the consumption rates, delegation fee and uptime requirement
+/// (i.e., 1_000_000 represents 100%).
+/// ref. "avalanchego/vms/platformvm/reward.PercentDenominator"
+pub const PERCENT_DENOMINATOR: u64 = 1_000_000;
+
+/// Transforms a permissioned subnet into a permissionless (elastic) subnet,
+/// authorized by the subnet owner.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#TransformSubnetTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+
+    /// ID of the subnet to transform.
+    /// Must not be the primary network.
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+    /// Asset to use when staking on the subnet.
+    /// Must not be empty or AVAX.
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    /// Amount to initially specify as the current supply.
+    /// Must be non-zero.
+    pub initial_supply: u64,
+    /// Amount to specify as the maximum token supply.
+    /// Must be at least the initial supply.
+    pub maximum_supply: u64,
+    /// Range of the reward rates, denominated in "PERCENT_DENOMINATOR".
+    /// The min must be no greater than the max, and the max must be
+    /// no greater than "PERCENT_DENOMINATOR".
+    pub min_consumption_rate: u64,
+    pub max_consumption_rate: u64,
+    /// Minimum amount of the asset required to validate the subnet.
+    /// Must be non-zero and no greater than the initial supply.
+    pub min_validator_stake: u64,
+    /// Maximum amount of the asset a validator may stake.
+    /// Must be at least the min, and no greater than the maximum supply.
+    pub max_validator_stake: u64,
+    /// Range of the staking period in seconds.
+    /// The min must be non-zero and no greater than the max.
+    pub min_stake_duration: u32,
+    pub max_stake_duration: u32,
+    /// Minimum percentage a validator must charge the delegators,
+    /// denominated in "PERCENT_DENOMINATOR".
+    pub min_delegation_fee: u32,
+    /// Minimum amount of the asset required to delegate.
+    /// Must be non-zero.
+    pub min_delegator_stake: u64,
+    /// Maximum factor by which the delegated stake can exceed
+    /// the validator stake (e.g., 1 disables the delegation).
+    /// Must be non-zero.
+    pub max_validator_weight_factor: u8,
+    /// Minimum uptime to be rewarded, denominated in "PERCENT_DENOMINATOR".
+    pub uptime_requirement: u32,
+    /// Proves that the issuer has the right to transform the subnet,
+    /// same as "AddSubnetValidatorTx.SubnetAuth".
+    #[serde(rename = "subnetAuthorization")]
+    pub subnet_auth: key::secp256k1::txs::Input,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            subnet_id: ids::Id::empty(),
+            asset_id: ids::Id::empty(),
+            initial_supply: 0,
+            maximum_supply: 0,
+            min_consumption_rate: 0,
+            max_consumption_rate: 0,
+            min_validator_stake: 0,
+            max_validator_stake: 0,
+            min_stake_duration: 0,
+            max_stake_duration: 0,
+            min_delegation_fee: 0,
+            min_delegator_stake: 0,
+            max_validator_weight_factor: 0,
+            uptime_requirement: 0,
+            subnet_auth: key::secp256k1::txs::Input::default(),
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.TransformSubnetTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Unpacks the signed tx bytes in the same order as "Tx::sign",
+    /// and initializes the metadata so that "tx_id" is available.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let tx = Self::unpack_prefix(d)?;
+        if let Some(metadata) = &tx.base_tx.metadata {
+            metadata.check_extra_space(d)?;
+        }
+        Ok(tx)
+    }
+
+    /// Unpacks the signed tx from the beginning of the bytes,
+    /// ignoring the trailing bytes (e.g., the rest of the block).
+    pub fn unpack_prefix(d: &[u8]) -> Result<Self> {
+        let (packer, mut base_tx) = txs::Tx::unpack(d, Self::type_id())?;
+
+        // unpack the second field "subnet_id" in the struct
+        let subnet_id = packer.unpack_bytes(ids::LEN)?;
+        let subnet_id = ids::Id::from_slice(&subnet_id);
+
+        // unpack the third field "asset_id" in the struct
+        let asset_id = packer.unpack_bytes(ids::LEN)?;
+        let asset_id = ids::Id::from_slice(&asset_id);
+
+        // unpack the economic parameters in the struct
+        let initial_supply = packer.unpack_u64()?;
+        let maximum_supply = packer.unpack_u64()?;
+        let min_consumption_rate = packer.unpack_u64()?;
+        let max_consumption_rate = packer.unpack_u64()?;
+        let min_validator_stake = packer.unpack_u64()?;
+        let max_validator_stake = packer.unpack_u64()?;
+        let min_stake_duration = packer.unpack_u32()?;
+        let max_stake_duration = packer.unpack_u32()?;
+        let min_delegation_fee = packer.unpack_u32()?;
+        let min_delegator_stake = packer.unpack_u64()?;
+        let max_validator_weight_factor = packer.unpack_byte()?;
+        let uptime_requirement = packer.unpack_u32()?;
+
+        // unpack the last field "subnet_auth" in the struct
+        let subnet_auth = packer.unpack_subnet_auth()?;
+
+        let tx_bytes_with_no_signature_len = packer.get_offset();
+        let creds = packer.unpack_credentials()?;
+        base_tx.metadata = Some(txs::Metadata::from_unpacked_prefix(
+            &packer,
+            d,
+            tx_bytes_with_no_signature_len,
+        ));
+
+        Ok(Self {
+            base_tx,
+            subnet_id,
+            asset_id,
+            initial_supply,
+            maximum_supply,
+            min_consumption_rate,
+            max_consumption_rate,
+            min_validator_stake,
+            max_validator_stake,
+            min_stake_duration,
+            max_stake_duration,
+            min_delegation_fee,
+            min_delegator_stake,
+            max_validator_weight_factor,
+            uptime_requirement,
+            subnet_auth,
+            creds,
+        })
+    }
+
+    /// Verifies the subnet transformation parameters, as in the
+    /// syntactic verification of "avalanchego" so that the invalid
+    /// tx fails before issuance.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#TransformSubnetTx.SyntacticVerify>
+    pub fn verify(&self, avax_asset_id: &ids::Id) -> Result<()> {
+        let invalid = |message: &str| -> Result<()> {
+            Err(Error::Other {
+                message: message.to_string(),
+                retryable: false,
+            })
+        };
+
+        if self.subnet_id.is_empty() {
+            return invalid("cannot transform primary network"); // ref. "errCantTransformPrimaryNetwork"
+        }
+        if self.asset_id.is_empty() {
+            return invalid("empty assetID"); // ref. "errEmptyAssetID"
+        }
+        if self.asset_id == *avax_asset_id {
+            return invalid("assetID can't be AVAX"); // ref. "errAssetIDCantBeAVAX"
+        }
+        if self.initial_supply == 0 {
+            return invalid("initial supply must be non-0"); // ref. "errInitialSupplyZero"
+        }
+        if self.initial_supply > self.maximum_supply {
+            return invalid("initial supply can't be greater than maximum supply");
+            // ref. "errInitialSupplyGreaterThanMaxSupply"
+        }
+        if self.min_consumption_rate > self.max_consumption_rate {
+            return invalid(
+                "min consumption rate must be less than or equal to max consumption rate",
+            ); // ref. "errMinConsumptionRateTooLarge"
+        }
+        if self.max_consumption_rate > PERCENT_DENOMINATOR {
+            return invalid("max consumption rate must be less than or equal to 1,000,000");
+            // ref. "errMaxConsumptionRateTooLarge"
+        }
+        if self.min_validator_stake == 0 {
+            return invalid("min validator stake must be non-0"); // ref. "errMinValidatorStakeZero"
+        }
+        if self.min_validator_stake > self.initial_supply {
+            return invalid("min validator stake must be less than or equal to initial supply");
+            // ref. "errMinValidatorStakeAboveSupply"
+        }
+        if self.min_validator_stake > self.max_validator_stake {
+            return invalid(
+                "min validator stake must be less than or equal to max validator stake",
+            ); // ref. "errMinValidatorStakeAboveMax"
+        }
+        if self.max_validator_stake > self.maximum_supply {
+            return invalid("max validator stake must be less than or equal to max supply");
+            // ref. "errMaxValidatorStakeTooLarge"
+        }
+        if self.min_stake_duration == 0 {
+            return invalid("min stake duration must be non-0"); // ref. "errMinStakeDurationZero"
+        }
+        if self.min_stake_duration > self.max_stake_duration {
+            return invalid("min stake duration must be less than or equal to max stake duration");
+            // ref. "errMinStakeDurationTooLarge"
+        }
+        if self.min_delegation_fee as u64 > PERCENT_DENOMINATOR {
+            return invalid("min delegation fee must be less than or equal to 1,000,000");
+            // ref. "errMinDelegationFeeTooLarge"
+        }
+        if self.min_delegator_stake == 0 {
+            return invalid("min delegator stake must be non-0"); // ref. "errMinDelegatorStakeZero"
+        }
+        if self.max_validator_weight_factor == 0 {
+            return invalid("max validator weight factor must be non-0"); // ref. "errMaxValidatorWeightFactorZero"
+        }
+        if self.uptime_requirement as u64 > PERCENT_DENOMINATOR {
+            return invalid("uptime requirement must be less than or equal to 1,000,000");
+            // ref. "errUptimeRequirementTooLarge"
+        }
+        Ok(())
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        // marshal "unsigned tx" with the codec version
+        let type_id = Self::type_id();
+        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+
+        // "avalanchego" marshals the whole struct again for signed bytes
+        // even when the underlying "unsigned_tx" is already once marshaled
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
+        //
+        // reuse the underlying packer to avoid marshaling the unsigned tx twice
+        // just marshal the next fields in the struct and pack them all together
+        // in the existing packer
+        let unsigned_tx_bytes = packer.take_bytes();
+        packer.set_bytes(&unsigned_tx_bytes);
+
+        // pack the second field "subnet_id" in the struct
+        packer.pack_bytes(self.subnet_id.as_ref())?;
+
+        // pack the third field "asset_id" in the struct
+        packer.pack_bytes(self.asset_id.as_ref())?;
+
+        // pack the economic parameters in the struct
+        packer.pack_u64(self.initial_supply)?;
+        packer.pack_u64(self.maximum_supply)?;
+        packer.pack_u64(self.min_consumption_rate)?;
+        packer.pack_u64(self.max_consumption_rate)?;
+        packer.pack_u64(self.min_validator_stake)?;
+        packer.pack_u64(self.max_validator_stake)?;
+        packer.pack_u32(self.min_stake_duration)?;
+        packer.pack_u32(self.max_stake_duration)?;
+        packer.pack_u32(self.min_delegation_fee)?;
+        packer.pack_u64(self.min_delegator_stake)?;
+        packer.pack_byte(self.max_validator_weight_factor)?;
+        packer.pack_u32(self.uptime_requirement)?;
+
+        // pack the last field "subnet_auth" in the struct
+        let subnet_auth_type_id = key::secp256k1::txs::Input::type_id();
+        packer.pack_u32(subnet_auth_type_id)?;
+        packer.pack_u32(self.subnet_auth.sig_indices.len() as u32)?;
+        for sig_idx in self.subnet_auth.sig_indices.iter() {
+            packer.pack_u32(*sig_idx)?;
+        }
+
+        // take bytes just for hashing computation
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // compute sha256 for marshaled "unsigned tx" bytes
+        // IMPORTANT: take the hash only for the type "platformvm.TransformSubnetTx" unsigned tx
+        // not other fields -- only hash "platformvm.TransformSubnetTx.*" but not "platformvm.Tx.Creds"
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#TransformSubnetTx
+        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
+
+        // number of of credentials
+        let creds_len = signers.len() as u32;
+        // pack the fourth field in the struct
+        packer.pack_u32(creds_len)?;
+
+        // sign the hash with the signers (in case of multi-sig)
+        // and combine all signatures into a secp256k1fx credential
+        self.creds = Vec::new();
+        for keys in signers.iter() {
+            let mut sigs: Vec<Vec<u8>> = Vec::new();
+            for k in keys.iter() {
+                let sig = k.sign_digest(&tx_bytes_hash).await?;
+                sigs.push(Vec::from(sig));
+            }
+
+            let mut cred = key::secp256k1::txs::Credential::default();
+            cred.signatures = sigs;
+
+            // add a new credential to "Tx"
+            self.creds.push(cred);
+        }
+        if creds_len > 0 {
+            // pack each "cred" which is "secp256k1fx.Credential"
+            // marshal type ID for "secp256k1fx.Credential"
+            let cred_type_id = key::secp256k1::txs::Credential::type_id();
+            for cred in self.creds.iter() {
+                // marshal type ID for "secp256k1fx.Credential"
+                packer.pack_u32(cred_type_id)?;
+
+                // marshal fields for "secp256k1fx.Credential"
+                packer.pack_u32(cred.signatures.len() as u32)?;
+                for sig in cred.signatures.iter() {
+                    packer.pack_bytes(sig)?;
+                }
+            }
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+
+        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
+        // ref. "avalanchego/vms/platformvm.Tx.Sign"
+        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
+        self.base_tx.metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(&tx_id),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::transform_subnet::test_transform_subnet_tx_serialization_with_two_signers --exact --show-output
+#[test]
+fn test_transform_subnet_tx_serialization_with_two_signers() {
+    use crate::{ids::short, platformvm};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let avax_asset_id = ids::Id::from_slice(&<Vec<u8>>::from([
+        0x88, 0xee, 0xc2, 0xe0, 0x99, 0xc6, 0xa5, 0x28, //
+        0xe6, 0x89, 0x61, 0x8e, 0x87, 0x21, 0xe0, 0x4a, //
+        0xe8, 0x5e, 0xa5, 0x74, 0xc7, 0xa1, 0x5a, 0x79, //
+        0x68, 0x64, 0x4d, 0x14, 0xd5, 0x47, 0x80, 0x14, //
+    ]));
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 1000000,
+            transferable_outputs: Some(vec![txs::transferable::Output {
+                asset_id: avax_asset_id,
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount: 0x2c6874d5c56f500,
+                    output_owners: key::secp256k1::txs::OutputOwners {
+                        locktime: 0x00,
+                        threshold: 0x01,
+                        addresses: vec![short::Id::from_slice(&<Vec<u8>>::from([
+                            0x65, 0x84, 0x4a, 0x05, 0x40, 0x5f, 0x36, 0x62, 0xc1, 0x92, //
+                            0x81, 0x42, 0xc6, 0xc2, 0xa7, 0x83, 0xef, 0x87, 0x1d, 0xe9, //
+                        ]))],
+                    },
+                }),
+                ..txs::transferable::Output::default()
+            }]),
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    output_index: 0,
+                    tx_id: ids::Id::from_slice(&[0x01; ids::LEN]),
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: avax_asset_id,
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 0x2c6874d62d06000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        subnet_id: ids::Id::from_slice(&[0x07; ids::LEN]),
+        asset_id: ids::Id::from_slice(&[0x2a; ids::LEN]),
+        initial_supply: 0x1000,
+        maximum_supply: 0x2000,
+        min_consumption_rate: 0x10,
+        max_consumption_rate: 0x20,
+        min_validator_stake: 0x100,
+        max_validator_stake: 0x200,
+        min_stake_duration: 0x3c,
+        max_stake_duration: 0x78,
+        min_delegation_fee: 0x01,
+        min_delegator_stake: 0x02,
+        max_validator_weight_factor: 0x05,
+        uptime_requirement: 0x0c3500,
+        subnet_auth: key::secp256k1::txs::Input {
+            sig_indices: vec![0_u32],
+        },
+        ..Tx::default()
+    };
+    tx.verify(&avax_asset_id).expect("failed to verify");
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key");
+    // one for the fee input, one for the subnet authorization
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> =
+        vec![vec![test_key.clone()], vec![test_key]];
+    ab!(tx.sign(signers)).expect("failed to sign");
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures.clone();
+    assert_eq!(
+        tx.tx_id(),
+        ids::Id::from_slice(&hash::sha256(&tx_bytes_with_signatures))
+    );
+
+    // "avax.BaseTx" with the "platformvm.TransformSubnetTx" type ID
+    let mut expected_unsigned_bytes = tx
+        .base_tx
+        .pack(codec::VERSION, 24)
+        .unwrap()
+        .take_bytes()
+        .to_vec();
+    assert_eq!(&expected_unsigned_bytes[2..6], &[0x00, 0x00, 0x00, 0x18]);
+    // subnet_id
+    expected_unsigned_bytes.extend_from_slice(&[0x07; ids::LEN]);
+    // asset_id
+    expected_unsigned_bytes.extend_from_slice(&[0x2a; ids::LEN]);
+    expected_unsigned_bytes.extend_from_slice(&[
+        // initial_supply
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, //
+        //
+        // maximum_supply
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, //
+        //
+        // min_consumption_rate
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, //
+        //
+        // max_consumption_rate
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, //
+        //
+        // min_validator_stake
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, //
+        //
+        // max_validator_stake
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, //
+        //
+        // min_stake_duration
+        0x00, 0x00, 0x00, 0x3c, //
+        //
+        // max_stake_duration
+        0x00, 0x00, 0x00, 0x78, //
+        //
+        // min_delegation_fee
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // min_delegator_stake
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, //
+        //
+        // max_validator_weight_factor
+        0x05, //
+        //
+        // uptime_requirement
+        0x00, 0x0c, 0x35, 0x00, //
+        //
+        // secp256k1fx.Input type ID
+        0x00, 0x00, 0x00, 0x0a, //
+        //
+        // "subnet_auth" sig_indices.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "subnet_auth" sig_indices[0]
+        0x00, 0x00, 0x00, 0x00, //
+    ]);
+    assert!(cmp_manager::eq_vectors(
+        &expected_unsigned_bytes,
+        &tx_metadata.tx_bytes_with_no_signature
+    ));
+
+    // two credentials, each with one 65-byte signature
+    assert_eq!(
+        tx_bytes_with_signatures.len(),
+        expected_unsigned_bytes.len() + 4 + 2 * (4 + 4 + 65)
+    );
+
+    let unpacked = Tx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(unpacked, tx);
+    assert_eq!(unpacked.tx_id(), tx.tx_id());
+
+    let signed = platformvm::txs::SignedTx::unpack(&tx_bytes_with_signatures).unwrap();
+    assert_eq!(signed, platformvm::txs::SignedTx::TransformSubnet(tx));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::transform_subnet::test_transform_subnet_tx_verify --exact --show-output
+#[test]
+fn test_transform_subnet_tx_verify() {
+    let avax_asset_id = ids::Id::from_slice(&[0x01; ids::LEN]);
+    let valid = Tx {
+        subnet_id: ids::Id::from_slice(&[0x07; ids::LEN]),
+        asset_id: ids::Id::from_slice(&[0x2a; ids::LEN]),
+        initial_supply: 1000,
+        maximum_supply: 2000,
+        min_consumption_rate: 100_000,
+        max_consumption_rate: 120_000,
+        min_validator_stake: 10,
+        max_validator_stake: 2000,
+        min_stake_duration: 60,
+        max_stake_duration: 3600,
+        min_delegation_fee: 20_000,
+        min_delegator_stake: 1,
+        max_validator_weight_factor: 5,
+        uptime_requirement: 800_000,
+        ..Tx::default()
+    };
+    assert!(valid.verify(&avax_asset_id).is_ok());
+
+    let cases: Vec<(Tx, &str)> = vec![
+        (
+            Tx {
+                subnet_id: ids::Id::empty(),
+                ..valid.clone()
+            },
+            "cannot transform primary network",
+        ),
+        (
+            Tx {
+                asset_id: ids::Id::empty(),
+                ..valid.clone()
+            },
+            "empty assetID",
+        ),
+        (
+            Tx {
+                asset_id: avax_asset_id,
+                ..valid.clone()
+            },
+            "assetID can't be AVAX",
+        ),
+        (
+            Tx {
+                initial_supply: 0,
+                ..valid.clone()
+            },
+            "initial supply must be non-0",
+        ),
+        (
+            Tx {
+                initial_supply: 2001,
+                ..valid.clone()
+            },
+            "initial supply can't be greater than maximum supply",
+        ),
+        (
+            Tx {
+                min_consumption_rate: 120_001,
+                ..valid.clone()
+            },
+            "min consumption rate must be less than or equal to max consumption rate",
+        ),
+        (
+            Tx {
+                max_consumption_rate: PERCENT_DENOMINATOR + 1,
+                ..valid.clone()
+            },
+            "max consumption rate must be less than or equal to 1,000,000",
+        ),
+        (
+            Tx {
+                min_validator_stake: 0,
+                ..valid.clone()
+            },
+            "min validator stake must be non-0",
+        ),
+        (
+            Tx {
+                min_validator_stake: 1001,
+                ..valid.clone()
+            },
+            "min validator stake must be less than or equal to initial supply",
+        ),
+        (
+            Tx {
+                max_validator_stake: 9,
+                ..valid.clone()
+            },
+            "min validator stake must be less than or equal to max validator stake",
+        ),
+        (
+            Tx {
+                max_validator_stake: 2001,
+                ..valid.clone()
+            },
+            "max validator stake must be less than or equal to max supply",
+        ),
+        (
+            Tx {
+                min_stake_duration: 0,
+                ..valid.clone()
+            },
+            "min stake duration must be non-0",
+        ),
+        (
+            Tx {
+                min_stake_duration: 3601,
+                ..valid.clone()
+            },
+            "min stake duration must be less than or equal to max stake duration",
+        ),
+        (
+            Tx {
+                min_delegation_fee: PERCENT_DENOMINATOR as u32 + 1,
+                ..valid.clone()
+            },
+            "min delegation fee must be less than or equal to 1,000,000",
+        ),
+        (
+            Tx {
+                min_delegator_stake: 0,
+                ..valid.clone()
+            },
+            "min delegator stake must be non-0",
+        ),
+        (
+            Tx {
+                max_validator_weight_factor: 0,
+                ..valid.clone()
+            },
+            "max validator weight factor must be non-0",
+        ),
+        (
+            Tx {
+                uptime_requirement: PERCENT_DENOMINATOR as u32 + 1,
+                ..valid.clone()
+            },
+            "uptime requirement must be less than or equal to 1,000,000",
+        ),
+    ];
+    for (tx, expected) in cases.iter() {
+        let err = tx.verify(&avax_asset_id).unwrap_err();
+        assert_eq!(err.message(), *expected);
+        assert!(!err.retryable());
+    }
+}
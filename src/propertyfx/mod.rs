@@ -0,0 +1,225 @@
+//! Property (non-transferable ownership) feature extension.
+//! ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx>
+use crate::{
+    codec,
+    errors::Result,
+    key,
+    packer::{self, Unpackable},
+};
+use serde::{Deserialize, Serialize};
+
+/// Grants the owners the authority to mint the property.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#MintOutput>
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct MintOutput {
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for MintOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl MintOutput {
+    pub fn default() -> Self {
+        Self {
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(output_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        Self { output_owners }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.MintOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+/// Owns the property.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#OwnedOutput>
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct OwnedOutput {
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for OwnedOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl OwnedOutput {
+    pub fn default() -> Self {
+        Self {
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(output_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        Self { output_owners }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.OwnedOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+/// Spent by "secp256k1fx.Input" as the mint input of "propertyfx.MintOperation".
+impl key::secp256k1::keychain::Spendable for MintOutput {
+    type Input = key::secp256k1::txs::Input;
+
+    fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners {
+        &self.output_owners
+    }
+
+    fn spend_input(&self, sig_indices: Vec<u32>) -> Self::Input {
+        key::secp256k1::txs::Input::new(sig_indices)
+    }
+}
+
+/// Spent by "secp256k1fx.Input" as the input of "propertyfx.BurnOperation".
+impl key::secp256k1::keychain::Spendable for OwnedOutput {
+    type Input = key::secp256k1::txs::Input;
+
+    fn output_owners(&self) -> &key::secp256k1::txs::OutputOwners {
+        &self.output_owners
+    }
+
+    fn spend_input(&self, sig_indices: Vec<u32>) -> Self::Input {
+        key::secp256k1::txs::Input::new(sig_indices)
+    }
+}
+
+/// Consumes a "propertyfx.MintOutput" to produce a new "MintOutput"
+/// and an "OwnedOutput" of the property.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#MintOperation>
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct MintOperation {
+    #[serde(rename = "mintInput")]
+    pub mint_input: key::secp256k1::txs::Input,
+    #[serde(rename = "mintOutput")]
+    pub mint_output: MintOutput,
+    #[serde(rename = "ownedOutput")]
+    pub owned_output: OwnedOutput,
+}
+
+impl Default for MintOperation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl MintOperation {
+    pub fn default() -> Self {
+        Self {
+            mint_input: key::secp256k1::txs::Input::default(),
+            mint_output: MintOutput::default(),
+            owned_output: OwnedOutput::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.MintOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+/// Consumes a "propertyfx.OwnedOutput" without producing any output.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#BurnOperation>
+#[derive(
+    Debug, Serialize, Deserialize, Eq, PartialEq, Clone, packer::Packable, packer::Unpackable,
+)]
+pub struct BurnOperation {
+    /// The custom de/serializer embeds "input" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub input: key::secp256k1::txs::Input,
+}
+
+impl Default for BurnOperation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl BurnOperation {
+    pub fn default() -> Self {
+        Self {
+            input: key::secp256k1::txs::Input::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.BurnOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+/// Returns the type name of "propertyfx.Credential", which embeds
+/// "secp256k1fx.Credential" and is used to spend the propertyfx outputs.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#Credential>
+pub fn credential_type_name() -> String {
+    "propertyfx.Credential".to_string()
+}
+
+pub fn credential_type_id() -> u32 {
+    *(codec::X_TYPES.get(&credential_type_name()).unwrap()) as u32
+}
+
+impl packer::Packer {
+    /// Unpacks the "propertyfx.MintOutput" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#MintOutput>
+    pub fn unpack_property_mint_output(&self) -> Result<MintOutput> {
+        // "propertyfx.MintOutput.OutputOwners" is embedded, thus no type ID
+        MintOutput::unpack_from(self)
+    }
+
+    /// Unpacks the "propertyfx.OwnedOutput" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#OwnedOutput>
+    pub fn unpack_property_owned_output(&self) -> Result<OwnedOutput> {
+        // "propertyfx.OwnedOutput.OutputOwners" is embedded, thus no type ID
+        OwnedOutput::unpack_from(self)
+    }
+
+    /// Unpacks the "propertyfx.MintOperation" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#MintOperation>
+    pub fn unpack_property_mint_operation(&self) -> Result<MintOperation> {
+        MintOperation::unpack_from(self)
+    }
+
+    /// Unpacks the "propertyfx.BurnOperation" fields.
+    /// The caller must have already unpacked the type ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#BurnOperation>
+    pub fn unpack_property_burn_operation(&self) -> Result<BurnOperation> {
+        // "propertyfx.BurnOperation.Input" is embedded, thus no type ID
+        BurnOperation::unpack_from(self)
+    }
+}
@@ -277,9 +277,10 @@ impl Tx {
                         // marshal "platformvm::txs::StakeableLockIn.locktime" field
                         packer.pack_u64(stakeable_lock_in.locktime)?;
 
-                        // "platformvm.StakeableLockIn.TransferableIn" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockIn"
-                        // so no need to encode type ID
+                        // "platformvm.StakeableLockIn.TransferableIn" is an interface
+                        // thus encode the type ID for "secp256k1fx.TransferInput"
+                        packer.pack_u32(key::secp256k1::txs::transfer::Input::type_id())?;
+                        //
                         // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
                         // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
                         // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
@@ -324,6 +325,56 @@ impl Tx {
 
         Ok(packer)
     }
+
+    /// Unpacks the codec version, the type ID, and the "avax.BaseTx" fields
+    /// in the same order as "Tx::pack", from the signed tx bytes.
+    ///
+    /// Returns the packer itself so that the caller can continue to
+    /// unmarshal the following fields from the current offset.
+    ///
+    /// Fails if the encoded type ID does not match the expected "type_id".
+    /// ref. "avalanchego/codec.manager.Unmarshal"
+    /// ref. "avalanchego/codec/reflectcodec.genericCodec.unmarshal"
+    pub fn unpack(d: &[u8], type_id: u32) -> Result<(packer::Packer, Self)> {
+        let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
+
+        let codec_version = packer.unpack_u16()?;
+        if codec_version != codec::VERSION {
+            return Err(Error::Other {
+                message: format!("unknown codec version {}", codec_version), // ref. "errUnknownVersion"
+                retryable: false,
+            });
+        }
+        let type_id_unsigned_tx = packer.unpack_u32()?;
+        if type_id_unsigned_tx != type_id {
+            return Err(Error::UnexpectedTypeId {
+                type_id: type_id_unsigned_tx,
+                type_name: None,
+                interface: format!("UnsignedTx (expected type ID {})", type_id),
+            });
+        }
+
+        // "BaseTx.Metadata" is not serialize:"true" thus skipping deserialization!!!
+        let network_id = packer.unpack_u32()?;
+        let blockchain_id = packer.unpack_bytes(ids::LEN)?;
+        let blockchain_id = ids::Id::from_slice(&blockchain_id);
+
+        let transferable_outputs = packer.unpack_transferable_outputs()?;
+        let transferable_inputs = packer.unpack_transferable_inputs()?;
+
+        let memo = packer.unpack_bytes_with_header()?;
+        let memo = if memo.is_empty() { None } else { Some(memo) };
+
+        let tx = Self {
+            metadata: None,
+            network_id,
+            blockchain_id,
+            transferable_inputs,
+            transferable_outputs,
+            memo,
+        };
+        Ok((packer, tx))
+    }
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::test_base_tx_serialization --exact --show-output
@@ -509,6 +560,53 @@ impl Metadata {
         }
     }
 
+    /// Creates the metadata for the signed tx bytes that were fully consumed
+    /// by the packer, where the first "tx_bytes_with_no_signature_len" bytes
+    /// are the unsigned tx.
+    /// ref. "avalanchego/codec.manager.Unmarshal"
+    pub fn from_unpacked(
+        packer: &packer::Packer,
+        tx_bytes_with_signatures: &[u8],
+        tx_bytes_with_no_signature_len: usize,
+    ) -> Result<Self> {
+        let metadata = Self::from_unpacked_prefix(
+            packer,
+            tx_bytes_with_signatures,
+            tx_bytes_with_no_signature_len,
+        );
+        metadata.check_extra_space(tx_bytes_with_signatures)?;
+        Ok(metadata)
+    }
+
+    /// Creates the metadata for the signed tx bytes that were consumed
+    /// by the packer so far, ignoring the trailing bytes
+    /// (e.g., the rest of the block that embeds the tx).
+    pub fn from_unpacked_prefix(
+        packer: &packer::Packer,
+        tx_bytes_with_signatures: &[u8],
+        tx_bytes_with_no_signature_len: usize,
+    ) -> Self {
+        Self::new(
+            &tx_bytes_with_signatures[..tx_bytes_with_no_signature_len],
+            &tx_bytes_with_signatures[..packer.get_offset()],
+        )
+    }
+
+    /// Fails if the signed tx did not consume all of the unpacked bytes.
+    pub fn check_extra_space(&self, unpacked: &[u8]) -> Result<()> {
+        if self.tx_bytes_with_signatures.len() != unpacked.len() {
+            return Err(Error::Other {
+                message: format!(
+                    "unpacked {} bytes but tx has {} bytes (extra space)",
+                    self.tx_bytes_with_signatures.len(),
+                    unpacked.len()
+                ), // ref. "errExtraSpace"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
     pub fn verify(&self) -> Result<()> {
         if self.id.is_empty() {
             return Err(Error::Other {
@@ -1,6 +1,9 @@
 use std::cmp::Ordering;
 
-use crate::{ids, key, platformvm, txs};
+use crate::{
+    errors::{Error, Result},
+    ids, key, packer, platformvm, txs,
+};
 use serde::{Deserialize, Serialize};
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput>
@@ -123,6 +126,76 @@ impl PartialEq for Output {
     }
 }
 
+impl packer::Packer {
+    /// Unpacks the "avax.TransferableOutput" in the order of "Tx::pack".
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput>
+    pub fn unpack_transferable_output(&self) -> Result<Output> {
+        // "TransferableOutput.Asset" is embedded, thus no type ID
+        let asset_id = self.unpack_bytes(ids::LEN)?;
+        let asset_id = ids::Id::from_slice(&asset_id);
+
+        // fx_id is serialize:"false" thus skipping deserialization
+
+        // "TransferableOutput.Out" is an interface, thus prefixed with type ID
+        let type_id_transferable_out = self.unpack_u32()?;
+        match type_id_transferable_out {
+            7 => {
+                // "key::secp256k1::txs::transfer::Output"
+                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
+                let transfer_output = self.unpack_transfer_output()?;
+                Ok(Output {
+                    asset_id,
+                    transfer_output: Some(transfer_output),
+                    ..Output::default()
+                })
+            }
+            22 => {
+                // "platformvm::txs::StakeableLockOut"
+                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
+                let locktime = self.unpack_u64()?;
+
+                // "StakeableLockOut.TransferableOut" is an interface, thus prefixed with type ID
+                let type_id_transfer_out = self.unpack_u32()?;
+                if type_id_transfer_out != key::secp256k1::txs::transfer::Output::type_id() {
+                    return Err(Error::UnexpectedTypeId {
+                        type_id: type_id_transfer_out,
+                        type_name: None,
+                        interface: "StakeableLockOut.TransferableOut".to_string(),
+                    });
+                }
+                let transfer_output = self.unpack_transfer_output()?;
+                Ok(Output {
+                    asset_id,
+                    stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+                        locktime,
+                        transfer_output,
+                    }),
+                    ..Output::default()
+                })
+            }
+            _ => Err(Error::UnexpectedTypeId {
+                type_id: type_id_transferable_out,
+                type_name: None,
+                interface: "avax.TransferableOut".to_string(),
+            }),
+        }
+    }
+
+    /// Unpacks the list of "avax.TransferableOutput", prefixed with its length.
+    /// Returns "None" for the empty list, as "Tx::pack" encodes "None" with zero length.
+    pub fn unpack_transferable_outputs(&self) -> Result<Option<Vec<Output>>> {
+        let outs_len = self.unpack_u32()?;
+        if outs_len == 0 {
+            return Ok(None);
+        }
+        let mut outs: Vec<Output> = Vec::new();
+        for _ in 0..outs_len {
+            outs.push(self.unpack_transferable_output()?);
+        }
+        Ok(Some(outs))
+    }
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableOutputs>
 /// ref. "avalanchego/vms/components/avax.TestTransferableOutputSorting"
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::transferable::test_sort_transferable_outputs --exact --show-output
@@ -443,6 +516,89 @@ impl Input {
     }
 }
 
+impl packer::Packer {
+    /// Unpacks the "avax.TransferableInput" in the order of "Tx::pack".
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput>
+    pub fn unpack_transferable_input(&self) -> Result<Input> {
+        // "TransferableInput.UTXOID" is embedded, thus no type ID
+        let tx_id = self.unpack_bytes(ids::LEN)?;
+        let tx_id = ids::Id::from_slice(&tx_id);
+        let output_index = self.unpack_u32()?;
+
+        // "TransferableInput.Asset" is embedded, thus no type ID
+        let asset_id = self.unpack_bytes(ids::LEN)?;
+        let asset_id = ids::Id::from_slice(&asset_id);
+
+        // fx_id is serialize:"false" thus skipping deserialization
+
+        let utxo_id = txs::utxo::Id {
+            tx_id,
+            output_index,
+            ..txs::utxo::Id::default()
+        };
+
+        // "TransferableInput.In" is an interface, thus prefixed with type ID
+        let type_id_transferable_in = self.unpack_u32()?;
+        match type_id_transferable_in {
+            5 => {
+                // "key::secp256k1::txs::transfer::Input"
+                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
+                let transfer_input = self.unpack_transfer_input()?;
+                Ok(Input {
+                    utxo_id,
+                    asset_id,
+                    transfer_input: Some(transfer_input),
+                    ..Input::default()
+                })
+            }
+            21 => {
+                // "platformvm::txs::StakeableLockIn"
+                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
+                let locktime = self.unpack_u64()?;
+
+                // "StakeableLockIn.TransferableIn" is an interface, thus prefixed with type ID
+                let type_id_transfer_in = self.unpack_u32()?;
+                if type_id_transfer_in != key::secp256k1::txs::transfer::Input::type_id() {
+                    return Err(Error::UnexpectedTypeId {
+                        type_id: type_id_transfer_in,
+                        type_name: None,
+                        interface: "StakeableLockIn.TransferableIn".to_string(),
+                    });
+                }
+                let transfer_input = self.unpack_transfer_input()?;
+                Ok(Input {
+                    utxo_id,
+                    asset_id,
+                    stakeable_lock_in: Some(platformvm::txs::StakeableLockIn {
+                        locktime,
+                        transfer_input,
+                    }),
+                    ..Input::default()
+                })
+            }
+            _ => Err(Error::UnexpectedTypeId {
+                type_id: type_id_transferable_in,
+                type_name: None,
+                interface: "avax.TransferableIn".to_string(),
+            }),
+        }
+    }
+
+    /// Unpacks the list of "avax.TransferableInput", prefixed with its length.
+    /// Returns "None" for the empty list, as "Tx::pack" encodes "None" with zero length.
+    pub fn unpack_transferable_inputs(&self) -> Result<Option<Vec<Input>>> {
+        let ins_len = self.unpack_u32()?;
+        if ins_len == 0 {
+            return Ok(None);
+        }
+        let mut ins: Vec<Input> = Vec::new();
+        for _ in 0..ins_len {
+            ins.push(self.unpack_transferable_input()?);
+        }
+        Ok(Some(ins))
+    }
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableInputs>
 ///
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableInputsWithSigners>
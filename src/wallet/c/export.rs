@@ -0,0 +1,310 @@
+use crate::{
+    choices::status::Status,
+    coreth::atomic,
+    errors::{Error, Result},
+    formatting,
+    ids::{self, short},
+    key, txs,
+};
+use primitive_types::U256;
+use tokio::time::{sleep, Duration, Instant};
+
+/// Represents C-chain "Export" transaction, which moves the funds from
+/// the EVM account to the shared memory of the X-chain or the P-chain.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/c/builder.go> "NewExportTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::c::C<T>,
+
+    /// Export destination blockchain id.
+    pub destination_blockchain_id: ids::Id,
+
+    /// Transfer amount in nAVAX, excluding the tx fee.
+    pub amount: u64,
+
+    /// Owner of the exported funds on the destination chain.
+    /// Defaults to the wallet owner's short address.
+    pub to: short::Id,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(c: &crate::wallet::c::C<T>) -> Self {
+        Self {
+            inner: c.clone(),
+            destination_blockchain_id: ids::Id::empty(),
+            amount: 0,
+            to: c.inner.short_address.clone(),
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the destination blockchain Id.
+    #[must_use]
+    pub fn destination_blockchain_id(mut self, blockchain_id: ids::Id) -> Self {
+        self.destination_blockchain_id = blockchain_id;
+        self
+    }
+
+    /// Sets the transfer amount.
+    #[must_use]
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Sets the owner of the exported funds on the destination chain.
+    #[must_use]
+    pub fn to(mut self, to: short::Id) -> Self {
+        self.to = to;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Issues the export transaction and returns the transaction Id.
+    /// The dynamic fee is debited from the EVM account on top of the amount.
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/c/builder.go> "NewExportTx"
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "exporting {} AVAX from {} to {} via {}",
+            self.amount,
+            self.inner.inner.h160_address,
+            self.destination_blockchain_id,
+            picked_http_rpc.1
+        );
+
+        let nonce = self.inner.nonce().await?;
+        let mut unsigned_tx = atomic::UnsignedExportTx {
+            network_id: self.inner.inner.network_id,
+            blockchain_id: self.inner.inner.blockchain_id_c,
+            destination_chain: self.destination_blockchain_id,
+            ins: vec![atomic::EvmInput {
+                address: self.inner.inner.h160_address,
+                amount: self.amount,
+                asset_id: self.inner.inner.avax_asset_id,
+                nonce,
+            }],
+            exported_outputs: vec![txs::transferable::Output {
+                asset_id: self.inner.inner.avax_asset_id,
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount: self.amount,
+                    output_owners: key::secp256k1::txs::OutputOwners {
+                        locktime: 0,
+                        threshold: 1,
+                        addresses: vec![self.to.clone()],
+                    },
+                }),
+                ..Default::default()
+            }],
+        };
+
+        // the tx size does not depend on the input amount,
+        // so the gas can be computed before adding the fee
+        let base_fee = self.inner.base_fee().await?;
+        let gas_used = atomic::UnsignedAtomicTx::Export(unsigned_tx.clone()).gas_used(true)?;
+        let tx_fee = atomic::calculate_dynamic_fee(gas_used, base_fee)?;
+        let amount_to_consume = self
+            .amount
+            .checked_add(tx_fee)
+            .ok_or_else(|| Error::Other {
+                message: "export amount overflows u64".to_string(),
+                retryable: false,
+            })?;
+        log::info!(
+            "exporting {} AVAX with tx fee {} (gas used {}, base fee {}, nonce {})",
+            self.amount,
+            tx_fee,
+            gas_used,
+            base_fee,
+            nonce
+        );
+
+        // EVM balance is denominated in wei
+        let balance = self.inner.balance().await?;
+        if balance / U256::from(atomic::X2C_RATE) < U256::from(amount_to_consume) {
+            return Err(Error::Other {
+                message: format!(
+                    "insufficient funds to export {} AVAX with tx fee {} (balance {} wei)",
+                    self.amount, tx_fee, balance
+                ), // ref. "errInsufficientFunds"
+                retryable: false,
+            });
+        }
+        unsigned_tx.ins[0].amount = amount_to_consume;
+
+        // one signer per EVM input, the key that owns the EVM account
+        let signer = self
+            .inner
+            .inner
+            .keychain
+            .keys
+            .iter()
+            .find(|k| k.h160_address() == self.inner.inner.h160_address)
+            .ok_or_else(|| Error::Other {
+                message: format!(
+                    "no key in the keychain for the EVM address {}",
+                    self.inner.inner.h160_address
+                ),
+                retryable: false,
+            })?;
+        let signers = vec![vec![signer.clone()]];
+
+        let mut tx = atomic::Tx::new(atomic::UnsignedAtomicTx::Export(unsigned_tx));
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok(tx.tx_id());
+        }
+
+        let tx_bytes_with_signatures = tx.metadata.unwrap().tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = self
+            .inner
+            .inner
+            .client
+            .c()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!("failed to issue export transaction {:?}", e),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp
+            .result
+            .ok_or_else(|| Error::API {
+                message: format!(
+                    "failed to issue export transaction {:?} (no result)",
+                    resp.error
+                ),
+                retryable: false,
+            })?
+            .tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm export transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = self
+                .inner
+                .inner
+                .client
+                .c()
+                .get_atomic_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
+
+            let status = resp
+                .result
+                .ok_or_else(|| Error::API {
+                    message: format!(
+                        "failed to get atomic tx status {:?} (no result)",
+                        resp.error
+                    ),
+                    retryable: false,
+                })?
+                .status;
+            if status == Status::Accepted {
+                log::info!("{} successfully accepted", tx_id);
+                success = true;
+                break;
+            }
+            if status == Status::Unknown(String::from("Dropped")) {
+                return Err(Error::API {
+                    message: format!("{} dropped", tx_id),
+                    retryable: false,
+                });
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok(tx_id)
+    }
+}
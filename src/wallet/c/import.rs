@@ -0,0 +1,312 @@
+use std::time::SystemTime;
+
+use crate::{
+    choices::status::Status,
+    coreth::atomic,
+    errors::{Error, Result},
+    formatting, ids, key, txs,
+};
+use primitive_types::H160;
+use tokio::time::{sleep, Duration, Instant};
+
+/// Represents C-chain "Import" transaction, which moves the funds exported
+/// from the X-chain or the P-chain into the EVM account.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/c/builder.go> "NewImportTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::c::C<T>,
+
+    /// Import source blockchain id.
+    pub source_blockchain_id: ids::Id,
+
+    /// EVM address to receive the imported funds.
+    /// Defaults to the wallet owner's address.
+    pub to: H160,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(c: &crate::wallet::c::C<T>) -> Self {
+        Self {
+            inner: c.clone(),
+            source_blockchain_id: ids::Id::empty(),
+            to: c.inner.h160_address,
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the source blockchain Id.
+    #[must_use]
+    pub fn source_blockchain_id(mut self, blockchain_id: ids::Id) -> Self {
+        self.source_blockchain_id = blockchain_id;
+        self
+    }
+
+    /// Sets the EVM address to receive the imported funds.
+    #[must_use]
+    pub fn to(mut self, to: H160) -> Self {
+        self.to = to;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Issues the import transaction and returns the transaction Id.
+    /// The dynamic fee is deducted from the imported amount.
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/c/builder.go> "NewImportTx"
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "importing from {} to {} via {}",
+            self.source_blockchain_id,
+            self.to,
+            picked_http_rpc.1
+        );
+
+        let utxos = self
+            .inner
+            .atomic_utxos_with_endpoint(&picked_http_rpc.1, &self.source_blockchain_id)
+            .await?;
+
+        let now_unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        let mut import_amount = 0u64;
+        let mut inputs_with_signers: Vec<(txs::transferable::Input, Vec<T>)> = Vec::new();
+        for utxo in utxos.iter() {
+            if utxo.asset_id != self.inner.inner.avax_asset_id {
+                continue;
+            }
+
+            if let Some(out) = &utxo.transfer_output {
+                let res = self.inner.inner.keychain.spend(out, now_unix);
+                if res.is_none() {
+                    // cannot spend the output, move onto next
+                    continue;
+                }
+                let (transfer_input, in_signers) = res.unwrap();
+
+                import_amount = import_amount
+                    .checked_add(transfer_input.amount)
+                    .ok_or_else(|| Error::Other {
+                        message: "import amount overflows u64".to_string(),
+                        retryable: false,
+                    })?;
+
+                inputs_with_signers.push((
+                    txs::transferable::Input {
+                        utxo_id: utxo.utxo_id.clone(),
+                        asset_id: utxo.asset_id,
+                        transfer_input: Some(transfer_input),
+                        ..txs::transferable::Input::default()
+                    },
+                    in_signers,
+                ));
+            }
+        }
+        if inputs_with_signers.is_empty() {
+            return Err(Error::Other {
+                message: "no spendable funds were found".to_string(),
+                retryable: false,
+            });
+        }
+
+        // signers must be in the same order as the sorted inputs
+        inputs_with_signers.sort_by(|a, b| a.0.cmp(&b.0));
+        let (imported_inputs, signers): (Vec<txs::transferable::Input>, Vec<Vec<T>>) =
+            inputs_with_signers.into_iter().unzip();
+
+        let mut unsigned_tx = atomic::UnsignedImportTx {
+            network_id: self.inner.inner.network_id,
+            blockchain_id: self.inner.inner.blockchain_id_c,
+            source_chain: self.source_blockchain_id,
+            imported_inputs,
+            outs: vec![atomic::EvmOutput {
+                address: self.to,
+                amount: 0,
+                asset_id: self.inner.inner.avax_asset_id,
+            }],
+        };
+
+        // the tx size does not depend on the output amount,
+        // so the gas can be computed before deducting the fee
+        let base_fee = self.inner.base_fee().await?;
+        let gas_used = atomic::UnsignedAtomicTx::Import(unsigned_tx.clone()).gas_used(true)?;
+        let tx_fee = atomic::calculate_dynamic_fee(gas_used, base_fee)?;
+        log::info!(
+            "importing total {} AVAX with tx fee {} (gas used {}, base fee {})",
+            import_amount,
+            tx_fee,
+            gas_used,
+            base_fee
+        );
+        if import_amount <= tx_fee {
+            return Err(Error::Other {
+                message: format!(
+                    "insufficient funds to import {} AVAX with tx fee {}",
+                    import_amount, tx_fee
+                ), // ref. "errInsufficientFunds"
+                retryable: false,
+            });
+        }
+        unsigned_tx.outs[0].amount = import_amount - tx_fee;
+
+        let mut tx = atomic::Tx::new(atomic::UnsignedAtomicTx::Import(unsigned_tx));
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok(tx.tx_id());
+        }
+
+        let tx_bytes_with_signatures = tx.metadata.unwrap().tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = self
+            .inner
+            .inner
+            .client
+            .c()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!("failed to issue import transaction {:?}", e),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp
+            .result
+            .ok_or_else(|| Error::API {
+                message: format!(
+                    "failed to issue import transaction {:?} (no result)",
+                    resp.error
+                ),
+                retryable: false,
+            })?
+            .tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm import transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = self
+                .inner
+                .inner
+                .client
+                .c()
+                .get_atomic_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
+
+            let status = resp
+                .result
+                .ok_or_else(|| Error::API {
+                    message: format!(
+                        "failed to get atomic tx status {:?} (no result)",
+                        resp.error
+                    ),
+                    retryable: false,
+                })?
+                .status;
+            if status == Status::Accepted {
+                log::info!("{} successfully accepted", tx_id);
+                success = true;
+                break;
+            }
+            if status == Status::Unknown(String::from("Dropped")) {
+                return Err(Error::API {
+                    message: format!("{} dropped", tx_id),
+                    retryable: false,
+                });
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok(tx_id)
+    }
+}
@@ -0,0 +1,99 @@
+pub mod export;
+pub mod import;
+
+use crate::{errors::Result, ids, jsonrpc::client::evm as client_evm, key, txs, wallet};
+use futures::TryStreamExt;
+use primitive_types::U256;
+
+impl<T> wallet::Wallet<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    #[must_use]
+    pub fn c(&self) -> C<T> {
+        C {
+            inner: self.clone(),
+        }
+    }
+}
+
+/// Builds the C-chain side of the atomic swaps, to move funds between
+/// the EVM account and the shared memory of the X-chain or the P-chain.
+#[derive(Clone, Debug)]
+pub struct C<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::Wallet<T>,
+}
+
+impl<T> C<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    /// Returns the C-chain EVM RPC endpoint for the base HTTP URL.
+    pub fn evm_rpc_ep(base_http_url: &str) -> String {
+        format!("{base_http_url}/ext/bc/C/rpc")
+    }
+
+    /// Fetches the current balance in wei of the wallet owner's EVM account.
+    pub async fn balance(&self) -> Result<U256> {
+        let rpc_ep = Self::evm_rpc_ep(&self.inner.pick_base_http_url().1);
+        client_evm::get_balance(&rpc_ep, self.inner.h160_address).await
+    }
+
+    /// Fetches the current nonce of the wallet owner's EVM account.
+    pub async fn nonce(&self) -> Result<u64> {
+        let rpc_ep = Self::evm_rpc_ep(&self.inner.pick_base_http_url().1);
+        let nonce = client_evm::get_transaction_count(&rpc_ep, self.inner.h160_address).await?;
+        Ok(nonce.as_u64())
+    }
+
+    /// Fetches the current base fee in wei, to compute the atomic tx fees.
+    pub async fn base_fee(&self) -> Result<U256> {
+        let rpc_ep = Self::evm_rpc_ep(&self.inner.pick_base_http_url().1);
+        client_evm::base_fee(&rpc_ep).await
+    }
+
+    /// Fetches all atomic UTXOs exported from the source chain
+    /// to the wallet owner's C-chain address.
+    pub async fn atomic_utxos(
+        &self,
+        source_blockchain_id: &ids::Id,
+    ) -> Result<Vec<txs::utxo::Utxo>> {
+        self.atomic_utxos_with_endpoint(&self.inner.pick_base_http_url().1, source_blockchain_id)
+            .await
+    }
+
+    /// Fetches all atomic UTXOs exported from the source chain
+    /// to the wallet owner's C-chain address from the specified HTTP endpoint.
+    pub async fn atomic_utxos_with_endpoint(
+        &self,
+        http_rpc: &str,
+        source_blockchain_id: &ids::Id,
+    ) -> Result<Vec<txs::utxo::Utxo>> {
+        let utxos: Vec<txs::utxo::Utxo> = self
+            .inner
+            .client
+            .c()
+            .get_all_utxos(
+                http_rpc,
+                std::slice::from_ref(&self.inner.c_address),
+                &source_blockchain_id.to_string(),
+            )
+            .try_collect()
+            .await?;
+        log::debug!("fetched {} atomic UTXOs via {}", utxos.len(), http_rpc);
+        Ok(utxos)
+    }
+
+    #[must_use]
+    pub fn export(&self) -> export::Tx<T> {
+        export::Tx::new(self)
+    }
+
+    #[must_use]
+    pub fn import(&self) -> import::Tx<T> {
+        import::Tx::new(self)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod c;
 pub mod p;
 pub mod x;
 
@@ -13,8 +14,7 @@ use std::{
 use crate::{
     errors::Result,
     ids::{self, short},
-    jsonrpc::client::{info as api_info, x as api_x},
-    key, utils,
+    jsonrpc, key, utils,
 };
 
 #[derive(Debug, Clone)]
@@ -26,17 +26,22 @@ pub struct Wallet<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone
     pub base_http_urls: Vec<String>,
     pub base_http_url_cursor: Arc<Mutex<usize>>, // to roundrobin
 
+    /// Shared JSON-RPC client, reused across all chain API calls.
+    pub client: jsonrpc::client::Client,
+
     pub network_id: u32,
     pub network_name: String,
 
     pub x_address: String,
     pub p_address: String,
+    pub c_address: String,
     pub short_address: short::Id,
     pub eth_address: String,
     pub h160_address: primitive_types::H160,
 
     pub blockchain_id_x: ids::Id,
     pub blockchain_id_p: ids::Id,
+    pub blockchain_id_c: ids::Id,
 
     pub avax_asset_id: ids::Id,
 
@@ -44,10 +49,16 @@ pub struct Wallet<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone
     pub tx_fee: u64,
     /// Transaction fee for adding a primary network validator.
     pub add_primary_network_validator_fee: u64,
+    /// Transaction fee for adding a primary network delegator.
+    pub add_primary_network_delegator_fee: u64,
     /// Transaction fee to create a new subnet.
     pub create_subnet_tx_fee: u64,
+    /// Transaction fee to transform a subnet into an elastic subnet.
+    pub transform_subnet_tx_fee: u64,
     /// Transaction fee to create a new blockchain.
     pub create_blockchain_tx_fee: u64,
+    /// Transaction fee to create a new asset.
+    pub create_asset_tx_fee: u64,
 }
 
 /// ref. <https://doc.rust-lang.org/std/string/trait.ToString.html>
@@ -65,12 +76,14 @@ where
 
         write!(f, "x_address: {}\n", self.x_address)?;
         write!(f, "p_address: {}\n", self.p_address)?;
+        write!(f, "c_address: {}\n", self.c_address)?;
         write!(f, "short_address: {}\n", self.short_address)?;
         write!(f, "eth_address: {}\n", self.eth_address)?;
         write!(f, "h160_address: {}\n", self.h160_address)?;
 
         write!(f, "blockchain_id_x: {}\n", self.blockchain_id_x)?;
         write!(f, "blockchain_id_p: {}\n", self.blockchain_id_p)?;
+        write!(f, "blockchain_id_c: {}\n", self.blockchain_id_c)?;
 
         write!(f, "avax_asset_id: {}\n", self.avax_asset_id)?;
 
@@ -80,12 +93,23 @@ where
             "add_primary_network_validator_fee: {}\n",
             self.add_primary_network_validator_fee
         )?;
+        write!(
+            f,
+            "add_primary_network_delegator_fee: {}\n",
+            self.add_primary_network_delegator_fee
+        )?;
         write!(f, "create_subnet_tx_fee: {}\n", self.create_subnet_tx_fee)?;
+        write!(
+            f,
+            "transform_subnet_tx_fee: {}\n",
+            self.transform_subnet_tx_fee
+        )?;
         write!(
             f,
             "create_blockchain_tx_fee: {}\n",
             self.create_blockchain_tx_fee
-        )
+        )?;
+        write!(f, "create_asset_tx_fee: {}\n", self.create_asset_tx_fee)
     }
 }
 
@@ -112,6 +136,7 @@ pub struct Builder<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clon
     pub key: T,
     pub base_http_urls: Vec<String>,
     pub only_evm: bool,
+    pub client: Option<jsonrpc::client::Client>,
 }
 
 impl<T> Builder<T>
@@ -123,6 +148,7 @@ where
             key: key.clone(),
             base_http_urls: Vec::new(),
             only_evm: false,
+            client: None,
         }
     }
 
@@ -158,6 +184,14 @@ where
         self
     }
 
+    /// Sets the JSON-RPC client to share across wallet calls.
+    /// If not set, a client with default options is created.
+    #[must_use]
+    pub fn client(mut self, client: jsonrpc::client::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
     /// Overwrites the HTTP rpc endpoints to the `urls` field in the Builder.
     /// If URL path is specified, it strips the URL path.
     #[must_use]
@@ -190,6 +224,12 @@ where
             self.base_http_urls.len()
         );
 
+        let client = if let Some(c) = &self.client {
+            c.clone()
+        } else {
+            jsonrpc::client::Client::new()?
+        };
+
         let keychain = key::secp256k1::keychain::Keychain::new(vec![self.key.clone()]);
         let h160_address = keychain.keys[0].h160_address();
 
@@ -198,10 +238,13 @@ where
             network_name,
             blockchain_id_x,
             blockchain_id_p,
+            blockchain_id_c,
             avax_asset_id,
             tx_fee,
             create_subnet_tx_fee,
+            transform_subnet_tx_fee,
             create_blockchain_tx_fee,
+            create_asset_tx_fee,
         ) = if self.only_evm {
             log::warn!("wallet is only used for EVM thus skipping querying info API");
             (
@@ -210,43 +253,58 @@ where
                 ids::Id::empty(),
                 ids::Id::empty(),
                 ids::Id::empty(),
+                ids::Id::empty(),
+                0,
+                0,
                 0,
                 0,
                 0,
             )
         } else {
-            let resp = api_info::get_network_id(&self.base_http_urls[0]).await?;
+            let info = client.info();
+            let resp = info.get_network_id(&self.base_http_urls[0]).await?;
             let network_id = resp.result.unwrap().network_id;
-            let resp = api_info::get_network_name(&self.base_http_urls[0]).await?;
+            let resp = info.get_network_name(&self.base_http_urls[0]).await?;
             let network_name = resp.result.unwrap().network_name;
 
-            let resp = api_info::get_blockchain_id(&self.base_http_urls[0], "X").await?;
+            let resp = info.get_blockchain_id(&self.base_http_urls[0], "X").await?;
             let blockchain_id_x = resp.result.unwrap().blockchain_id;
 
-            let resp = api_info::get_blockchain_id(&self.base_http_urls[0], "P").await?;
+            let resp = info.get_blockchain_id(&self.base_http_urls[0], "P").await?;
             let blockchain_id_p = resp.result.unwrap().blockchain_id;
 
-            let resp = api_x::get_asset_description(&self.base_http_urls[0], "AVAX").await?;
+            let resp = info.get_blockchain_id(&self.base_http_urls[0], "C").await?;
+            let blockchain_id_c = resp.result.unwrap().blockchain_id;
+
+            let resp = client
+                .x()
+                .get_asset_description(&self.base_http_urls[0], "AVAX")
+                .await?;
             let resp = resp
                 .result
                 .expect("unexpected None GetAssetDescriptionResult");
             let avax_asset_id = resp.asset_id;
 
-            let resp = api_info::get_tx_fee(&self.base_http_urls[0]).await?;
+            let resp = info.get_tx_fee(&self.base_http_urls[0]).await?;
             let get_tx_fee_result = resp.result.unwrap();
             let tx_fee = get_tx_fee_result.tx_fee;
             let create_subnet_tx_fee = get_tx_fee_result.create_subnet_tx_fee;
+            let transform_subnet_tx_fee = get_tx_fee_result.transform_subnet_tx_fee;
             let create_blockchain_tx_fee = get_tx_fee_result.create_blockchain_tx_fee;
+            let create_asset_tx_fee = get_tx_fee_result.create_asset_tx_fee;
 
             (
                 network_id,
                 network_name,
                 blockchain_id_x,
                 blockchain_id_p,
+                blockchain_id_c,
                 avax_asset_id,
                 tx_fee,
                 create_subnet_tx_fee,
+                transform_subnet_tx_fee,
                 create_blockchain_tx_fee,
+                create_asset_tx_fee,
             )
         };
 
@@ -256,25 +314,31 @@ where
 
             base_http_urls: self.base_http_urls.clone(),
             base_http_url_cursor: Arc::new(Mutex::new(0)),
+            client,
 
             network_id,
             network_name,
 
             x_address: self.key.hrp_address(network_id, "X").unwrap(),
             p_address: self.key.hrp_address(network_id, "P").unwrap(),
+            c_address: self.key.hrp_address(network_id, "C").unwrap(),
             short_address: self.key.short_address().unwrap(),
             eth_address: self.key.eth_address(),
             h160_address,
 
             blockchain_id_x,
             blockchain_id_p,
+            blockchain_id_c,
 
             avax_asset_id,
 
             tx_fee,
             add_primary_network_validator_fee: ADD_PRIMARY_NETWORK_VALIDATOR_FEE,
+            add_primary_network_delegator_fee: ADD_PRIMARY_NETWORK_DELEGATOR_FEE,
             create_subnet_tx_fee,
+            transform_subnet_tx_fee,
             create_blockchain_tx_fee,
+            create_asset_tx_fee,
         };
         log::info!("initiated the wallet:\n{}", w);
 
@@ -284,3 +348,6 @@ where
 
 /// ref. <https://docs.avax.network/learn/platform-overview/transaction-fees/#fee-schedule>
 pub const ADD_PRIMARY_NETWORK_VALIDATOR_FEE: u64 = 0;
+
+/// ref. <https://docs.avax.network/learn/platform-overview/transaction-fees/#fee-schedule>
+pub const ADD_PRIMARY_NETWORK_DELEGATOR_FEE: u64 = 0;
@@ -0,0 +1,401 @@
+use std::time::SystemTime;
+
+use crate::{
+    errors::{Error, Result},
+    formatting,
+    ids::{self, node},
+    key, platformvm, txs, units,
+};
+use chrono::{DateTime, Utc};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Represents P-chain "AddPermissionlessDelegator" transaction.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewAddPermissionlessDelegatorTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::p::P<T>,
+
+    /// Node Id of the validator to delegate to.
+    pub node_id: node::Id,
+
+    /// Empty for the primary network.
+    pub subnet_id: ids::Id,
+
+    /// Asset to stake, which must be the staking asset of the subnet
+    /// (set by "TransformSubnetTx"). Defaults to AVAX.
+    pub stake_asset_id: ids::Id,
+
+    /// Denominated in the smallest unit of the staking asset
+    /// (nano-AVAX for the primary network).
+    pub stake_amount: u64,
+
+    /// The delegation period must be a subset of the validation period.
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+
+    /// Owner of the delegation rewards.
+    /// Defaults to the wallet owner.
+    pub rewards_owner: key::secp256k1::txs::OutputOwners,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(p: &crate::wallet::p::P<T>) -> Self {
+        let now_unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        let start_time = now_unix + 60;
+        let start_time = DateTime::<Utc>::from_timestamp(start_time as i64, 0).unwrap();
+
+        // 14-day + 5-min, same as the default validate period
+        // must be bounded by the validator's end time
+        // otherwise "delegator's staking period must be a subset of the validator's"
+        let end_time = now_unix + 14 * 24 * 60 * 60 + 5 * 60;
+        let end_time = DateTime::<Utc>::from_timestamp(end_time as i64, 0).unwrap();
+
+        Self {
+            inner: p.clone(),
+            node_id: node::Id::empty(),
+            subnet_id: ids::Id::empty(),
+            stake_asset_id: p.inner.avax_asset_id,
+            stake_amount: 25 * units::AVAX,
+            start_time,
+            end_time,
+            rewards_owner: key::secp256k1::txs::OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![p.inner.short_address.clone()],
+            },
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_secs(62), // enough to elapse delegate start time
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the validator node Id to delegate to.
+    #[must_use]
+    pub fn node_id(mut self, node_id: node::Id) -> Self {
+        self.node_id = node_id;
+        self
+    }
+
+    /// Sets the subnet Id.
+    #[must_use]
+    pub fn subnet_id(mut self, subnet_id: ids::Id) -> Self {
+        self.subnet_id = subnet_id;
+        self
+    }
+
+    /// Sets the asset Id to stake.
+    #[must_use]
+    pub fn stake_asset_id(mut self, stake_asset_id: ids::Id) -> Self {
+        self.stake_asset_id = stake_asset_id;
+        self
+    }
+
+    /// Sets the stake amount.
+    #[must_use]
+    pub fn stake_amount(mut self, stake_amount: u64) -> Self {
+        self.stake_amount = stake_amount;
+        self
+    }
+
+    /// Sets the delegate start time.
+    #[must_use]
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Sets the delegate end time.
+    #[must_use]
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = end_time;
+        self
+    }
+
+    /// Sets the delegate start/end time in days from 'offset_seconds' later.
+    #[must_use]
+    pub fn delegate_period_in_days(mut self, days: u64, offset_seconds: u64) -> Self {
+        let now_unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        let start_time = now_unix + offset_seconds;
+        let start_time = DateTime::<Utc>::from_timestamp(start_time as i64, 0).unwrap();
+
+        let end_time = now_unix + days * 24 * 60 * 60;
+        let end_time = DateTime::<Utc>::from_timestamp(end_time as i64, 0).unwrap();
+
+        self.start_time = start_time;
+        self.end_time = end_time;
+        self
+    }
+
+    /// Sets the owner of the delegation rewards.
+    #[must_use]
+    pub fn rewards_owner(mut self, rewards_owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.rewards_owner = rewards_owner;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Issues the add permissionless delegator transaction and returns the transaction Id.
+    /// The boolean return represents whether the "add_permissionless_delegator" request was
+    /// successfully issued or not (regardless of its acceptance).
+    /// Fails if the node is not a current validator of the subnet.
+    pub async fn issue(&self) -> Result<(ids::Id, bool)> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "adding permissionless delegator to '{}' for subnet '{}' with stake amount {} of asset '{}' via {}",
+            self.node_id,
+            self.subnet_id,
+            self.stake_amount,
+            self.stake_asset_id,
+            picked_http_rpc.1
+        );
+
+        let is_validator = self
+            .inner
+            .is_subnet_validator(&self.node_id, &self.subnet_id)
+            .await?;
+        if !is_validator {
+            return Err(Error::Other {
+                message: format!(
+                    "node Id '{}' is not a validator for subnet '{}', can't delegate",
+                    self.node_id, self.subnet_id
+                ),
+                retryable: false,
+            });
+        }
+
+        // subnet delegator fee is same as the tx fee
+        // ref. "avalanchego/vms/platformvm/config.Config.AddSubnetDelegatorFee"
+        let fee = if self.subnet_id.is_empty() {
+            self.inner.inner.add_primary_network_delegator_fee
+        } else {
+            self.inner.inner.tx_fee
+        };
+
+        // the balance only counts AVAX, so the non-AVAX stake amount
+        // is checked while spending the UTXOs
+        let required = if self.stake_asset_id == self.inner.inner.avax_asset_id {
+            self.stake_amount + fee
+        } else {
+            fee
+        };
+        let cur_balance_p = self.inner.balance().await?;
+        if cur_balance_p < required {
+            return Err(Error::Other {
+                message: format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, required),
+                retryable: false,
+            });
+        };
+        log::info!(
+            "{} current P-chain balance {}",
+            self.inner.inner.p_address,
+            cur_balance_p
+        );
+
+        let (ins, unstaked_outs, staked_outs, signers) = self
+            .inner
+            .spend_with_stake_asset(self.stake_asset_id, self.stake_amount, fee)
+            .await?;
+
+        let mut tx = platformvm::txs::add_permissionless_delegator::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_p,
+                transferable_outputs: Some(unstaked_outs),
+                transferable_inputs: Some(ins),
+                ..Default::default()
+            },
+            validator: platformvm::txs::Validator {
+                node_id: self.node_id,
+                start: self.start_time.timestamp() as u64,
+                end: self.end_time.timestamp() as u64,
+                weight: self.stake_amount,
+            },
+
+            // empty id for primary network
+            subnet_id: self.subnet_id,
+
+            stake_transferable_outputs: Some(staked_outs),
+            delegation_rewards_owner: self.rewards_owner.clone(),
+            ..Default::default()
+        };
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok((tx.base_tx.metadata.unwrap().id, false));
+        }
+
+        let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = self
+            .inner
+            .inner
+            .client
+            .p()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!(
+                    "failed to issue add permissionless delegator transaction {:?}",
+                    e
+                ),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok((tx_id, true));
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm add permissionless delegator transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = self
+                .inner
+                .inner
+                .client
+                .p()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
+
+            let status = resp.result.unwrap().status;
+            if status == platformvm::txs::status::Status::Committed {
+                log::info!("{} successfully committed", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        log::info!("polling to confirm delegator");
+        success = false;
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let is_delegator = self
+                .inner
+                .is_subnet_delegator(&self.node_id, &self.subnet_id, &tx_id)
+                .await?;
+            if is_delegator {
+                log::info!(
+                    "{} is now delegating to '{}' for subnet '{}'",
+                    tx_id,
+                    self.node_id,
+                    self.subnet_id
+                );
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} is not delegating to '{}' yet (elapsed {:?})",
+                tx_id,
+                self.node_id,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check permissionless delegator acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok((tx_id, true))
+    }
+}
@@ -4,7 +4,6 @@ use crate::{
     errors::{Error, Result},
     formatting,
     ids::{self, node},
-    jsonrpc::client::p as client_p,
     key, platformvm, txs, units,
 };
 use chrono::{DateTime, NaiveDateTime, Utc};
@@ -258,7 +257,13 @@ where
 
         let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
         let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_p::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        let resp = self
+            .inner
+            .inner
+            .client
+            .p()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
 
         if let Some(e) = resp.error {
             // handle duplicate validator
@@ -301,7 +306,13 @@ where
                 break;
             }
 
-            let resp = client_p::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+            let resp = self
+                .inner
+                .inner
+                .client
+                .p()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
 
             let status = resp.result.unwrap().status;
             if status == platformvm::txs::status::Status::Committed {
@@ -2,9 +2,7 @@ use std::time::SystemTime;
 
 use crate::{
     errors::{Error, Result},
-    formatting, ids,
-    jsonrpc::client::p as client_p,
-    key, platformvm, txs,
+    formatting, ids, key, platformvm, txs,
 };
 use tokio::time::{sleep, Duration, Instant};
 
@@ -95,7 +93,6 @@ where
 
     /// Issues the import transaction and returns the transaction Id.
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewImportTx"
-    /// TODO: not working... cache exported Utxos
     pub async fn issue(&self) -> Result<ids::Id> {
         let picked_http_rpc = self.inner.inner.pick_base_http_url();
         log::info!(
@@ -104,15 +101,10 @@ where
             picked_http_rpc.1
         );
 
-        let utxos = client_p::get_utxos(&picked_http_rpc.1, &self.inner.inner.p_address).await?;
-        let utxos_result = utxos.result.unwrap();
-        let utxos = utxos_result.utxos.unwrap();
-        log::debug!(
-            "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
-            utxos_result.num_fetched,
-            utxos_result.end_index,
-            utxos.len()
-        );
+        let utxos = self
+            .inner
+            .utxos_with_endpoint(&picked_http_rpc.1, Some(&self.source_blockchain_id))
+            .await?;
 
         // ref. "avalanchego/vms/avm#Service.SendMultiple"
         let now_unix = SystemTime::now()
@@ -206,7 +198,13 @@ where
 
         let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
         let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_p::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        let resp = self
+            .inner
+            .inner
+            .client
+            .p()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
 
         if let Some(e) = resp.error {
             return Err(Error::API {
@@ -235,7 +233,13 @@ where
                 break;
             }
 
-            let resp = client_p::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+            let resp = self
+                .inner
+                .inner
+                .client
+                .p()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
 
             let status = resp.result.unwrap().status;
             if status == platformvm::txs::status::Status::Committed {
@@ -1,3 +1,5 @@
+pub mod add_delegator;
+pub mod add_permissionless_delegator;
 pub mod add_permissionless_validator;
 pub mod add_subnet_validator;
 pub mod add_validator;
@@ -5,15 +7,17 @@ pub mod create_chain;
 pub mod create_subnet;
 pub mod export;
 pub mod import;
+pub mod remove_subnet_validator;
+pub mod transform_subnet;
 
-use std::{cmp, time::SystemTime};
+use std::{cmp, collections::HashMap, time::SystemTime};
 
 use crate::{
     errors::{Error, Result},
     ids::{self, node},
-    jsonrpc::client::p as client_p,
     key, platformvm, txs, wallet,
 };
+use futures::TryStreamExt;
 
 impl<T> wallet::Wallet<T>
 where
@@ -41,7 +45,12 @@ where
 {
     /// Fetches the current balance of the wallet owner from the specified HTTP endpoint.
     pub async fn balance_with_endpoint(&self, http_rpc: &str) -> Result<u64> {
-        let resp = client_p::get_balance(http_rpc, &self.inner.p_address).await?;
+        let resp = self
+            .inner
+            .client
+            .p()
+            .get_balance(http_rpc, &self.inner.p_address)
+            .await?;
         let cur_balance = resp
             .result
             .expect("unexpected None GetBalanceResult")
@@ -66,23 +75,44 @@ where
             .await
     }
 
-    /// Fetches UTXOs for "P" chain.
+    /// Fetches all UTXOs of the wallet owner for "P" chain.
     /// TODO: cache this like avalanchego
     pub async fn utxos(&self) -> Result<Vec<txs::utxo::Utxo>> {
-        let resp =
-            client_p::get_utxos(&self.inner.pick_base_http_url().1, &self.inner.p_address).await?;
-        let utxos = resp
-            .result
-            .expect("unexpected None GetUtxosResult")
-            .utxos
-            .expect("unexpected None Utxos");
+        self.utxos_with_endpoint(&self.inner.pick_base_http_url().1, None)
+            .await
+    }
+
+    /// Fetches all UTXOs of the wallet owner for "P" chain from the specified HTTP endpoint.
+    /// Set "source_blockchain_id" to fetch the atomic UTXOs exported from the source chain.
+    pub async fn utxos_with_endpoint(
+        &self,
+        http_rpc: &str,
+        source_blockchain_id: Option<&ids::Id>,
+    ) -> Result<Vec<txs::utxo::Utxo>> {
+        let source_chain = source_blockchain_id.map(|id| id.to_string());
+        let utxos: Vec<txs::utxo::Utxo> = self
+            .inner
+            .client
+            .p()
+            .get_all_utxos(
+                http_rpc,
+                std::slice::from_ref(&self.inner.p_address),
+                source_chain.as_deref(),
+            )
+            .try_collect()
+            .await?;
+        log::debug!("fetched {} UTXOs via {}", utxos.len(), http_rpc);
         Ok(utxos)
     }
 
     /// Returns "true" if the node_id is a current primary network validator.
     pub async fn is_primary_network_validator(&self, node_id: &node::Id) -> Result<bool> {
-        let resp =
-            client_p::get_primary_network_validators(&self.inner.pick_base_http_url().1).await?;
+        let resp = self
+            .inner
+            .client
+            .p()
+            .get_primary_network_validators(&self.inner.pick_base_http_url().1)
+            .await?;
         let resp = resp
             .result
             .expect("unexpected None GetCurrentValidatorResult");
@@ -96,17 +126,55 @@ where
         Ok(false)
     }
 
+    /// Returns "true" if the delegation tx is a current delegator
+    /// of the primary network validator "node_id".
+    pub async fn is_primary_network_delegator(
+        &self,
+        node_id: &node::Id,
+        tx_id: &ids::Id,
+    ) -> Result<bool> {
+        let resp = self
+            .inner
+            .client
+            .p()
+            .get_primary_network_validators(&self.inner.pick_base_http_url().1)
+            .await?;
+        let resp = resp
+            .result
+            .expect("unexpected None GetCurrentValidatorResult");
+        let validators = resp.validators.expect("unexpected None vaidators");
+        for validator in validators.iter() {
+            if !validator.node_id.eq(node_id) {
+                continue;
+            }
+            if let Some(delegators) = &validator.delegators {
+                for delegator in delegators.iter() {
+                    log::info!(
+                        "listing primary network delegator {} for {}",
+                        delegator.tx_id,
+                        node_id
+                    );
+                    if delegator.tx_id.eq(tx_id) {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
+
     /// Returns "true" if the node_id is a current subnet validator.
     pub async fn is_subnet_validator(
         &self,
         node_id: &node::Id,
         subnet_id: &ids::Id,
     ) -> Result<bool> {
-        let resp = client_p::get_subnet_validators(
-            &self.inner.pick_base_http_url().1,
-            &subnet_id.to_string(),
-        )
-        .await?;
+        let resp = self
+            .inner
+            .client
+            .p()
+            .get_subnet_validators(&self.inner.pick_base_http_url().1, &subnet_id.to_string())
+            .await?;
         let resp = resp
             .result
             .expect("unexpected None GetCurrentValidatorResult");
@@ -124,6 +192,79 @@ where
         Ok(false)
     }
 
+    /// Returns "true" if the node_id is a pending validator of the subnet,
+    /// which has been added but has not started validating yet.
+    /// Use the empty subnet Id for the primary network.
+    pub async fn is_pending_subnet_validator(
+        &self,
+        node_id: &node::Id,
+        subnet_id: &ids::Id,
+    ) -> Result<bool> {
+        let resp = self
+            .inner
+            .client
+            .p()
+            .get_pending_validators(
+                &self.inner.pick_base_http_url().1,
+                Some(*subnet_id),
+                Some(vec![*node_id]),
+            )
+            .await?;
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!("failed to get pending validators {:?}", e),
+                retryable: false,
+            });
+        }
+        let resp = resp
+            .result
+            .expect("unexpected None GetPendingValidatorsResult");
+        Ok(resp
+            .validators
+            .iter()
+            .any(|validator| validator.node_id.eq(node_id)))
+    }
+
+    /// Returns "true" if the delegation tx is a current delegator
+    /// of the subnet validator "node_id".
+    /// Use the empty subnet Id for the primary network.
+    pub async fn is_subnet_delegator(
+        &self,
+        node_id: &node::Id,
+        subnet_id: &ids::Id,
+        tx_id: &ids::Id,
+    ) -> Result<bool> {
+        let resp = self
+            .inner
+            .client
+            .p()
+            .get_subnet_validators(&self.inner.pick_base_http_url().1, &subnet_id.to_string())
+            .await?;
+        let resp = resp
+            .result
+            .expect("unexpected None GetCurrentValidatorResult");
+        let validators = resp.validators.expect("unexpected None vaidators");
+        for validator in validators.iter() {
+            if !validator.node_id.eq(node_id) {
+                continue;
+            }
+            if let Some(delegators) = &validator.delegators {
+                for delegator in delegators.iter() {
+                    log::info!(
+                        "listing subnet delegator {} for {} in subnet {}",
+                        delegator.tx_id,
+                        node_id,
+                        subnet_id
+                    );
+                    if delegator.tx_id.eq(tx_id) {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
+
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/utxo/handler.go#L169> "Spend"
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go#L325-L358> "NewAddValidatorTx"
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/builder/builder.go#L428> "NewAddValidatorTx"
@@ -136,6 +277,25 @@ where
         Vec<txs::transferable::Output>,
         Vec<txs::transferable::Output>,
         Vec<Vec<T>>,
+    )> {
+        self.spend_with_stake_asset(self.inner.avax_asset_id, amount, fee)
+            .await
+    }
+
+    /// Same as "spend" but stakes "amount" of the "stake_asset_id"
+    /// (e.g., the staking asset of an elastic subnet), while the fee
+    /// is always burned in AVAX.
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "spend"
+    async fn spend_with_stake_asset(
+        &self,
+        stake_asset_id: ids::Id,
+        amount: u64,
+        fee: u64,
+    ) -> Result<(
+        Vec<txs::transferable::Input>,
+        Vec<txs::transferable::Output>,
+        Vec<txs::transferable::Output>,
+        Vec<Vec<T>>,
     )> {
         let utxos = self.utxos().await?;
 
@@ -149,7 +309,7 @@ where
         let mut staked_outputs: Vec<txs::transferable::Output> = Vec::new();
         let mut signers: Vec<Vec<T>> = Vec::new();
 
-        // amount of AVAX that has been staked
+        // amount of the staking asset that has been staked
         let mut amount_staked: u64 = 0_u64;
 
         // consume locked UTXOs
@@ -160,8 +320,8 @@ where
                 break;
             }
 
-            // only staking avax so ignore other assets
-            if utxo.asset_id != self.inner.avax_asset_id {
+            // only staking the stake asset so ignore other assets
+            if utxo.asset_id != stake_asset_id {
                 continue;
             }
 
@@ -178,16 +338,15 @@ where
                 continue;
             }
 
-            // check "*secp256k1fx.TransferOutput"
-            let inner = out.clone().transfer_output;
-            let res = self.inner.keychain.spend(&inner, now_unix);
+            // spend the locked output with "*platformvm.StakeableLockIn"
+            let res = self.inner.keychain.spend(&out, now_unix);
             if res.is_none() {
                 // cannot spend the output, move onto next
                 continue;
             }
-            let (transfer_input, in_signers) = res.unwrap();
+            let (stakeable_lock_in, in_signers) = res.unwrap();
 
-            let mut remaining_value = transfer_input.amount;
+            let mut remaining_value = stakeable_lock_in.transfer_input.amount;
             let amount_to_stake = cmp::min(
                 amount - amount_staked, // amount we still need to stake
                 remaining_value,        // amount available to stake
@@ -199,10 +358,7 @@ where
             ins.push(txs::transferable::Input {
                 utxo_id: utxo.utxo_id.clone(),
                 asset_id: utxo.asset_id,
-                stakeable_lock_in: Some(platformvm::txs::StakeableLockIn {
-                    locktime: out.locktime,
-                    transfer_input,
-                }),
+                stakeable_lock_in: Some(stakeable_lock_in),
                 ..txs::transferable::Input::default()
             });
 
@@ -212,7 +368,7 @@ where
                 stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
                     locktime: out.clone().locktime,
                     transfer_output: key::secp256k1::txs::transfer::Output {
-                        amount: remaining_value,
+                        amount: amount_to_stake,
                         output_owners: out.clone().transfer_output.output_owners,
                     },
                 }),
@@ -227,7 +383,7 @@ where
                     stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
                         locktime: out.clone().locktime,
                         transfer_output: key::secp256k1::txs::transfer::Output {
-                            amount: amount_to_stake,
+                            amount: remaining_value,
                             output_owners: out.clone().transfer_output.output_owners,
                         },
                     }),
@@ -248,8 +404,12 @@ where
                 break;
             }
 
-            // only burn AVAX, thus ignore other assets
-            if utxo.asset_id != self.inner.avax_asset_id {
+            // only burn AVAX and stake the stake asset, thus ignore other assets
+            let (to_burn, to_stake) = (
+                utxo.asset_id == self.inner.avax_asset_id && amount_burned < fee,
+                utxo.asset_id == stake_asset_id && amount_staked < amount,
+            );
+            if !to_burn && !to_stake {
                 continue;
             }
 
@@ -277,17 +437,25 @@ where
 
             // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L763
             let mut remaining_value = transfer_input.amount;
-            let amount_to_burn = cmp::min(
-                fee - amount_burned, // amount we still need to burn
-                remaining_value,     // amount available to burn
-            );
+            let amount_to_burn = if to_burn {
+                cmp::min(
+                    fee - amount_burned, // amount we still need to burn
+                    remaining_value,     // amount available to burn
+                )
+            } else {
+                0
+            };
             amount_burned += amount_to_burn;
             remaining_value -= amount_to_burn;
 
-            let amount_to_stake = cmp::min(
-                amount - amount_staked, // amount we still need to stake
-                remaining_value,        // amount available to stake
-            );
+            let amount_to_stake = if to_stake {
+                cmp::min(
+                    amount - amount_staked, // amount we still need to stake
+                    remaining_value,        // amount available to stake
+                )
+            } else {
+                0
+            };
             amount_staked += amount_to_stake;
             remaining_value -= amount_to_stake;
 
@@ -363,6 +531,114 @@ where
         Ok((ins, returned_outputs, staked_outputs, signers))
     }
 
+    /// Consumes the unlocked UTXOs to burn "to_burn" of each asset without staking,
+    /// and returns the inputs, the change outputs and the signers.
+    /// The UTXOs that are still stakeable locked are skipped, since only
+    /// the unlocked inputs count toward the burned amounts in the flow check.
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "spend"
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/utxo/handler.go> "VerifySpendUTXOs"
+    async fn burn(
+        &self,
+        to_burn: &HashMap<ids::Id, u64>,
+    ) -> Result<(
+        Vec<txs::transferable::Input>,
+        Vec<txs::transferable::Output>,
+        Vec<Vec<T>>,
+    )> {
+        let utxos = self.utxos().await?;
+
+        let now_unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        let mut ins: Vec<txs::transferable::Input> = Vec::new();
+        let mut returned_outputs: Vec<txs::transferable::Output> = Vec::new();
+        let mut signers: Vec<Vec<T>> = Vec::new();
+
+        // amount of each asset that still needs to be burned
+        let mut remaining_to_burn = to_burn.clone();
+
+        for utxo in utxos.iter() {
+            let remaining = match remaining_to_burn.get_mut(&utxo.asset_id) {
+                Some(remaining) if *remaining > 0 => remaining,
+                // no need to burn this asset, or burned enough of it
+                _ => continue,
+            };
+
+            let out = if let Some(out) = &utxo.transfer_output {
+                out.clone()
+            } else if let Some(inner) = &utxo.stakeable_lock_out {
+                if inner.locktime > now_unix {
+                    // output is currently locked, so this output cannot be burned
+                    continue;
+                }
+                inner.transfer_output.clone()
+            } else {
+                continue;
+            };
+
+            let res = self.inner.keychain.spend(&out, now_unix);
+            if res.is_none() {
+                // cannot spend the output, move onto next
+                continue;
+            }
+            let (transfer_input, in_signers) = res.unwrap();
+
+            let amount_to_burn = cmp::min(
+                *remaining,            // amount we still need to burn
+                transfer_input.amount, // amount available to burn
+            );
+            *remaining -= amount_to_burn;
+            let remaining_value = transfer_input.amount - amount_to_burn;
+
+            // add the input to the consumed inputs
+            ins.push(txs::transferable::Input {
+                utxo_id: utxo.utxo_id.clone(),
+                asset_id: utxo.asset_id,
+                transfer_input: Some(transfer_input),
+                ..txs::transferable::Input::default()
+            });
+
+            if remaining_value > 0 {
+                // this input had extra value, so some must be returned
+                returned_outputs.push(txs::transferable::Output {
+                    asset_id: utxo.asset_id,
+                    transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                        amount: remaining_value,
+                        output_owners: key::secp256k1::txs::OutputOwners {
+                            locktime: 0,
+                            threshold: 1,
+                            addresses: vec![self.inner.short_address.clone()],
+                        },
+                    }),
+                    ..txs::transferable::Output::default()
+                });
+            }
+
+            signers.push(in_signers);
+        }
+
+        for (asset_id, remaining) in remaining_to_burn.iter() {
+            if *remaining > 0 {
+                return Err(Error::Other {
+                    message: format!(
+                        "provided keys have insufficient unlocked funds of asset {} (need {} more to burn)",
+                        asset_id, remaining
+                    ),
+                    retryable: false,
+                });
+            }
+        }
+
+        // TODO: for now just ignore "signers" in the sorting
+        // since the wallet currently only supports one key
+        ins.sort();
+        returned_outputs.sort();
+
+        Ok((ins, returned_outputs, signers))
+    }
+
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/utxo/handler.go#L411> "Authorize"
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go#L360-L390> "NewAddSubnetValidatorTx"
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/builder/builder.go#L512> "NewAddSubnetValidatorTx"
@@ -372,8 +648,12 @@ where
     ) -> Result<(key::secp256k1::txs::Input, Vec<Vec<T>>)> {
         log::info!("authorizing subnet {}", subnet_id);
 
-        let tx =
-            client_p::get_tx(&self.inner.pick_base_http_url().1, &subnet_id.to_string()).await?;
+        let tx = self
+            .inner
+            .client
+            .p()
+            .get_tx(&self.inner.pick_base_http_url().1, &subnet_id.to_string())
+            .await?;
         if let Some(tx_result) = tx.result {
             let output_owners = tx_result.tx.unsigned_tx.output_owners;
 
@@ -416,11 +696,24 @@ where
         add_validator::Tx::new(self)
     }
 
+    /// Delegates stake to a current primary network validator.
+    #[must_use]
+    pub fn add_delegator(&self) -> add_delegator::Tx<T> {
+        add_delegator::Tx::new(self)
+    }
+
     #[must_use]
     pub fn add_permissionless_validator(&self) -> add_permissionless_validator::Tx<T> {
         add_permissionless_validator::Tx::new(self)
     }
 
+    /// Delegates the staking asset to a current validator of
+    /// the primary network or an elastic subnet.
+    #[must_use]
+    pub fn add_permissionless_delegator(&self) -> add_permissionless_delegator::Tx<T> {
+        add_permissionless_delegator::Tx::new(self)
+    }
+
     /// Once subnet is created, the avalanche node must whitelist the subnet Id
     /// (the returned/confirmed transaction Id).
     #[must_use]
@@ -435,6 +728,21 @@ where
         add_subnet_validator::Tx::new(self)
     }
 
+    /// Removes the validator from the subnet, authorized by the subnet owner
+    /// (e.g., to rotate a faulty node).
+    #[must_use]
+    pub fn remove_subnet_validator(&self) -> remove_subnet_validator::Tx<T> {
+        remove_subnet_validator::Tx::new(self)
+    }
+
+    /// Transforms the permissioned subnet into an elastic subnet
+    /// staked with its own asset. Once transformed, the validators
+    /// and delegators must be added with the permissionless txs.
+    #[must_use]
+    pub fn transform_subnet(&self) -> transform_subnet::Tx<T> {
+        transform_subnet::Tx::new(self)
+    }
+
     /// Once the subnet validators are added, each virtual machine must create
     /// its own blockchain and use the chain Id as the RPC endpoint.
     #[must_use]
@@ -0,0 +1,279 @@
+use crate::{
+    errors::{Error, Result},
+    formatting,
+    ids::{self, node},
+    key, platformvm, txs,
+};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Represents P-chain "RemoveSubnetValidator" transaction.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewRemoveSubnetValidatorTx"
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/builder/builder.go> "NewRemoveSubnetValidatorTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::p::P<T>,
+
+    pub node_id: node::Id,
+    pub subnet_id: ids::Id,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(p: &crate::wallet::p::P<T>) -> Self {
+        Self {
+            inner: p.clone(),
+            node_id: node::Id::empty(),
+            subnet_id: ids::Id::empty(),
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_secs(5),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the subnet validator node Id to remove.
+    #[must_use]
+    pub fn node_id(mut self, node_id: node::Id) -> Self {
+        self.node_id = node_id;
+        self
+    }
+
+    /// Sets the subnet Id.
+    #[must_use]
+    pub fn subnet_id(mut self, subnet_id: ids::Id) -> Self {
+        self.subnet_id = subnet_id;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Issues the remove subnet validator transaction and returns the transaction Id.
+    /// The boolean return represents whether the "remove_subnet_validator" request was
+    /// successfully issued or not (regardless of its acceptance).
+    /// If the node is neither a current nor a pending subnet validator,
+    /// it returns an empty Id and false.
+    pub async fn issue(&self) -> Result<(ids::Id, bool)> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "removing {} from subnet {} validators via {}",
+            self.node_id,
+            self.subnet_id,
+            picked_http_rpc.1
+        );
+
+        let is_validator = self.is_current_or_pending_validator().await?;
+        if !is_validator {
+            log::warn!(
+                "node Id {} is neither a current nor a pending subnet validator -- returning empty tx Id",
+                self.node_id
+            );
+            return Ok((ids::Id::empty(), false));
+        }
+
+        let cur_balance_p = self.inner.balance().await?;
+        if cur_balance_p < self.inner.inner.tx_fee {
+            return Err(Error::Other {
+                message: format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, self.inner.inner.tx_fee),
+                retryable: false,
+            });
+        };
+        log::info!(
+            "{} current P-chain balance {}",
+            self.inner.inner.p_address,
+            cur_balance_p
+        );
+
+        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, self.inner.inner.tx_fee).await?;
+        let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
+
+        let mut tx = platformvm::txs::remove_subnet_validator::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_p,
+                transferable_outputs: Some(unstaked_outs),
+                transferable_inputs: Some(ins),
+                ..Default::default()
+            },
+            node_id: self.node_id,
+            subnet_id: self.subnet_id,
+            // same subnet owner authorization as "add_subnet_validator"
+            subnet_auth,
+            ..Default::default()
+        };
+        tx.sign([signers, subnet_signers].concat()).await?;
+
+        if self.dry_mode {
+            return Ok((tx.base_tx.metadata.unwrap().id, false));
+        }
+
+        let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = self
+            .inner
+            .inner
+            .client
+            .p()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!(
+                    "failed to issue remove subnet validator transaction {:?}",
+                    e
+                ),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok((tx_id, true));
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm remove subnet validator transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = self
+                .inner
+                .inner
+                .client
+                .p()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
+
+            let status = resp.result.unwrap().status;
+            if status == platformvm::txs::status::Status::Committed {
+                log::info!("{} successfully committed", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        log::info!("polling to confirm subnet validator removal");
+        success = false;
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let is_validator = self.is_current_or_pending_validator().await?;
+            if !is_validator {
+                log::info!("node Id {} is no longer a subnet validator", self.node_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "node Id {} is still a subnet validator (elapsed {:?})",
+                self.node_id,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check subnet validator removal in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok((tx_id, true))
+    }
+
+    /// Returns "true" if the node is a current or a pending subnet validator,
+    /// both of which can be removed.
+    /// ref. "avalanchego/vms/platformvm/txs/executor.removeSubnetValidatorValidation"
+    async fn is_current_or_pending_validator(&self) -> Result<bool> {
+        if self
+            .inner
+            .is_subnet_validator(&self.node_id, &self.subnet_id)
+            .await?
+        {
+            return Ok(true);
+        }
+        self.inner
+            .is_pending_subnet_validator(&self.node_id, &self.subnet_id)
+            .await
+    }
+}
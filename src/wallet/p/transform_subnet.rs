@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+
+use crate::{
+    errors::{Error, Result},
+    formatting, ids, key, platformvm, txs,
+};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Represents P-chain "TransformSubnet" transaction.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewTransformSubnetTx"
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/executor/standard_tx_executor.go> "TransformSubnetTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::p::P<T>,
+
+    pub subnet_id: ids::Id,
+
+    /// Staking asset of the elastic subnet, which must be already
+    /// imported to the P-chain.
+    pub asset_id: ids::Id,
+    pub initial_supply: u64,
+    pub maximum_supply: u64,
+
+    /// Denominated in "platformvm::txs::transform_subnet::PERCENT_DENOMINATOR".
+    pub min_consumption_rate: u64,
+    pub max_consumption_rate: u64,
+
+    pub min_validator_stake: u64,
+    pub max_validator_stake: u64,
+
+    /// Denominated in seconds.
+    pub min_stake_duration: u32,
+    pub max_stake_duration: u32,
+
+    /// Denominated in "platformvm::txs::transform_subnet::PERCENT_DENOMINATOR".
+    pub min_delegation_fee: u32,
+    pub min_delegator_stake: u64,
+    pub max_validator_weight_factor: u8,
+    /// Denominated in "platformvm::txs::transform_subnet::PERCENT_DENOMINATOR".
+    pub uptime_requirement: u32,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    /// Defaults the reward and staking parameters to the primary network's.
+    /// The asset, supplies, and stake amounts must be set.
+    /// ref. "avalanchego/genesis.GetStakingConfig"
+    pub fn new(p: &crate::wallet::p::P<T>) -> Self {
+        Self {
+            inner: p.clone(),
+            subnet_id: ids::Id::empty(),
+            asset_id: ids::Id::empty(),
+            initial_supply: 0,
+            maximum_supply: 0,
+            min_consumption_rate: 100_000, // 10%
+            max_consumption_rate: 120_000, // 12%
+            min_validator_stake: 0,
+            max_validator_stake: 0,
+            min_stake_duration: 24 * 60 * 60,       // 1-day
+            max_stake_duration: 365 * 24 * 60 * 60, // 1-year
+            min_delegation_fee: 20_000,             // 2%
+            min_delegator_stake: 0,
+            max_validator_weight_factor: 5,
+            uptime_requirement: 800_000, // 80%
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the subnet Id to transform.
+    #[must_use]
+    pub fn subnet_id(mut self, subnet_id: ids::Id) -> Self {
+        self.subnet_id = subnet_id;
+        self
+    }
+
+    /// Sets the staking asset Id.
+    #[must_use]
+    pub fn asset_id(mut self, asset_id: ids::Id) -> Self {
+        self.asset_id = asset_id;
+        self
+    }
+
+    /// Sets the initial and maximum supply of the staking asset.
+    #[must_use]
+    pub fn supply(mut self, initial_supply: u64, maximum_supply: u64) -> Self {
+        self.initial_supply = initial_supply;
+        self.maximum_supply = maximum_supply;
+        self
+    }
+
+    /// Sets the min and max consumption rates.
+    #[must_use]
+    pub fn consumption_rate(mut self, min: u64, max: u64) -> Self {
+        self.min_consumption_rate = min;
+        self.max_consumption_rate = max;
+        self
+    }
+
+    /// Sets the min and max validator stake amounts.
+    #[must_use]
+    pub fn validator_stake(mut self, min: u64, max: u64) -> Self {
+        self.min_validator_stake = min;
+        self.max_validator_stake = max;
+        self
+    }
+
+    /// Sets the min and max stake durations in seconds.
+    #[must_use]
+    pub fn stake_duration(mut self, min: u32, max: u32) -> Self {
+        self.min_stake_duration = min;
+        self.max_stake_duration = max;
+        self
+    }
+
+    /// Sets the min delegation fee.
+    #[must_use]
+    pub fn min_delegation_fee(mut self, min_delegation_fee: u32) -> Self {
+        self.min_delegation_fee = min_delegation_fee;
+        self
+    }
+
+    /// Sets the min delegator stake amount.
+    #[must_use]
+    pub fn min_delegator_stake(mut self, min_delegator_stake: u64) -> Self {
+        self.min_delegator_stake = min_delegator_stake;
+        self
+    }
+
+    /// Sets the max validator weight factor.
+    #[must_use]
+    pub fn max_validator_weight_factor(mut self, max_validator_weight_factor: u8) -> Self {
+        self.max_validator_weight_factor = max_validator_weight_factor;
+        self
+    }
+
+    /// Sets the uptime requirement.
+    #[must_use]
+    pub fn uptime_requirement(mut self, uptime_requirement: u32) -> Self {
+        self.uptime_requirement = uptime_requirement;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Issues the transform subnet transaction and returns the transaction Id.
+    /// Fails if the parameters are invalid, without issuing the transaction.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "transforming subnet {} with asset {} via {}",
+            self.subnet_id,
+            self.asset_id,
+            picked_http_rpc.1
+        );
+
+        let mut tx = platformvm::txs::transform_subnet::Tx {
+            subnet_id: self.subnet_id,
+            asset_id: self.asset_id,
+            initial_supply: self.initial_supply,
+            maximum_supply: self.maximum_supply,
+            min_consumption_rate: self.min_consumption_rate,
+            max_consumption_rate: self.max_consumption_rate,
+            min_validator_stake: self.min_validator_stake,
+            max_validator_stake: self.max_validator_stake,
+            min_stake_duration: self.min_stake_duration,
+            max_stake_duration: self.max_stake_duration,
+            min_delegation_fee: self.min_delegation_fee,
+            min_delegator_stake: self.min_delegator_stake,
+            max_validator_weight_factor: self.max_validator_weight_factor,
+            uptime_requirement: self.uptime_requirement,
+            ..Default::default()
+        };
+        tx.verify(&self.inner.inner.avax_asset_id)?;
+
+        // the rewards to be minted must be burned upfront
+        // so that the total supply never exceeds the maximum supply,
+        // along with the fee in AVAX, with nothing to stake
+        // ref. "avalanchego/wallet/chain/p.builder.NewTransformSubnetTx"
+        // ref. "avalanchego/vms/platformvm/txs/executor.StandardTxExecutor.TransformSubnetTx"
+        let to_burn = HashMap::from([
+            (
+                self.inner.inner.avax_asset_id,
+                self.inner.inner.transform_subnet_tx_fee,
+            ),
+            (self.asset_id, self.maximum_supply - self.initial_supply),
+        ]);
+        let (ins, unstaked_outs, signers) = self.inner.burn(&to_burn).await?;
+        let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
+
+        tx.base_tx = txs::Tx {
+            network_id: self.inner.inner.network_id,
+            blockchain_id: self.inner.inner.blockchain_id_p,
+            transferable_outputs: Some(unstaked_outs),
+            transferable_inputs: Some(ins),
+            ..Default::default()
+        };
+        tx.subnet_auth = subnet_auth;
+        tx.sign([signers, subnet_signers].concat()).await?;
+
+        if self.dry_mode {
+            return Ok(tx.base_tx.metadata.unwrap().id);
+        }
+
+        let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = self
+            .inner
+            .inner
+            .client
+            .p()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!("failed to issue transform subnet transaction {:?}", e),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm transform subnet transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = self
+                .inner
+                .inner
+                .client
+                .p()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
+
+            let status = resp.result.unwrap().status;
+            if status == platformvm::txs::status::Status::Committed {
+                log::info!("{} successfully committed", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok(tx_id)
+    }
+}
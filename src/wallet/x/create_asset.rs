@@ -0,0 +1,336 @@
+use std::{cmp, time::SystemTime};
+
+use crate::{
+    avm::{self, txs::fx},
+    choices::status::Status,
+    errors::{Error, Result},
+    formatting, ids, key, txs,
+};
+use tokio::time::{sleep, Duration, Instant};
+
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::x::X<T>,
+
+    /// Human-readable name of the asset.
+    pub name: String,
+    /// Shorthand ticker symbol of the asset.
+    pub symbol: String,
+    /// Number of the decimal places of the asset.
+    pub denomination: u8,
+
+    /// Initial outputs of the asset, grouped by the fx index.
+    pub initial_states: Vec<avm::txs::create_asset::InitialState>,
+
+    /// Set "true" to poll transfer status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(x: &crate::wallet::x::X<T>) -> Self {
+        Self {
+            inner: x.clone(),
+            name: String::new(),
+            symbol: String::new(),
+            denomination: 0,
+            initial_states: Vec::new(),
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(500),
+            poll_interval: Duration::from_millis(700),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the asset name.
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Sets the asset symbol.
+    #[must_use]
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = symbol.to_string();
+        self
+    }
+
+    /// Sets the asset denomination.
+    #[must_use]
+    pub fn denomination(mut self, denomination: u8) -> Self {
+        self.denomination = denomination;
+        self
+    }
+
+    /// Appends an initial output to the initial state of its fx
+    /// (e.g., "secp256k1fx.TransferOutput" for the fungible supply,
+    /// "nftfx.MintOutput" for the NFT minters).
+    #[must_use]
+    pub fn initial_output(mut self, output: fx::Output) -> Self {
+        let fx_index = output.fx_index();
+        if let Some(state) = self
+            .initial_states
+            .iter_mut()
+            .find(|s| s.fx_index == fx_index)
+        {
+            state.outputs.push(output);
+        } else {
+            self.initial_states
+                .push(avm::txs::create_asset::InitialState::new(
+                    fx_index,
+                    vec![output],
+                ));
+        }
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Issues the create asset transaction and returns the asset Id,
+    /// which is the same as the transaction Id.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "creating asset '{}' ({}) with {} initial states via {}",
+            self.name,
+            self.symbol,
+            self.initial_states.len(),
+            picked_http_rpc.1
+        );
+
+        let utxos = self
+            .inner
+            .utxos_with_endpoint(&picked_http_rpc.1, None)
+            .await?;
+
+        let mut inputs: Vec<txs::transferable::Input> = Vec::new();
+        let mut outputs: Vec<txs::transferable::Output> = Vec::new();
+
+        // ref. "avalanchego/wallet/chain/x.builder.NewCreateAssetTx"
+        // "toBurn := map[ids.ID]uint64{b.backend.AVAXAssetID(): b.backend.CreateAssetTxFee()}"
+        let mut remaining_amount_to_burn = self.inner.inner.create_asset_tx_fee;
+
+        let now_unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        for utxo in utxos.iter() {
+            if utxo.asset_id != self.inner.inner.avax_asset_id {
+                continue;
+            }
+
+            // consumed enough, no need to burn more
+            if remaining_amount_to_burn == 0 {
+                continue;
+            }
+
+            if let Some(out) = &utxo.transfer_output {
+                let res = self.inner.inner.keychain.spend(out, now_unix);
+                if res.is_none() {
+                    // cannot spend the output, move onto next
+                    continue;
+                }
+                let (input, _) = res.unwrap();
+
+                inputs.push(txs::transferable::Input {
+                    utxo_id: utxo.utxo_id.clone(),
+                    asset_id: utxo.asset_id,
+                    transfer_input: Some(input),
+                    ..Default::default()
+                });
+
+                // burn any value that should be burned
+                let amount_to_burn = cmp::min(
+                    remaining_amount_to_burn, // amount we still need to burn
+                    out.amount,               // amount available to burn
+                );
+                remaining_amount_to_burn -= amount_to_burn;
+
+                let remaining_amount = out.amount - amount_to_burn;
+                if remaining_amount > 0 {
+                    // this input had extra value, so some must be returned
+                    outputs.push(txs::transferable::Output {
+                        asset_id: self.inner.inner.avax_asset_id,
+                        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                            amount: remaining_amount,
+                            output_owners: key::secp256k1::txs::OutputOwners {
+                                locktime: 0,
+                                threshold: 1,
+                                addresses: vec![self.inner.inner.short_address.clone()],
+                            },
+                        }),
+                        ..Default::default()
+                    })
+                }
+            }
+        }
+        if remaining_amount_to_burn > 0 {
+            return Err(Error::Other {
+                message: format!(
+                    "insufficient funds to create asset (need {} more AVAX)",
+                    remaining_amount_to_burn
+                ),
+                retryable: false,
+            });
+        }
+        inputs.sort();
+        outputs.sort();
+
+        // make sure it does not incur "tx has 1 credentials but 2 inputs. Should be same" error
+        let mut signers: Vec<Vec<T>> = Vec::new();
+        for _ in 0..inputs.len() {
+            signers.push(vec![self.inner.inner.keychain.keys[0].clone()]);
+        }
+        if inputs.len() > 1 {
+            log::debug!("signing for multiple inputs ({} inputs)", inputs.len());
+        }
+
+        let mut tx = avm::txs::create_asset::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_x,
+                transferable_outputs: Some(outputs),
+                transferable_inputs: Some(inputs),
+                ..Default::default()
+            },
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            denomination: self.denomination,
+            states: self.initial_states.clone(),
+            ..Default::default()
+        };
+        tx.sort_states()?;
+        tx.verify()?;
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok(tx.base_tx.metadata.unwrap().id);
+        }
+
+        let tx_bytes_with_signatures = tx
+            .base_tx
+            .metadata
+            .clone()
+            .unwrap()
+            .tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = self
+            .inner
+            .inner
+            .client
+            .x()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
+
+        if resp.result.is_none() {
+            return Err(Error::API {
+                message: format!("failed to issue tx {:?} (no result)", resp.error),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm create asset transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = self
+                .inner
+                .inner
+                .client
+                .x()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
+
+            let status = resp.result.unwrap().status;
+            if status == Status::Accepted {
+                log::info!("{} successfully accepted", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok(tx_id)
+    }
+}
@@ -4,9 +4,7 @@ use crate::{
     avm,
     choices::status::Status,
     errors::{Error, Result},
-    formatting, ids,
-    jsonrpc::client::x as client_x,
-    key, txs,
+    formatting, ids, key, txs,
 };
 use tokio::time::{sleep, Duration, Instant};
 
@@ -116,16 +114,10 @@ where
             picked_http_rpc.1
         );
 
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
-        let utxos_result = utxos.result.unwrap();
-        let utxos = utxos_result.utxos.unwrap();
-        log::debug!(
-            "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
-            utxos_result.num_fetched,
-            utxos_result.end_index,
-            utxos.len()
-        );
+        let utxos = self
+            .inner
+            .utxos_with_endpoint(&picked_http_rpc.1, None)
+            .await?;
 
         let mut inputs: Vec<txs::transferable::Input> = Vec::new();
         let outputs: Vec<txs::transferable::Output> = vec![
@@ -243,7 +235,13 @@ where
             .unwrap()
             .tx_bytes_with_signatures;
         let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        let resp = self
+            .inner
+            .inner
+            .client
+            .x()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
 
         if resp.result.is_none() {
             return Err(Error::API {
@@ -272,7 +270,13 @@ where
                 break;
             }
 
-            let resp = client_x::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+            let resp = self
+                .inner
+                .inner
+                .client
+                .x()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
 
             let status = resp.result.unwrap().status;
             if status == Status::Accepted {
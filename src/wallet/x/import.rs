@@ -4,9 +4,7 @@ use crate::{
     avm,
     choices::status::Status,
     errors::{Error, Result},
-    formatting, ids,
-    jsonrpc::client::x as client_x,
-    key, txs,
+    formatting, ids, key, txs,
 };
 use tokio::time::{sleep, Duration, Instant};
 
@@ -103,16 +101,10 @@ where
             picked_http_rpc.1
         );
 
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
-        let utxos_result = utxos.result.unwrap();
-        let utxos = utxos_result.utxos.unwrap();
-        log::debug!(
-            "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
-            utxos_result.num_fetched,
-            utxos_result.end_index,
-            utxos.len()
-        );
+        let utxos = self
+            .inner
+            .utxos_with_endpoint(&picked_http_rpc.1, Some(&self.source_blockchain_id))
+            .await?;
 
         // ref. "avalanchego/vms/avm#Service.SendMultiple"
         let now_unix = SystemTime::now()
@@ -190,7 +182,7 @@ where
         let mut tx = avm::txs::import::Tx {
             base_tx: txs::Tx {
                 network_id: self.inner.inner.network_id,
-                blockchain_id: self.inner.inner.blockchain_id_p.clone(),
+                blockchain_id: self.inner.inner.blockchain_id_x,
                 transferable_outputs: Some(outputs),
                 ..Default::default()
             },
@@ -211,7 +203,13 @@ where
             .unwrap()
             .tx_bytes_with_signatures;
         let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        let resp = self
+            .inner
+            .inner
+            .client
+            .x()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
 
         if resp.result.is_none() {
             return Err(Error::API {
@@ -240,7 +238,13 @@ where
                 break;
             }
 
-            let resp = client_x::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+            let resp = self
+                .inner
+                .inner
+                .client
+                .x()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
 
             let status = resp.result.unwrap().status;
             if status == Status::Accepted {
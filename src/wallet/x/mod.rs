@@ -1,8 +1,10 @@
+pub mod create_asset;
 pub mod export;
 pub mod import;
 pub mod transfer;
 
-use crate::{errors::Result, jsonrpc::client::x as client_x, key, txs, wallet};
+use crate::{errors::Result, ids, key, txs, wallet};
+use futures::TryStreamExt;
 
 impl<T> wallet::Wallet<T>
 where
@@ -30,7 +32,12 @@ where
 {
     /// Fetches the current balance of the wallet owner from the specified HTTP endpoint.
     pub async fn balance_with_endpoint(&self, http_rpc: &str) -> Result<u64> {
-        let resp = client_x::get_balance(http_rpc, &self.inner.x_address).await?;
+        let resp = self
+            .inner
+            .client
+            .x()
+            .get_balance(http_rpc, &self.inner.x_address)
+            .await?;
         let cur_balance = resp
             .result
             .expect("unexpected None GetBalanceResult")
@@ -55,21 +62,38 @@ where
             .await
     }
 
-    /// Fetches UTXOs for "X" chain.
+    /// Fetches all UTXOs of the wallet owner for "X" chain.
     /// TODO: cache this like avalanchego
     pub async fn utxos(&self) -> Result<Vec<txs::utxo::Utxo>> {
+        self.utxos_with_endpoint(&self.inner.pick_base_http_url().1, None)
+            .await
+    }
+
+    /// Fetches all UTXOs of the wallet owner for "X" chain from the specified HTTP endpoint.
+    /// Set "source_blockchain_id" to fetch the atomic UTXOs exported from the source chain.
+    pub async fn utxos_with_endpoint(
+        &self,
+        http_rpc: &str,
+        source_blockchain_id: Option<&ids::Id>,
+    ) -> Result<Vec<txs::utxo::Utxo>> {
         // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/wallet/chain/p/builder.go
         // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/vms/platformvm/add_validator_tx.go#L263
         // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/vms/platformvm/spend.go#L39 "stake"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L355 "AddValidator"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L614 "stake"
-        let resp =
-            client_x::get_utxos(&self.inner.pick_base_http_url().1, &self.inner.p_address).await?;
-        let utxos = resp
-            .result
-            .expect("unexpected None GetUtxosResult")
-            .utxos
-            .expect("unexpected None Utxos");
+        let source_chain = source_blockchain_id.map(|id| id.to_string());
+        let utxos: Vec<txs::utxo::Utxo> = self
+            .inner
+            .client
+            .x()
+            .get_all_utxos(
+                http_rpc,
+                std::slice::from_ref(&self.inner.x_address),
+                source_chain.as_deref(),
+            )
+            .try_collect()
+            .await?;
+        log::debug!("fetched {} UTXOs via {}", utxos.len(), http_rpc);
         Ok(utxos)
     }
 
@@ -78,6 +102,11 @@ where
         transfer::Tx::new(self)
     }
 
+    #[must_use]
+    pub fn create_asset(&self) -> create_asset::Tx<T> {
+        create_asset::Tx::new(self)
+    }
+
     #[must_use]
     pub fn export(&self) -> export::Tx<T> {
         export::Tx::new(self)
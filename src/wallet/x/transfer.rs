@@ -6,7 +6,6 @@ use crate::{
     errors::{Error, Result},
     formatting,
     ids::{self, short},
-    jsonrpc::client::x as client_x,
     key, txs,
 };
 use tokio::time::{sleep, Duration, Instant};
@@ -120,16 +119,10 @@ where
         // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/vms/platformvm/spend.go#L39 "stake"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L355 "AddValidator"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L614 "stake"
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
-        let utxos_result = utxos.result.unwrap();
-        let utxos = utxos_result.utxos.unwrap();
-        log::debug!(
-            "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
-            utxos_result.num_fetched,
-            utxos_result.end_index,
-            utxos.len()
-        );
+        let utxos = self
+            .inner
+            .utxos_with_endpoint(&picked_http_rpc.1, None)
+            .await?;
 
         let mut inputs: Vec<txs::transferable::Input> = Vec::new();
         let mut outputs: Vec<txs::transferable::Output> = vec![
@@ -240,7 +233,13 @@ where
             .unwrap()
             .tx_bytes_with_signatures;
         let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        let resp = self
+            .inner
+            .inner
+            .client
+            .x()
+            .issue_tx(&picked_http_rpc.1, &hex_tx)
+            .await?;
 
         if resp.result.is_none() {
             return Err(Error::API {
@@ -269,7 +268,13 @@ where
                 break;
             }
 
-            let resp = client_x::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+            let resp = self
+                .inner
+                .inner
+                .client
+                .x()
+                .get_tx_status(&picked_http_rpc.1, &tx_id.to_string())
+                .await?;
 
             let status = resp.result.unwrap().status;
             if status == Status::Accepted {
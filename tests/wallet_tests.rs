@@ -0,0 +1,353 @@
+//! Hermetic wallet tests against the mock avalanchego JSON-RPC server.
+use std::time::{Duration, SystemTime};
+
+use avalanche_types::{
+    choices, ids, jsonrpc::client::Client, jsonrpc::mock, key, key::secp256k1::ReadOnly,
+    platformvm, txs, wallet,
+};
+use primitive_types::U256;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+const POLL_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn init_logger() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+}
+
+async fn build_wallet(
+    server: &mock::Server,
+    k: &key::secp256k1::private_key::Key,
+) -> wallet::Wallet<key::secp256k1::private_key::Key> {
+    wallet::Builder::new(k)
+        .base_http_url(server.http_rpc())
+        .build()
+        .await
+        .unwrap()
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --test wallet --features jsonrpc_mock -- test_x_transfer --exact --show-output
+#[tokio::test]
+async fn test_x_transfer() {
+    init_logger();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+    let receiver = key::secp256k1::TEST_KEYS[1].short_address().unwrap();
+
+    let mut state = mock::State::default();
+    let blockchain_id_x = state.blockchain_id_x;
+    state.fund(&blockchain_id_x, k.short_address().unwrap(), 3_000_000_000);
+    state.fund(&blockchain_id_x, k.short_address().unwrap(), 2_000_000_000);
+    let tx_fee = state.fees.tx_fee;
+    let server = mock::Server::start(state).await.unwrap();
+
+    let w = build_wallet(&server, &k).await;
+    assert_eq!(w.network_id, 1000000);
+    assert_eq!(w.blockchain_id_x, blockchain_id_x);
+    assert_eq!(w.tx_fee, tx_fee);
+    assert_eq!(w.x().balance().await.unwrap(), 5_000_000_000);
+
+    // dry mode only signs the tx
+    w.x()
+        .transfer()
+        .receiver(receiver.clone())
+        .amount(4_000_000_000)
+        .dry_mode(true)
+        .issue()
+        .await
+        .unwrap();
+    assert_eq!(w.x().balance().await.unwrap(), 5_000_000_000);
+
+    let tx_id = w
+        .x()
+        .transfer()
+        .receiver(receiver.clone())
+        .amount(4_000_000_000)
+        .check_acceptance(true)
+        .poll_initial_wait(POLL_INTERVAL)
+        .poll_interval(POLL_INTERVAL)
+        .poll_timeout(POLL_TIMEOUT)
+        .issue()
+        .await
+        .unwrap();
+    assert_eq!(
+        w.x().balance().await.unwrap(),
+        5_000_000_000 - 4_000_000_000 - tx_fee
+    );
+
+    let resp = w
+        .client
+        .x()
+        .get_tx_status(&server.http_rpc(), &tx_id.to_string())
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.result.unwrap().status,
+        choices::status::Status::Accepted
+    );
+
+    let state = server.state.lock().unwrap();
+    assert_eq!(
+        state.balance(&blockchain_id_x, &receiver, &state.avax_asset_id),
+        4_000_000_000
+    );
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --test wallet --features jsonrpc_mock -- test_x_transfer_insufficient_funds --exact --show-output
+#[tokio::test]
+async fn test_x_transfer_insufficient_funds() {
+    init_logger();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+    let receiver = key::secp256k1::TEST_KEYS[1].short_address().unwrap();
+
+    let mut state = mock::State::default();
+    let blockchain_id_x = state.blockchain_id_x;
+    state.fund(&blockchain_id_x, k.short_address().unwrap(), 1_000_000_000);
+    let server = mock::Server::start(state).await.unwrap();
+
+    let w = build_wallet(&server, &k).await;
+    assert!(w
+        .x()
+        .transfer()
+        .receiver(receiver)
+        .amount(2_000_000_000)
+        .issue()
+        .await
+        .is_err());
+    assert_eq!(w.x().balance().await.unwrap(), 1_000_000_000);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --test wallet --features jsonrpc_mock -- test_export_import --exact --show-output
+#[tokio::test]
+async fn test_export_import() {
+    init_logger();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+
+    let mut state = mock::State::default();
+    let blockchain_id_x = state.blockchain_id_x;
+    state.fund(&blockchain_id_x, k.short_address().unwrap(), 5_000_000_000);
+    let tx_fee = state.fees.tx_fee;
+    let server = mock::Server::start(state).await.unwrap();
+
+    let w = build_wallet(&server, &k).await;
+
+    // X -> P
+    w.x()
+        .export()
+        .destination_blockchain_id(w.blockchain_id_p)
+        .amount(2_000_000_000)
+        .issue()
+        .await
+        .unwrap();
+    assert_eq!(
+        w.x().balance().await.unwrap(),
+        5_000_000_000 - 2_000_000_000 - tx_fee
+    );
+    assert_eq!(w.p().balance().await.unwrap(), 0);
+
+    let tx_id = w
+        .p()
+        .import()
+        .source_blockchain_id(w.blockchain_id_x)
+        .check_acceptance(true)
+        .poll_initial_wait(POLL_INTERVAL)
+        .poll_interval(POLL_INTERVAL)
+        .poll_timeout(POLL_TIMEOUT)
+        .issue()
+        .await
+        .unwrap();
+    assert_eq!(w.p().balance().await.unwrap(), 2_000_000_000 - tx_fee);
+
+    let resp = w
+        .client
+        .p()
+        .get_tx_status(&server.http_rpc(), &tx_id.to_string())
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.result.unwrap().status,
+        platformvm::txs::status::Status::Committed
+    );
+
+    // P -> X
+    w.p()
+        .export()
+        .destination_blockchain_id(w.blockchain_id_x)
+        .amount(1_000_000_000)
+        .issue()
+        .await
+        .unwrap();
+    w.x()
+        .import()
+        .source_blockchain_id(w.blockchain_id_p)
+        .issue()
+        .await
+        .unwrap();
+    assert_eq!(
+        w.p().balance().await.unwrap(),
+        2_000_000_000 - 1_000_000_000 - 2 * tx_fee
+    );
+    assert_eq!(
+        w.x().balance().await.unwrap(),
+        5_000_000_000 - 2_000_000_000 - tx_fee + 1_000_000_000 - tx_fee
+    );
+
+    // nothing left to import
+    assert!(w
+        .x()
+        .import()
+        .source_blockchain_id(w.blockchain_id_p)
+        .issue()
+        .await
+        .is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --test wallet --features jsonrpc_mock -- test_add_validator --exact --show-output
+#[tokio::test]
+async fn test_add_validator() {
+    init_logger();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+    let node_id = ids::node::Id::from_slice(&[1; 20]);
+
+    let mut state = mock::State::default();
+    let blockchain_id_p = state.blockchain_id_p;
+    state.fund(
+        &blockchain_id_p,
+        k.short_address().unwrap(),
+        3_000_000_000_000,
+    );
+    let server = mock::Server::start(state).await.unwrap();
+
+    let w = build_wallet(&server, &k).await;
+    assert!(!w.p().is_primary_network_validator(&node_id).await.unwrap());
+
+    let (tx_id, added) = w
+        .p()
+        .add_validator()
+        .node_id(node_id)
+        .stake_amount(2_000_000_000_000)
+        .validate_period_in_days(60, 60)
+        .check_acceptance(true)
+        .poll_initial_wait(POLL_INTERVAL)
+        .poll_interval(POLL_INTERVAL)
+        .poll_timeout(POLL_TIMEOUT)
+        .issue()
+        .await
+        .unwrap();
+    assert!(added);
+    assert!(w.p().is_primary_network_validator(&node_id).await.unwrap());
+    assert_eq!(w.p().balance().await.unwrap(), 1_000_000_000_000);
+
+    let state = server.state.lock().unwrap();
+    assert_eq!(state.validators.len(), 1);
+    assert_eq!(state.validators[0].tx_id, tx_id);
+    assert_eq!(state.validators[0].weight, Some(2_000_000_000_000));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --test wallet --features jsonrpc_mock -- test_add_validator_with_locked_utxo --exact --show-output
+#[tokio::test]
+async fn test_add_validator_with_locked_utxo() {
+    init_logger();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+    let owner = k.short_address().unwrap();
+    let locktime = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 365 * 24 * 60 * 60;
+
+    let mut state = mock::State::default();
+    let blockchain_id_p = state.blockchain_id_p;
+    let avax_asset_id = state.avax_asset_id;
+    state.add_utxo(
+        &blockchain_id_p,
+        txs::utxo::Utxo {
+            utxo_id: txs::utxo::Id::new(ids::Id::sha256("locked").as_ref(), 0, false).unwrap(),
+            asset_id: avax_asset_id,
+            stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+                locktime,
+                transfer_output: key::secp256k1::txs::transfer::Output::new(
+                    3_000_000_000_000,
+                    key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&owner)),
+                ),
+            }),
+            ..txs::utxo::Utxo::default()
+        },
+    );
+    let server = mock::Server::start(state).await.unwrap();
+
+    let locked_utxos = |state: &mock::State| -> Vec<u64> {
+        state.utxos[&blockchain_id_p]
+            .values()
+            .filter_map(|utxo| utxo.stakeable_lock_out.as_ref())
+            .map(|out| {
+                assert_eq!(out.locktime, locktime);
+                out.transfer_output.amount
+            })
+            .collect()
+    };
+
+    // partially stakes the locked UTXO, and returns the rest still locked
+    let w = build_wallet(&server, &k).await;
+    let (_, added) = w
+        .p()
+        .add_validator()
+        .node_id(ids::node::Id::from_slice(&[1; 20]))
+        .stake_amount(2_000_000_000_000)
+        .validate_period_in_days(60, 60)
+        .issue()
+        .await
+        .unwrap();
+    assert!(added);
+    {
+        let state = server.state.lock().unwrap();
+        assert_eq!(state.validators[0].weight, Some(2_000_000_000_000));
+        assert_eq!(locked_utxos(&state), vec![1_000_000_000_000]);
+    }
+
+    // fully stakes the returned locked UTXO
+    let (_, added) = w
+        .p()
+        .add_validator()
+        .node_id(ids::node::Id::from_slice(&[2; 20]))
+        .stake_amount(1_000_000_000_000)
+        .validate_period_in_days(60, 60)
+        .issue()
+        .await
+        .unwrap();
+    assert!(added);
+    let state = server.state.lock().unwrap();
+    assert_eq!(state.validators[1].weight, Some(1_000_000_000_000));
+    assert!(locked_utxos(&state).is_empty());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --test wallet --features jsonrpc_mock -- test_evm --exact --show-output
+#[tokio::test]
+async fn test_evm() {
+    init_logger();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+
+    let mut state = mock::State::default();
+    state.set_evm_balance(k.h160_address(), U256::from(1_000_000_000_000_000_000u64));
+    let base_fee = state.evm_base_fee;
+    let server = mock::Server::start(state).await.unwrap();
+
+    let w = build_wallet(&server, &k).await;
+    assert_eq!(
+        w.c().balance().await.unwrap(),
+        U256::from(1_000_000_000_000_000_000u64)
+    );
+    assert_eq!(w.c().nonce().await.unwrap(), 0);
+    assert_eq!(w.c().base_fee().await.unwrap(), base_fee);
+
+    let cli = Client::new().unwrap();
+    let resp = cli.health().health(&server.http_rpc(), &[]).await.unwrap();
+    assert!(resp.healthy);
+}